@@ -79,7 +79,7 @@ impl AjoContract {
     /// `Ok(())` on successful pause
     ///
     /// # Errors
-    /// * `UnauthorizedPause` - If the caller is not the admin
+    /// * `Unauthorized` - If the caller is not the admin
     ///
     /// # Authorization
     /// Only the contract admin can call this function.
@@ -100,7 +100,7 @@ impl AjoContract {
     /// `Ok(())` on successful unpause
     ///
     /// # Errors
-    /// * `UnauthorizedUnpause` - If the caller is not the admin
+    /// * `Unauthorized` - If the caller is not the admin
     ///
     /// # Authorization
     /// Only the contract admin can call this function.
@@ -190,9 +190,25 @@ impl AjoContract {
             insurance_config: crate::types::InsuranceConfig {
                 rate_bps: insurance_rate_bps,
                 is_enabled: insurance_rate_bps > 0,
+                premium_mode: crate::types::PremiumMode::OnTop,
+                pricing: crate::types::PremiumPricing::Flat,
+                floor_bps: insurance_rate_bps,
+                cap_bps: insurance_rate_bps,
             },
             payout_strategy: PayoutOrderingStrategy::Sequential,
             access_type: crate::types::GroupAccessType::Open,
+            forfeit_payout_on_default: false,
+            collateral_amount: 0,
+            swap_requires_approval: false,
+            discount_settlement: crate::types::DiscountSettlement::PayOut,
+            schedule_rule: crate::types::ScheduleRule::Unlocked,
+            min_members: 0,
+            formation_deadline: 0,
+            allow_late_join: false,
+            rollover_enabled: false,
+            round: 1,
+            predecessor_id: 0,
+            successor_id: 0,
         };
 
         // Store group
@@ -222,6 +238,118 @@ impl AjoContract {
         storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)
     }
 
+    /// Browse groups matching a filter, one page at a time.
+    ///
+    /// Groups are returned in ascending id order starting after `cursor`;
+    /// pass `0` for the first page and the returned `next_cursor` for the
+    /// next. Each call reads at most `MAX_INDEX_PAGES_SCANNED` index pages,
+    /// so a page can come back short or empty while `next_cursor` is still
+    /// set. Groups created before the indexes existed are only listed once
+    /// [`backfill_group_indexes`](Self::backfill_group_indexes) has reached
+    /// them. Forming groups whose formation deadline has passed are left out of
+    /// [`GroupFilter::OpenSeats`](crate::types::GroupFilter::OpenSeats)
+    /// since they can no longer be joined.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `filter` - Which groups to list
+    /// * `cursor` - Only groups with a higher id are returned
+    /// * `limit` - Maximum number of groups to return (at most `MAX_GROUP_PAGE_SIZE`)
+    ///
+    /// # Returns
+    /// A summary of each matching group and the cursor to continue from
+    pub fn list_groups(
+        env: Env,
+        filter: crate::types::GroupFilter,
+        cursor: u64,
+        limit: u32,
+    ) -> crate::types::GroupPage {
+        let limit = limit.min(crate::types::MAX_GROUP_PAGE_SIZE);
+        let now = utils::get_current_timestamp(&env);
+        let last_page = storage::get_group_count(&env) / crate::types::GROUP_INDEX_PAGE_SIZE;
+        let first_page = cursor / crate::types::GROUP_INDEX_PAGE_SIZE;
+        let end_page =
+            last_page.min(first_page.saturating_add(crate::types::MAX_INDEX_PAGES_SCANNED - 1));
+
+        let mut summaries = Vec::new(&env);
+        let mut next_cursor = cursor;
+        let mut page = first_page;
+        while page <= end_page {
+            for group_id in storage::get_group_index_page(&env, &filter, page).iter() {
+                if group_id <= cursor {
+                    continue;
+                }
+                if summaries.len() >= limit {
+                    break;
+                }
+                next_cursor = group_id;
+                let group = match storage::get_group(&env, group_id) {
+                    Some(group) => group,
+                    None => continue,
+                };
+                let expired = group.state == crate::types::GroupState::Forming
+                    && now > group.formation_deadline;
+                if filter == crate::types::GroupFilter::OpenSeats && expired {
+                    continue;
+                }
+                summaries.push_back(utils::group_summary(&env, &group));
+            }
+            if summaries.len() >= limit {
+                break;
+            }
+            page += 1;
+        }
+        if page > last_page {
+            next_cursor = 0;
+        } else if summaries.len() < limit {
+            next_cursor = (end_page + 1) * crate::types::GROUP_INDEX_PAGE_SIZE - 1;
+        }
+        crate::types::GroupPage { groups: summaries, next_cursor }
+    }
+
+    /// Add groups created before the discovery indexes and members' group
+    /// lists existed to them.
+    ///
+    /// Indexes the groups with ids above `cursor`, at most `limit` of them
+    /// (capped at `MAX_GROUP_PAGE_SIZE`) per call, and adds each to its
+    /// members' group lists. Groups already indexed are left as they were.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `admin` - Address of the contract admin
+    /// * `cursor` - Only groups with a higher id are indexed
+    /// * `limit` - Maximum number of groups to index
+    ///
+    /// # Returns
+    /// The last group id indexed; pass it as `cursor` to carry on, until it
+    /// equals the number of groups
+    ///
+    /// # Errors
+    /// * `Unauthorized` - If the caller is not the admin
+    pub fn backfill_group_indexes(
+        env: Env,
+        admin: Address,
+        cursor: u64,
+        limit: u32,
+    ) -> Result<u64, AjoError> {
+        admin.require_auth();
+        let stored_admin = storage::get_admin(&env).ok_or(AjoError::Unauthorized)?;
+        if admin != stored_admin {
+            return Err(AjoError::Unauthorized);
+        }
+
+        let limit = limit.min(crate::types::MAX_GROUP_PAGE_SIZE) as u64;
+        let last = storage::get_group_count(&env).min(cursor.saturating_add(limit));
+        let mut group_id = cursor;
+        while group_id < last {
+            group_id += 1;
+            if let Some(group) = storage::get_group(&env, group_id) {
+                storage::index_group(&env, &group);
+            }
+        }
+        Ok(group_id)
+    }
+
     /// Get list of all members in a group.
     ///
     /// Returns the ordered list of all member addresses currently in the group.
@@ -248,6 +376,7 @@ impl AjoContract {
     /// are already a member, the group is full, or the group has completed all cycles.
     /// For InviteOnly groups, requires a valid, non-expired invitation.
     /// For ApprovalRequired groups, direct joining is not allowed.
+    /// If the group requires collateral, it is escrowed from the member.
     ///
     /// # Arguments
     /// * `env` - The Soroban contract environment
@@ -260,11 +389,12 @@ impl AjoContract {
     /// # Errors
     /// * `GroupNotFound` - If the group does not exist
     /// * `MaxMembersExceeded` - If the group has reached max members
-    /// * `AlreadyMember` - If the address is already a member
+    /// * `AlreadyMember` - If the address is already a member, or if the invitation was
+    ///   already used
     /// * `GroupComplete` - If the group has completed all cycles
-    /// * `GroupAccessRestricted` - If the group is invite-only or approval-required
-    /// * `InvitationExpired` - If the invitation has expired
-    /// * `InvitationAlreadyAccepted` - If the invitation was already used
+    /// * `Unauthorized` - If the group is approval-required, or invite-only and the
+    ///   member has no invitation, or if the invitation has expired
+    /// * `InsufficientBalance` - If the member cannot cover the collateral
     pub fn join_group(env: Env, member: Address, group_id: u64) -> Result<(), AjoError> {
         // Check if paused
         pausable::ensure_not_paused(&env)?;
@@ -275,29 +405,8 @@ impl AjoContract {
         // Get group
         let mut group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
 
-        // Cache member count for comparisons
-        let member_count = group.members.len() as u32;
-        let max_members = group.max_members;
-
-        // Check if group is complete
-        if group.is_complete {
-            return Err(AjoError::GroupComplete);
-        }
-
-        // Check if group is cancelled
-        if group.state == crate::types::GroupState::Cancelled {
-            return Err(AjoError::GroupCancelled);
-        }
-
-        // Check if already a member
-        if utils::is_member(&group.members, &member) {
-            return Err(AjoError::AlreadyMember);
-        }
-
-        // Check if group is full
-        if member_count >= max_members {
-            return Err(AjoError::MaxMembersExceeded);
-        }
+        // Check group state, existing membership and capacity
+        utils::validate_can_join(&env, &group, &member)?;
 
         // Check access type
         match group.access_type {
@@ -305,20 +414,16 @@ impl AjoContract {
                 // Open groups allow direct joining
             }
             GroupAccessType::InviteOnly => {
-                // Check for valid invitation
-                let invitation = storage::get_invitation(&env, group_id, &member)
-                    .ok_or(AjoError::Unauthorized)?;
-
-                // Check if invitation is expired
-                let now = utils::get_current_timestamp(&env);
-                if now > invitation.expires_at {
-                    return Err(AjoError::OutsideCycleWindow);
-                }
-
-                // Check if already accepted
-                if invitation.accepted {
-                    return Err(AjoError::AlreadyMember);
-                }
+                // Requires a valid invitation, which is consumed on join
+                let mut invitation = utils::validate_invitation(&env, group_id, &member)
+                    .map_err(|err| match err {
+                        AjoError::Unauthorized => AjoError::Unauthorized,
+                        other => other,
+                    })?;
+                invitation.accepted = true;
+                storage::store_invitation(&env, group_id, &member, &invitation);
+                utils::set_contribution_tier(&env, &group, &member, invitation.contribution_amount);
+                events::emit_invitation_accepted(&env, group_id, &member);
             }
             GroupAccessType::ApprovalRequired => {
                 // Direct joining is not allowed for approval-required groups
@@ -326,1258 +431,3986 @@ impl AjoContract {
             }
         }
 
-        // Add member
-        group.members.push_back(member.clone());
+        // Escrow the group's collateral, if any
+        utils::escrow_collateral(&env, &group, &member)?;
 
-        // Update storage
-        storage::store_group(&env, group_id, &group);
-
-        // Emit event
-        events::emit_member_joined(&env, group_id, &member);
-
-        // Update member stats
-        let mut stats = storage::get_member_stats(&env, &member)
-            .unwrap_or_else(|| utils::default_member_stats(&env, &member));
-        stats.total_groups_joined += 1;
-        storage::store_member_stats(&env, &member, &stats);
+        // Add member, update storage, emit event and update stats
+        utils::admit_member(&env, &mut group, &member);
 
         Ok(())
     }
 
-    /// Check if an address is a member of a group.
+    /// Join an open group at the member's own contribution tier.
     ///
-    /// Returns whether the provided address is currently a member of the specified group.
+    /// Works like [`join_group`](Self::join_group), except the member pays
+    /// `contribution_amount` per share each cycle instead of the group's
+    /// amount. Each of their payouts is their tier times the group's total
+    /// shares, so across the round they get back exactly what they paid in.
+    /// Invite-only groups set the tier in the invitation instead
+    /// (see [`invite_member_with_tier`](Self::invite_member_with_tier)).
     ///
     /// # Arguments
     /// * `env` - The Soroban contract environment
-    /// * `group_id` - The group to check
-    /// * `address` - The address to check
+    /// * `member` - Address of the member joining (must authenticate)
+    /// * `group_id` - The group to join
+    /// * `contribution_amount` - The member's contribution per share per cycle
     ///
-    /// # Returns
-    /// `true` if the address is a member, `false` otherwise
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `MaxMembersExceeded` - If the group has reached max members
+    /// * `AlreadyMember` - If the address is already a member
+    /// * `GroupComplete` - If the group has completed all cycles
+    /// * `GroupCancelled` - If the group has been cancelled
+    /// * `Unauthorized` - If the group is not open
+    /// * `ContributionAmountZero` - If `contribution_amount == 0`
+    /// * `ContributionAmountNegative` - If `contribution_amount < 0`
+    /// * `InvalidMultiTokenConfig` - If the group accepts several tokens
+    /// * `InsufficientBalance` - If the member cannot cover the collateral
+    pub fn join_group_with_tier(
+        env: Env,
+        member: Address,
+        group_id: u64,
+        contribution_amount: i128,
+    ) -> Result<(), AjoError> {
+        pausable::ensure_not_paused(&env)?;
+        member.require_auth();
+
+        let mut group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        utils::validate_can_join(&env, &group, &member)?;
+        if group.access_type != GroupAccessType::Open {
+            return Err(AjoError::Unauthorized);
+        }
+        utils::validate_contribution_tier(&env, &group, contribution_amount)?;
+
+        utils::escrow_collateral(&env, &group, &member)?;
+        utils::set_contribution_tier(&env, &group, &member, contribution_amount);
+        utils::admit_member(&env, &mut group, &member);
+
+        Ok(())
+    }
+
+    /// Get a member's contribution per share per cycle: their own tier, or
+    /// the group's `contribution_amount` if they joined without one.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The group
+    /// * `member` - The member's address
     ///
     /// # Errors
     /// * `GroupNotFound` - If the group does not exist
-    pub fn is_member(env: Env, group_id: u64, address: Address) -> Result<bool, AjoError> {
+    /// * `NotMember` - If the address is not a member
+    pub fn get_contribution_tier(env: Env, group_id: u64, member: Address) -> Result<i128, AjoError> {
         let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
-        Ok(utils::is_member(&group.members, &address))
+        if !utils::is_member(&group.members, &member) {
+            return Err(AjoError::NotMember);
+        }
+        Ok(utils::member_tier(&env, &group, &member))
     }
 
-    /// Contribute to the current cycle.
-    ///
-    /// Records a member's contribution for the current cycle and transfers tokens from
-    /// the member to the contract. Each member can contribute once per cycle.
-    /// Authentication is required.
+    /// Get how much of a higher-tier member's payouts is still owed to them.
     ///
-    /// The function transfers the contribution amount from the member's token balance
-    /// to the contract. Late contributions (after cycle ends but within grace period)
-    /// incur penalties. Contributions after grace period are rejected.
+    /// Payouts to members on a higher tier than average are funded partly
+    /// from pot held back from lower-tier payouts; whatever could not be
+    /// covered yet is paid at later payouts.
     ///
     /// # Arguments
     /// * `env` - The Soroban contract environment
-    /// * `member` - Address making the contribution (must authenticate)
-    /// * `group_id` - The group to contribute to
+    /// * `group_id` - The group
+    /// * `member` - The member's address
+    pub fn get_tier_owed(env: Env, group_id: u64, member: Address) -> i128 {
+        storage::get_tier_owed(&env, group_id, &member)
+    }
+
+    /// Put a new group into a forming phase.
     ///
-    /// # Returns
-    /// `Ok(())` on successful contribution and token transfer
+    /// A forming group takes members but runs no cycles: contributions and
+    /// payouts are rejected until it starts. It starts automatically once
+    /// its members' shares reach `max_members`, or when the creator calls
+    /// [`start_group`](Self::start_group) with at least `min_members`.
+    /// If it has not started `formation_period` seconds from now, anyone can
+    /// expire it with [`expire_formation`](Self::expire_formation). Calling
+    /// this again while forming updates the settings and the deadline.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `creator` - The group creator (must authenticate)
+    /// * `group_id` - The group
+    /// * `min_members` - Members needed before the creator can start the group
+    /// * `formation_period` - Seconds from now until the group can be expired; a
+    ///   period too large to add to the current time means it never expires
+    /// * `allow_late_join` - Whether members can still join after the start
     ///
     /// # Errors
     /// * `GroupNotFound` - If the group does not exist
-    /// * `NotMember` - If the address is not a member
-    /// * `AlreadyContributed` - If already contributed this cycle
+    /// * `Unauthorized` - If the caller is not the group creator
     /// * `GroupComplete` - If the group has completed all cycles
-    /// * `GracePeriodExpired` - If contribution is too late (after grace period)
-    /// * `InsufficientBalance` - If member doesn't have enough tokens
-    /// * `TransferFailed` - If the token transfer fails
-    pub fn contribute(env: Env, member: Address, group_id: u64) -> Result<(), AjoError> {
-        // Check if paused
+    /// * `GroupCancelled` - If the group has been cancelled
+    /// * `InvalidState` - If the group has started after forming, or
+    ///   anyone has already paid towards its first cycle
+    /// * `InvalidInput` - If `min_members < 2` or `min_members > max_members`
+    pub fn set_formation(
+        env: Env,
+        creator: Address,
+        group_id: u64,
+        min_members: u32,
+        formation_period: u64,
+        allow_late_join: bool,
+    ) -> Result<(), AjoError> {
         pausable::ensure_not_paused(&env)?;
+        creator.require_auth();
 
-        // Require authentication
-        member.require_auth();
-
-        // Get group (single fetch)
-        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
-
-        // Cache frequently accessed values
-        let group_id_cached = group.id;
-        let current_cycle = group.current_cycle;
-        let contribution_amount = group.contribution_amount;
-
-        // Check if group is complete
+        let mut group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        if group.creator != creator {
+            return Err(AjoError::Unauthorized);
+        }
         if group.is_complete {
             return Err(AjoError::GroupComplete);
         }
-
-        // Check if group is cancelled
         if group.state == crate::types::GroupState::Cancelled {
             return Err(AjoError::GroupCancelled);
         }
-
-        // Check if member
-        if !utils::is_member(&group.members, &member) {
-            return Err(AjoError::NotMember);
+        if group.state != crate::types::GroupState::Forming {
+            let paid = group
+                .members
+                .iter()
+                .any(|member| utils::get_amount_paid(&env, &group, group.current_cycle, &member) > 0);
+            if group.min_members > 0 || group.payout_index > 0 || paid {
+                return Err(AjoError::InvalidState);
+            }
         }
-
-        // Check if already contributed
-        if storage::has_contributed(&env, group_id_cached, current_cycle, &member) {
-            return Err(AjoError::AlreadyContributed);
+        if min_members < 2 || min_members > group.max_members {
+            return Err(AjoError::InvalidInput);
         }
 
-        // Get contract address for token transfer
-        let contract_address = env.current_contract_address();
-
-        // Check member balance before transfer
-        crate::token::check_balance(&env, &group.token_address, &member, contribution_amount)?;
+        let now = utils::get_current_timestamp(&env);
+        group.state = crate::types::GroupState::Forming;
+        group.min_members = min_members;
+        group.formation_deadline = now.saturating_add(formation_period);
+        group.allow_late_join = allow_late_join;
+        events::emit_formation_set(&env, group_id, min_members, group.formation_deadline, allow_late_join);
+
+        if utils::total_shares(&env, &group) >= group.max_members {
+            utils::start_cycles(&env, &mut group);
+        }
+        storage::store_group(&env, group_id, &group);
 
-        // Transfer tokens from member to contract
-        crate::token::transfer_token(
-            &env,
-            &group.token_address,
-            &member,
-            &contract_address,
-            contribution_amount,
-        )?;
+        Ok(())
+    }
 
-        // Record contribution
-        storage::store_contribution(&env, group_id_cached, current_cycle, &member, true);
+    /// Start a forming group's first cycle before it is full.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `creator` - The group creator (must authenticate)
+    /// * `group_id` - The group
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `Unauthorized` - If the caller is not the group creator
+    /// * `GroupCancelled` - If the group has been cancelled
+    /// * `InvalidState` - If the group is not forming, or if the formation deadline has
+    ///   passed, or if the group has fewer than `min_members`
+    pub fn start_group(env: Env, creator: Address, group_id: u64) -> Result<(), AjoError> {
+        pausable::ensure_not_paused(&env)?;
+        creator.require_auth();
 
-        // Insurance logic: Deduct premium if enabled
-        if group.insurance_config.is_enabled {
-            let premium = crate::insurance::calculate_premium(contribution_amount, group.insurance_config.rate_bps);
-            if premium > 0 {
-                crate::insurance::deposit_to_pool(&env, &group.token_address, premium);
-            }
+        let mut group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        if group.creator != creator {
+            return Err(AjoError::Unauthorized);
+        }
+        if group.state == crate::types::GroupState::Cancelled {
+            return Err(AjoError::GroupCancelled);
+        }
+        if group.state != crate::types::GroupState::Forming {
+            return Err(AjoError::InvalidState);
+        }
+        if utils::get_current_timestamp(&env) > group.formation_deadline {
+            return Err(AjoError::InvalidState);
+        }
+        if utils::total_shares(&env, &group) < group.min_members {
+            return Err(AjoError::InvalidState);
         }
 
-        // Emit event
-        events::emit_contribution_made(
-            &env,
-            group_id_cached,
-            &member,
-            current_cycle,
-            contribution_amount,
-        );
+        utils::start_cycles(&env, &mut group);
+        storage::store_group(&env, group_id, &group);
 
-        // Update member stats
-        let mut stats = storage::get_member_stats(&env, &member)
-            .unwrap_or_else(|| utils::default_member_stats(&env, &member));
-        stats.total_contributions += 1;
-        stats.on_time_contributions += 1;
-        stats.total_amount_contributed += contribution_amount;
-        storage::store_member_stats(&env, &member, &stats);
+        Ok(())
+    }
 
-        // Check and record member achievements
-        let achievements = utils::check_member_achievements(&env, &member, &stats);
-        for achievement in achievements.iter() {
-            let record = AchievementRecord {
-                member: member.clone(),
-                achievement,
-                earned_at: utils::get_current_timestamp(&env),
-                group_id: group_id_cached,
-            };
-            storage::add_member_achievement(&env, &member, &record);
-            events::emit_achievement_earned(&env, &member, record.achievement as u32, group_id_cached);
+    /// Expire a forming group that did not start before its deadline.
+    ///
+    /// The group is cancelled and every member's collateral is returned.
+    /// Anyone can call this once the deadline has passed.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The group
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `GroupCancelled` - If the group has already been cancelled
+    /// * `InvalidState` - If the group is not forming, or if the formation deadline has
+    ///   not passed
+    /// * `TransferFailed` - If a collateral refund fails
+    pub fn expire_formation(env: Env, group_id: u64) -> Result<(), AjoError> {
+        pausable::ensure_not_paused(&env)?;
+
+        let mut group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        if group.state == crate::types::GroupState::Cancelled {
+            return Err(AjoError::GroupCancelled);
+        }
+        if group.state != crate::types::GroupState::Forming {
+            return Err(AjoError::InvalidState);
+        }
+        if utils::get_current_timestamp(&env) <= group.formation_deadline {
+            return Err(AjoError::InvalidState);
         }
 
+        utils::return_all_collateral(&env, &group)?;
+        utils::refund_all_prepayments(&env, &group)?;
+        group.state = crate::types::GroupState::Cancelled;
+        storage::store_group(&env, group_id, &group);
+
+        events::emit_formation_expired(&env, group_id);
+
         Ok(())
     }
 
-    /// Get contribution status for all members in a specific cycle.
+    /// Turn automatic rollover into a new round on or off.
     ///
-    /// Returns an ordered list of all members paired with their contribution status
-    /// for the specified cycle. Member order matches the group's member list order.
+    /// When a group with rollover enabled completes, a successor group opens
+    /// with the same parameters and every member who opted in with
+    /// [`set_rollover_opt_in`](Self::set_rollover_opt_in). The creator has to
+    /// opt in as well, since they run the successor. The successor links back
+    /// through `predecessor_id` and inherits the setting.
     ///
     /// # Arguments
     /// * `env` - The Soroban contract environment
-    /// * `group_id` - The group to check
-    /// * `cycle_number` - The cycle to check (typically use current_cycle from group)
-    ///
-    /// # Returns
-    /// Vector of (Address, bool) tuples where `true` indicates the member has contributed
+    /// * `creator` - The group creator (must authenticate)
+    /// * `group_id` - The group
+    /// * `enabled` - Whether to open a successor round on completion
     ///
     /// # Errors
     /// * `GroupNotFound` - If the group does not exist
-    pub fn get_contribution_status(
+    /// * `Unauthorized` - If the caller is not the group creator
+    /// * `GroupComplete` - If the group has completed all cycles
+    /// * `GroupCancelled` - If the group has been cancelled
+    pub fn set_rollover(
         env: Env,
+        creator: Address,
         group_id: u64,
-        cycle_number: u32,
-    ) -> Result<Vec<(Address, bool)>, AjoError> {
-        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
-        Ok(storage::get_cycle_contributions(
-            &env,
-            group_id,
-            cycle_number,
-            &group.members,
-        ))
-    }
-
-    /// Execute payout for the current cycle.
-    ///
-    /// This is the core function that rotates payouts through group members.
-    /// It verifies that all members have contributed, calculates the total payout
-    /// (including any penalties collected), transfers tokens from the contract to
-    /// the recipient, and advances the cycle. When all members have received their
-    /// payout, the group is marked complete.
-    ///
-    /// Payout can only be executed after the grace period expires to ensure all
-    /// late contributions are collected.
-    ///
-    /// Process:
-    /// 1. Verifies all members have contributed in the current cycle
-    /// 2. Ensures grace period has expired
-    /// 3. Calculates total payout (contribution_amount × member_count + penalties)
-    /// 4. Verifies contract has sufficient token balance
-    /// 5. Transfers tokens from contract to recipient
-    /// 6. Records payout to the current recipient
-    /// 7. Emits payout event with penalty bonus
-    /// 8. Advances to next cycle (or marks complete if done)
-    ///
-    /// # Arguments
-    /// * `env` - The Soroban contract environment
-    /// * `group_id` - The group to execute payout for
-    ///
-    /// # Returns
-    /// `Ok(())` on successful payout execution and token transfer
-    ///
-    /// # Errors
-    /// * `GroupNotFound` - If the group does not exist
-    /// * `IncompleteContributions` - If not all members have contributed
-    /// * `GroupComplete` - If the group has already completed all payouts
-    /// * `NoMembers` - If the group has no members (should never happen)
-    /// * `OutsideCycleWindow` - If grace period has not expired yet
-    /// * `InsufficientContractBalance` - If contract doesn't have enough tokens
-    /// * `TransferFailed` - If the token transfer fails
-    pub fn execute_payout(env: Env, group_id: u64) -> Result<(), AjoError> {
-        // Check if paused
+        enabled: bool,
+    ) -> Result<(), AjoError> {
         pausable::ensure_not_paused(&env)?;
+        creator.require_auth();
 
-        // Get group (single fetch)
         let mut group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
-
-        // Check if group is cancelled
-        if group.state == crate::types::GroupState::Cancelled {
-            return Err(AjoError::GroupCancelled);
+        if group.creator != creator {
+            return Err(AjoError::Unauthorized);
         }
-
-        // Check if group is complete
         if group.is_complete {
             return Err(AjoError::GroupComplete);
         }
-
-        // Cache frequently accessed values
-        let group_id_cached = group.id;
-        let current_cycle = group.current_cycle;
-        let member_count = group.members.len();
-
-        // Check if all members have contributed
-        if !utils::all_members_contributed(&env, &group) {
-            return Err(AjoError::IncompleteContributions);
-        }
-
-        // Ensure grace period has expired before executing payout
-        let current_time = utils::get_current_timestamp(&env);
-        let grace_end = utils::get_grace_period_end(&group);
-        if current_time < grace_end {
-            // Still within grace period - delay payout
-            return Err(AjoError::OutsideCycleWindow);
+        if group.state == crate::types::GroupState::Cancelled {
+            return Err(AjoError::GroupCancelled);
         }
 
-        // Get payout recipient using the group's ordering strategy
-        let payout_recipient = utils::determine_next_recipient(&env, &group)?;
-
-        // Calculate payout amounts: base payout + collected penalties for this cycle
-        let base_payout = group.contribution_amount * (member_count as i128);
-        let penalty_bonus = storage::get_cycle_penalty_pool(&env, group_id_cached, current_cycle);
-        let payout_amount = base_payout + penalty_bonus;
-
-        // Get contract address for token transfer
-        let contract_address = env.current_contract_address();
+        group.rollover_enabled = enabled;
+        storage::store_group(&env, group_id, &group);
 
-        // Verify contract has sufficient balance
-        crate::token::check_contract_balance(
-            &env,
-            &group.token_address,
-            &contract_address,
-            payout_amount,
-        )?;
+        events::emit_rollover_set(&env, group_id, enabled);
 
-        // Transfer tokens from contract to recipient
-        crate::token::transfer_token(
-            &env,
-            &group.token_address,
-            &contract_address,
-            &payout_recipient,
-            payout_amount,
-        )?;
+        Ok(())
+    }
 
-        // Mark payout as received
-        storage::mark_payout_received(&env, group_id_cached, &payout_recipient);
+    /// Opt in or out of the group's next round.
+    ///
+    /// Members, the creator included, only carry on into the next round if
+    /// they opted in before completion. Without the creator there is no next
+    /// round, and everyone's collateral is returned.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `member` - The member (must authenticate)
+    /// * `group_id` - The group
+    /// * `opt_in` - Whether to join the next round
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `GroupComplete` - If the group has completed all cycles
+    /// * `GroupCancelled` - If the group has been cancelled
+    /// * `NotMember` - If the address is not a member
+    pub fn set_rollover_opt_in(
+        env: Env,
+        member: Address,
+        group_id: u64,
+        opt_in: bool,
+    ) -> Result<(), AjoError> {
+        pausable::ensure_not_paused(&env)?;
+        member.require_auth();
 
-        // Emit payout event with penalty information
-        if penalty_bonus > 0 {
-            events::emit_penalty_distributed(
-                &env,
-                group_id,
-                &payout_recipient,
-                group.current_cycle,
-                base_payout,
-                penalty_bonus,
-            );
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        if group.is_complete {
+            return Err(AjoError::GroupComplete);
         }
-
-        events::emit_payout_executed(
-            &env,
-            group_id_cached,
-            &payout_recipient,
-            current_cycle,
-            payout_amount,
-        );
-
-        // Record the ordering decision for transparency
-        events::emit_payout_order_determined(
-            &env,
-            group_id_cached,
-            current_cycle,
-            &payout_recipient,
-            group.payout_strategy as u32,
-        );
-
-        // Advance payout index
-        group.payout_index += 1;
-
-        // Check if all members have received payout
-        if group.payout_index >= member_count as u32 {
-            // All members have received payout - mark complete
-            group.is_complete = true;
-            events::emit_group_completed(&env, group_id_cached);
-        } else {
-            // Advance to next cycle
-            group.current_cycle += 1;
-            group.cycle_start_time = utils::get_current_timestamp(&env);
+        if group.state == crate::types::GroupState::Cancelled {
+            return Err(AjoError::GroupCancelled);
         }
-
-        // Update storage (single write)
-        storage::store_group(&env, group_id, &group);
-
-        // Check and record group milestones
-        let milestones = utils::check_group_milestones(&env, &group);
-        for milestone in milestones.iter() {
-            let record = MilestoneRecord {
-                group_id: group.id,
-                milestone,
-                achieved_at: utils::get_current_timestamp(&env),
-                cycle_number: current_cycle,
-            };
-            storage::add_group_milestone(&env, group.id, &record);
-            events::emit_milestone_achieved(&env, group.id, record.milestone as u32, current_cycle);
+        if !utils::is_member(&group.members, &member) {
+            return Err(AjoError::NotMember);
         }
 
-        // If group completed, update member stats for all members
-        if group.is_complete {
-            for member in group.members.iter() {
-                let mut stats = storage::get_member_stats(&env, &member)
-                    .unwrap_or_else(|| utils::default_member_stats(&env, &member));
-                stats.total_groups_completed += 1;
-                storage::store_member_stats(&env, &member, &stats);
+        let mut opted_in = storage::get_rollover_opt_ins(&env, group_id);
+        match (opted_in.first_index_of(&member), opt_in) {
+            (None, true) => opted_in.push_back(member.clone()),
+            (Some(index), false) => {
+                opted_in.remove(index);
             }
+            _ => {}
         }
+        storage::store_rollover_opt_ins(&env, group_id, &opted_in);
+
+        events::emit_rollover_opt_in(&env, group_id, &member, opt_in);
 
         Ok(())
     }
-    // Insurance – automated claim verification & settlement
 
-    /// Automatically verify and settle an insurance claim based on on-chain data.
+    /// Get the members who opted into the group's next round.
     ///
-    /// Replaces the manual admin-approval flow with fully automated, trustless
-    
-    pub fn auto_verify_insurance_claim(env: Env, claim_id: u64) -> Result<(), AjoError> {
-        // Honour the global pause flag so this endpoint is blocked during emergencies.
-        pausable::ensure_not_paused(&env)?;
-
-        crate::insurance::auto_process_claim(&env, claim_id)
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The group
+    pub fn get_rollover_opt_ins(env: Env, group_id: u64) -> Vec<Address> {
+        storage::get_rollover_opt_ins(&env, group_id)
     }
 
-    /// Get insurance pool information for a token.
+    /// Get the ids of every round of a recurring group up to this one.
     ///
-    /// Returns the current balance, total payouts, and pending claims count
-    /// for the insurance pool associated with the given token address.
+    /// Follows `predecessor_id` links back to the first round.
     ///
     /// # Arguments
-    /// * `env`           - The Soroban contract environment
-    /// * `token_address` - Token contract address for the pool to query
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The group
     ///
     /// # Returns
-    /// `Ok(InsurancePool)` containing pool balance and statistics.
+    /// Group ids from the first round to `group_id`, oldest first
     ///
     /// # Errors
-    /// * `PoolNotFound` – no pool exists for the given token
-    pub fn get_insurance_pool_info(
-        env: Env,
-        token_address: Address,
-    ) -> Result<crate::types::InsurancePool, AjoError> {
-        crate::insurance::get_pool_info(&env, &token_address)
-    }
-
-    // Query helpers
-    
-    pub fn is_complete(env: Env, group_id: u64) -> Result<bool, AjoError> {
-        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
-        Ok(group.is_complete)
+    /// * `GroupNotFound` - If the group does not exist
+    pub fn get_group_rounds(env: Env, group_id: u64) -> Result<Vec<u64>, AjoError> {
+        let mut group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        let mut rounds = Vec::new(&env);
+        rounds.push_front(group.id);
+        while group.predecessor_id != 0 {
+            group = storage::get_group(&env, group.predecessor_id).ok_or(AjoError::GroupNotFound)?;
+            rounds.push_front(group.id);
+        }
+        Ok(rounds)
     }
 
-    /// Get comprehensive group status.
-    ///
-    /// Returns a detailed snapshot of the group's current state, including cycle
-    /// information, contribution status, payout progress, and timing data.
-    /// This is the primary function for checking a group's overall progress.
+    /// Allowlist a lending adapter that groups can park their pots with.
     ///
-    /// Returns information about:
-    /// - Current cycle number and progress
-    /// - Next recipient for payout
-    /// - Members who have contributed and those who are pending
-    /// - Cycle timing (start time, end time, whether cycle is active)
-    /// - Whether the group is complete
+    /// Only the admin can call this function. The adapter must implement
+    /// [`YieldAdapter`](crate::YieldAdapter) and lend out `token`.
     ///
     /// # Arguments
     /// * `env` - The Soroban contract environment
-    /// * `group_id` - The unique group identifier
-    ///
-    /// # Returns
-    /// A `GroupStatus` struct containing comprehensive group state information
+    /// * `adapter` - The adapter contract
+    /// * `token` - The token the adapter lends out
     ///
     /// # Errors
-    /// * `GroupNotFound` - If the group does not exist
-    pub fn get_group_status(env: Env, group_id: u64) -> Result<GroupStatus, AjoError> {
-        // Get the group data (single fetch)
-        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+    /// * `Unauthorized` - If the contract has no admin
+    pub fn allow_yield_adapter(env: Env, adapter: Address, token: Address) -> Result<(), AjoError> {
+        let admin = storage::get_admin(&env).ok_or(AjoError::Unauthorized)?;
+        admin.require_auth();
 
-        // Cache frequently accessed values
-        let current_time = utils::get_current_timestamp(&env);
-        let _member_count = group.members.len();
-        let group_id_cached = group.id;
-        let current_cycle = group.current_cycle;
+        storage::store_yield_adapter(&env, &adapter, &token);
+        events::emit_yield_adapter_allowed(&env, &adapter, &token, true);
 
-        // Calculate cycle timing
-        let cycle_end_time = group.cycle_start_time + group.cycle_duration;
-        let grace_period_end_time = utils::get_grace_period_end(&group);
-        let is_cycle_active = current_time < cycle_end_time;
-        let is_in_grace_period = utils::is_within_grace_period(&group, current_time);
+        Ok(())
+    }
 
-        // Get penalty pool for current cycle
-        let cycle_penalty_pool = storage::get_cycle_penalty_pool(&env, group_id, group.current_cycle);
+    /// Remove a lending adapter from the allowlist.
+    ///
+    /// Groups using it stop parking new contributions; funds already parked
+    /// are still withdrawn at the next payout or refund.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `adapter` - The adapter contract
+    ///
+    /// # Errors
+    /// * `Unauthorized` - If the contract has no admin
+    /// * `InvalidInput` - If the adapter is not allowlisted
+    pub fn remove_yield_adapter(env: Env, adapter: Address) -> Result<(), AjoError> {
+        let admin = storage::get_admin(&env).ok_or(AjoError::Unauthorized)?;
+        admin.require_auth();
 
-        // Build pending_contributors list
-        let mut contributions_received: u32 = 0;
-        let mut pending_contributors = Vec::new(&env);
+        let token = storage::get_yield_adapter(&env, &adapter).ok_or(AjoError::InvalidInput)?;
+        storage::remove_yield_adapter(&env, &adapter);
+        events::emit_yield_adapter_allowed(&env, &adapter, &token, false);
 
-        // Single pass through members to check contributions
-        for member in group.members.iter() {
-            if storage::has_contributed(&env, group_id_cached, current_cycle, &member) {
-                contributions_received += 1;
-            } else {
-                pending_contributors.push_back(member);
-            }
-        }
+        Ok(())
+    }
 
-        // Determine next recipient according to the configured strategy without
-        // committing payout-order storage from a read-only status query.
-        let (has_next_recipient, next_recipient) = if group.is_complete {
-            (false, group.creator.clone())
-        } else {
-            match utils::preview_next_recipient(&env, &group) {
-                Ok(recipient) => (true, recipient),
-                Err(_) => (false, group.creator.clone()),
-            }
-        };
+    /// Get the token an allowlisted adapter lends out.
+    ///
+    /// # Returns
+    /// The token, or `None` if the adapter is not allowlisted
+    pub fn get_yield_adapter(env: Env, adapter: Address) -> Option<Address> {
+        storage::get_yield_adapter(&env, &adapter)
+    }
 
-        // Build and return status
-        Ok(GroupStatus {
-            group_id: group.id,
-            current_cycle: group.current_cycle,
-            has_next_recipient,
-            next_recipient,
-            contributions_received,
-            total_members: group.members.len() as u32,
-            pending_contributors,
-            is_complete: group.is_complete,
-            is_cycle_active,
-            cycle_start_time: group.cycle_start_time,
-            cycle_end_time,
-            current_time,
-            cycle_penalty_pool,
-            is_in_grace_period,
-            grace_period_end_time,
-        })
+    /// Park the group's collected contributions with a lending adapter.
+    ///
+    /// From then on each contribution's share of the pot is deposited with
+    /// the adapter and withdrawn again before the payout or any refund. Yield
+    /// earned on top goes where `distribution` says.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `creator` - The group creator (must authenticate)
+    /// * `group_id` - The group
+    /// * `adapter` - An allowlisted adapter for the group's token
+    /// * `distribution` - Who receives the yield
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `Unauthorized` - If the caller is not the group creator
+    /// * `GroupComplete` - If the group has completed all cycles
+    /// * `GroupCancelled` - If the group has been cancelled
+    /// * `InvalidMultiTokenConfig` - If the group accepts several tokens
+    /// * `InvalidInput` - If the adapter is not allowlisted for the group's token
+    /// * `InvalidState` - If funds are parked with the current adapter
+    pub fn enable_yield(
+        env: Env,
+        creator: Address,
+        group_id: u64,
+        adapter: Address,
+        distribution: crate::types::YieldDistribution,
+    ) -> Result<(), AjoError> {
+        pausable::ensure_not_paused(&env)?;
+        creator.require_auth();
+
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        if group.creator != creator {
+            return Err(AjoError::Unauthorized);
+        }
+        if group.is_complete {
+            return Err(AjoError::GroupComplete);
+        }
+        if group.state == crate::types::GroupState::Cancelled {
+            return Err(AjoError::GroupCancelled);
+        }
+        if storage::get_multi_token_config(&env, group_id).is_some() {
+            return Err(AjoError::InvalidMultiTokenConfig);
+        }
+        if storage::get_yield_adapter(&env, &adapter) != Some(group.token_address.clone()) {
+            return Err(AjoError::InvalidInput);
+        }
+        if storage::get_yield_position(&env, group_id).is_some() {
+            return Err(AjoError::InvalidState);
+        }
+
+        storage::store_yield_config(
+            &env,
+            group_id,
+            &crate::types::YieldConfig { adapter: adapter.clone(), distribution },
+        );
+        events::emit_yield_enabled(&env, group_id, &adapter, distribution as u32);
+
+        Ok(())
     }
 
-    /// Set or update metadata for an Ajo group.
+    /// Stop parking the group's contributions with a lending adapter.
     ///
-    /// Only the group creator can set or update metadata.
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `creator` - The group creator (must authenticate)
+    /// * `group_id` - The group
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `Unauthorized` - If the caller is not the group creator
+    /// * `InvalidState` - If funds are still parked until the next payout
+    pub fn disable_yield(env: Env, creator: Address, group_id: u64) -> Result<(), AjoError> {
+        pausable::ensure_not_paused(&env)?;
+        creator.require_auth();
+
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        if group.creator != creator {
+            return Err(AjoError::Unauthorized);
+        }
+        if storage::get_yield_position(&env, group_id).is_some() {
+            return Err(AjoError::InvalidState);
+        }
+
+        storage::remove_yield_config(&env, group_id);
+        events::emit_yield_disabled(&env, group_id);
+
+        Ok(())
+    }
+
+    /// Get the group's yield adapter settings.
+    ///
+    /// # Returns
+    /// The [`YieldConfig`](crate::types::YieldConfig), or `None` if the group has not opted in
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    pub fn get_yield_config(
+        env: Env,
+        group_id: u64,
+    ) -> Result<Option<crate::types::YieldConfig>, AjoError> {
+        storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        Ok(storage::get_yield_config(&env, group_id))
+    }
+
+    /// Get the group's funds currently parked with its yield adapter.
+    ///
+    /// # Returns
+    /// The [`YieldPosition`](crate::types::YieldPosition), or `None` if nothing is parked
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    pub fn get_yield_position(
+        env: Env,
+        group_id: u64,
+    ) -> Result<Option<crate::types::YieldPosition>, AjoError> {
+        storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        Ok(storage::get_yield_position(&env, group_id))
+    }
+
+    /// Change how new members can join a group.
+    ///
+    /// Only the group creator can change the access type.
     ///
     /// # Arguments
     /// * `env` - The Soroban contract environment
-    /// * `group_id` - The unique group identifier
-    /// * `name` - The name of the group
-    /// * `description` - The description of the group
-    /// * `rules` - The rules of the group
+    /// * `creator` - Address of the group creator (must authenticate)
+    /// * `group_id` - The group to update
+    /// * `access_type` - The new [`GroupAccessType`]
     ///
     /// # Errors
     /// * `GroupNotFound` - If the group does not exist
     /// * `Unauthorized` - If the caller is not the group creator
-    /// * `MetadataTooLong` - If any field exceeds its length limit
-    pub fn set_group_metadata(
+    /// * `GroupComplete` - If the group has completed all cycles
+    /// * `GroupCancelled` - If the group has been cancelled
+    pub fn set_group_access_type(
         env: Env,
+        creator: Address,
         group_id: u64,
-        name: soroban_sdk::String,
-        description: soroban_sdk::String,
-        rules: soroban_sdk::String,
+        access_type: GroupAccessType,
     ) -> Result<(), AjoError> {
-        // Validate lengths
-        if name.len() > crate::types::MAX_NAME_LENGTH
-            || description.len() > crate::types::MAX_DESCRIPTION_LENGTH
-            || rules.len() > crate::types::MAX_RULES_LENGTH
-        {
-            return Err(AjoError::MetadataTooLong);
+        pausable::ensure_not_paused(&env)?;
+        creator.require_auth();
+
+        let mut group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        if group.creator != creator {
+            return Err(AjoError::Unauthorized);
+        }
+        if group.is_complete {
+            return Err(AjoError::GroupComplete);
+        }
+        if group.state == crate::types::GroupState::Cancelled {
+            return Err(AjoError::GroupCancelled);
         }
 
-        // Get group to verify existence and check creator
+        group.access_type = access_type;
+        storage::store_group(&env, group_id, &group);
+
+        events::emit_access_type_changed(&env, group_id, access_type);
+
+        Ok(())
+    }
+
+    /// Invite an address to join a group.
+    ///
+    /// Only the group creator can issue invitations. Re-inviting an address
+    /// replaces any earlier unused invitation.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `creator` - Address of the group creator (must authenticate)
+    /// * `group_id` - The group to invite to
+    /// * `invitee` - The address being invited
+    /// * `expires_in` - Seconds from now until the invitation expires
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `Unauthorized` - If the caller is not the group creator
+    /// * `AlreadyMember` - If the invitee is already a member
+    /// * `GroupComplete` - If the group has completed all cycles
+    /// * `GroupCancelled` - If the group has been cancelled
+    /// * `MaxMembersExceeded` - If the group is full
+    pub fn invite_member(
+        env: Env,
+        creator: Address,
+        group_id: u64,
+        invitee: Address,
+        expires_in: u64,
+    ) -> Result<(), AjoError> {
+        pausable::ensure_not_paused(&env)?;
+        creator.require_auth();
+
         let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        if group.creator != creator {
+            return Err(AjoError::Unauthorized);
+        }
+        utils::validate_can_join(&env, &group, &invitee)?;
 
-        // Require creator authentication
-        group.creator.require_auth();
+        utils::issue_invitation(&env, group_id, &creator, &invitee, expires_in, group.contribution_amount);
 
-        // Create and store metadata
-        let metadata = GroupMetadata {
-            name,
-            description,
-            rules,
+        Ok(())
+    }
+
+    /// Invite an address to join a group at its own contribution tier.
+    ///
+    /// Works like [`invite_member`](Self::invite_member); once the invitee
+    /// joins they pay `contribution_amount` per share each cycle instead of
+    /// the group's amount, and are paid out in proportion.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `creator` - Address of the group creator (must authenticate)
+    /// * `group_id` - The group to invite to
+    /// * `invitee` - The address being invited
+    /// * `expires_in` - Seconds from now until the invitation expires
+    /// * `contribution_amount` - The invitee's contribution per share per cycle
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `Unauthorized` - If the caller is not the group creator
+    /// * `AlreadyMember` - If the invitee is already a member
+    /// * `GroupComplete` - If the group has completed all cycles
+    /// * `GroupCancelled` - If the group has been cancelled
+    /// * `MaxMembersExceeded` - If the group is full
+    /// * `ContributionAmountZero` - If `contribution_amount == 0`
+    /// * `ContributionAmountNegative` - If `contribution_amount < 0`
+    /// * `InvalidMultiTokenConfig` - If the group accepts several tokens
+    pub fn invite_member_with_tier(
+        env: Env,
+        creator: Address,
+        group_id: u64,
+        invitee: Address,
+        expires_in: u64,
+        contribution_amount: i128,
+    ) -> Result<(), AjoError> {
+        pausable::ensure_not_paused(&env)?;
+        creator.require_auth();
+
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        if group.creator != creator {
+            return Err(AjoError::Unauthorized);
+        }
+        utils::validate_can_join(&env, &group, &invitee)?;
+        utils::validate_contribution_tier(&env, &group, contribution_amount)?;
+
+        utils::issue_invitation(&env, group_id, &creator, &invitee, expires_in, contribution_amount);
+
+        Ok(())
+    }
+
+    /// Revoke an unused invitation.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `creator` - Address of the group creator (must authenticate)
+    /// * `group_id` - The group the invitation is for
+    /// * `invitee` - The invited address
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `Unauthorized` - If the caller is not the group creator, or if no invitation
+    ///   exists for the invitee
+    /// * `AlreadyMember` - If the invitee has already joined
+    pub fn revoke_invitation(
+        env: Env,
+        creator: Address,
+        group_id: u64,
+        invitee: Address,
+    ) -> Result<(), AjoError> {
+        pausable::ensure_not_paused(&env)?;
+        creator.require_auth();
+
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        if group.creator != creator {
+            return Err(AjoError::Unauthorized);
+        }
+
+        let invitation = storage::get_invitation(&env, group_id, &invitee)
+            .ok_or(AjoError::Unauthorized)?;
+        if invitation.accepted {
+            return Err(AjoError::AlreadyMember);
+        }
+
+        storage::remove_invitation(&env, group_id, &invitee);
+        let mut invitees = storage::get_invitation_list(&env, group_id);
+        if let Some(index) = invitees.first_index_of(&invitee) {
+            invitees.remove(index);
+            storage::store_invitation_list(&env, group_id, &invitees);
+        }
+
+        events::emit_invitation_revoked(&env, group_id, &invitee);
+
+        Ok(())
+    }
+
+    /// Accept an invitation and join the group.
+    ///
+    /// Works regardless of the group's current access type, so creators can
+    /// also use invitations to admit members to approval-required groups.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `invitee` - The invited address (must authenticate)
+    /// * `group_id` - The group to join
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `Unauthorized` - If no invitation exists for the invitee, or if the invitation
+    ///   has expired
+    /// * `AlreadyMember` - If the invitation was already used, or if the invitee is
+    ///   already a member
+    /// * `MaxMembersExceeded` - If the group is full
+    pub fn accept_invitation(env: Env, invitee: Address, group_id: u64) -> Result<(), AjoError> {
+        pausable::ensure_not_paused(&env)?;
+        invitee.require_auth();
+
+        let mut group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        utils::validate_can_join(&env, &group, &invitee)?;
+
+        let mut invitation = utils::validate_invitation(&env, group_id, &invitee)?;
+        invitation.accepted = true;
+        storage::store_invitation(&env, group_id, &invitee, &invitation);
+
+        utils::escrow_collateral(&env, &group, &invitee)?;
+        utils::set_contribution_tier(&env, &group, &invitee, invitation.contribution_amount);
+        utils::admit_member(&env, &mut group, &invitee);
+        events::emit_invitation_accepted(&env, group_id, &invitee);
+
+        Ok(())
+    }
+
+    /// Ask to join an approval-required group.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `requester` - The address asking to join (must authenticate)
+    /// * `group_id` - The group to join
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `Unauthorized` - If the group does not take join requests
+    /// * `InvalidState` - If the requester already has a pending request
+    /// * `AlreadyMember` - If the requester is already a member
+    /// * `MaxMembersExceeded` - If the group is full
+    pub fn request_to_join(env: Env, requester: Address, group_id: u64) -> Result<(), AjoError> {
+        pausable::ensure_not_paused(&env)?;
+        requester.require_auth();
+
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        utils::validate_can_join(&env, &group, &requester)?;
+        if group.access_type != GroupAccessType::ApprovalRequired {
+            return Err(AjoError::Unauthorized);
+        }
+        let pending = storage::get_join_request(&env, group_id, &requester)
+            .is_some_and(|request| !request.approved);
+        if pending {
+            return Err(AjoError::InvalidState);
+        }
+
+        // Collateral is escrowed with the request and returned if it is rejected
+        utils::escrow_collateral(&env, &group, &requester)?;
+
+        let request = crate::types::JoinRequest {
+            group_id,
+            requester: requester.clone(),
+            created_at: utils::get_current_timestamp(&env),
+            approved: false,
+        };
+        storage::store_join_request(&env, group_id, &requester, &request);
+
+        let mut requesters = storage::get_join_request_list(&env, group_id);
+        requesters.push_back(requester.clone());
+        storage::store_join_request_list(&env, group_id, &requesters);
+
+        events::emit_join_requested(&env, group_id, &requester);
+
+        Ok(())
+    }
+
+    /// Approve a pending join request and add the requester to the group.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `creator` - Address of the group creator (must authenticate)
+    /// * `group_id` - The group being joined
+    /// * `requester` - The address whose request is approved
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `Unauthorized` - If the caller is not the group creator
+    /// * `InvalidInput` - If there is no pending request
+    /// * `MaxMembersExceeded` - If the group is full
+    pub fn approve_join_request(
+        env: Env,
+        creator: Address,
+        group_id: u64,
+        requester: Address,
+    ) -> Result<(), AjoError> {
+        pausable::ensure_not_paused(&env)?;
+        creator.require_auth();
+
+        let mut group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        if group.creator != creator {
+            return Err(AjoError::Unauthorized);
+        }
+
+        storage::get_join_request(&env, group_id, &requester)
+            .filter(|request| !request.approved)
+            .ok_or(AjoError::InvalidInput)?;
+        utils::validate_can_join(&env, &group, &requester)?;
+
+        // The request is spent once approved, so a member who later leaves can ask again
+        storage::remove_join_request(&env, group_id, &requester);
+        utils::remove_pending_join_request(&env, group_id, &requester);
+
+        utils::admit_member(&env, &mut group, &requester);
+        events::emit_join_approved(&env, group_id, &requester);
+
+        Ok(())
+    }
+
+    /// Reject a pending join request.
+    ///
+    /// The requester may ask again afterwards.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `creator` - Address of the group creator (must authenticate)
+    /// * `group_id` - The group the request is for
+    /// * `requester` - The address whose request is rejected
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `Unauthorized` - If the caller is not the group creator
+    /// * `InvalidInput` - If there is no pending request
+    pub fn reject_join_request(
+        env: Env,
+        creator: Address,
+        group_id: u64,
+        requester: Address,
+    ) -> Result<(), AjoError> {
+        pausable::ensure_not_paused(&env)?;
+        creator.require_auth();
+
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        if group.creator != creator {
+            return Err(AjoError::Unauthorized);
+        }
+
+        storage::get_join_request(&env, group_id, &requester)
+            .filter(|request| !request.approved)
+            .ok_or(AjoError::InvalidInput)?;
+
+        storage::remove_join_request(&env, group_id, &requester);
+        utils::remove_pending_join_request(&env, group_id, &requester);
+        utils::return_collateral(&env, &group, &requester)?;
+
+        events::emit_join_rejected(&env, group_id, &requester);
+
+        Ok(())
+    }
+
+    /// Get an address's invitation to a group.
+    ///
+    /// # Errors
+    /// * `Unauthorized` - If no invitation exists
+    pub fn get_invitation(
+        env: Env,
+        group_id: u64,
+        invitee: Address,
+    ) -> Result<crate::types::GroupInvitation, AjoError> {
+        storage::get_invitation(&env, group_id, &invitee).ok_or(AjoError::Unauthorized)
+    }
+
+    /// List every invitation issued for a group, including accepted ones.
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    pub fn get_group_invitations(
+        env: Env,
+        group_id: u64,
+    ) -> Result<Vec<crate::types::GroupInvitation>, AjoError> {
+        storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+
+        let mut invitations = Vec::new(&env);
+        for invitee in storage::get_invitation_list(&env, group_id).iter() {
+            if let Some(invitation) = storage::get_invitation(&env, group_id, &invitee) {
+                invitations.push_back(invitation);
+            }
+        }
+        Ok(invitations)
+    }
+
+    /// List the pending join requests for a group, oldest first.
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    pub fn get_pending_join_requests(
+        env: Env,
+        group_id: u64,
+    ) -> Result<Vec<crate::types::JoinRequest>, AjoError> {
+        storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+
+        let mut requests = Vec::new(&env);
+        for requester in storage::get_join_request_list(&env, group_id).iter() {
+            if let Some(request) = storage::get_join_request(&env, group_id, &requester) {
+                requests.push_back(request);
+            }
+        }
+        Ok(requests)
+    }
+
+    /// Leave a group before its first payout.
+    ///
+    /// Whatever the member has paid towards the current cycle is refunded,
+    /// less any insurance premium already taken from it, and their collateral
+    /// is returned. The group creator cannot leave.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `member` - Address of the member leaving (must authenticate)
+    /// * `group_id` - The group to leave
+    ///
+    /// # Returns
+    /// The amount refunded
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `GroupComplete` - If the group has completed all cycles
+    /// * `GroupCancelled` - If the group has been cancelled
+    /// * `NotMember` - If the address is not a member
+    /// * `Unauthorized` - If the member is the group creator
+    /// * `CannotCancelAfterPayout` - If the group has already paid someone out
+    pub fn leave_group(env: Env, member: Address, group_id: u64) -> Result<i128, AjoError> {
+        pausable::ensure_not_paused(&env)?;
+        member.require_auth();
+
+        let mut group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        if group.is_complete {
+            return Err(AjoError::GroupComplete);
+        }
+        if group.state == crate::types::GroupState::Cancelled {
+            return Err(AjoError::GroupCancelled);
+        }
+        let index = group.members.first_index_of(&member).ok_or(AjoError::NotMember)?;
+        if group.creator == member {
+            return Err(AjoError::Unauthorized);
+        }
+        if group.payout_index > 0 {
+            return Err(AjoError::CannotCancelAfterPayout);
+        }
+
+        let refunded = utils::refund_member_contribution(&env, &group, &member)?;
+        utils::return_collateral(&env, &group, &member)?;
+        utils::refund_prepayment(&env, &group, &member)?;
+
+        group.members.remove(index);
+        storage::store_group(&env, group_id, &group);
+        if let Some(mut schedule) = storage::get_payout_schedule(&env, group_id) {
+            while let Some(slot) = schedule.recipients.first_index_of(&member) {
+                schedule.recipients.remove(slot);
+            }
+            storage::store_payout_schedule(&env, &schedule);
+        }
+        storage::remove_member_shares(&env, group_id, &member);
+        storage::remove_contribution_tier(&env, group_id, &member);
+
+        let mut stats = storage::get_member_stats(&env, &member)
+            .unwrap_or_else(|| utils::default_member_stats(&env, &member));
+        stats.total_groups_joined = stats.total_groups_joined.saturating_sub(1);
+        storage::store_member_stats(&env, &member, &stats);
+
+        events::emit_member_left(&env, group_id, &member, refunded);
+
+        Ok(refunded)
+    }
+
+    /// Hand a member's slot to a replacement.
+    ///
+    /// The replacement takes over the member's position in the payout order
+    /// along with the current cycle's contribution and payout status. The
+    /// outgoing member's collateral and any prepaid cycles are returned and
+    /// the replacement escrows their own collateral. Requires the creator, the outgoing member and the
+    /// replacement to authenticate.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `creator` - The group creator (must authenticate)
+    /// * `group_id` - The group
+    /// * `from` - The member handing over their slot (must authenticate)
+    /// * `to` - The replacement (must authenticate)
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `Unauthorized` - If the caller is not the group creator, or if `from` is the
+    ///   group creator
+    /// * `GroupComplete` - If the group has completed all cycles
+    /// * `GroupCancelled` - If the group has been cancelled
+    /// * `NotMember` - If `from` is not a member
+    /// * `AlreadyMember` - If `to` is already a member
+    /// * `InsufficientBalance` - If `to` cannot cover the collateral
+    pub fn transfer_membership(
+        env: Env,
+        creator: Address,
+        group_id: u64,
+        from: Address,
+        to: Address,
+    ) -> Result<(), AjoError> {
+        pausable::ensure_not_paused(&env)?;
+        creator.require_auth();
+        from.require_auth();
+        to.require_auth();
+
+        let mut group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        if group.creator != creator {
+            return Err(AjoError::Unauthorized);
+        }
+        if group.is_complete {
+            return Err(AjoError::GroupComplete);
+        }
+        if group.state == crate::types::GroupState::Cancelled {
+            return Err(AjoError::GroupCancelled);
+        }
+        if !utils::is_member(&group.members, &from) {
+            return Err(AjoError::NotMember);
+        }
+        if from == group.creator {
+            return Err(AjoError::Unauthorized);
+        }
+        if utils::is_member(&group.members, &to) {
+            return Err(AjoError::AlreadyMember);
+        }
+
+        utils::transfer_slot(&env, &mut group, &from, &to)?;
+
+        events::emit_membership_transferred(&env, group_id, &from, &to);
+
+        Ok(())
+    }
+
+    /// Set how many shares ("hands") a member holds in a group.
+    ///
+    /// Each share adds one contribution per cycle, one payout slot in the
+    /// rotation and one vote. Shares count towards `max_members`, so a group
+    /// of five with one member holding two shares is full at four members.
+    /// Shares can only change before the first payout and before the member
+    /// has paid anything towards the current cycle. If the new count fills
+    /// the group, a forming group is started and its payout schedule is fixed.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `member` - The member (must authenticate)
+    /// * `group_id` - The group
+    /// * `shares` - The number of shares to hold (at least 1)
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `GroupComplete` - If the group has completed all cycles
+    /// * `GroupCancelled` - If the group has been cancelled
+    /// * `NotMember` - If the address is not a member
+    /// * `InvalidInput` - If `shares == 0`
+    /// * `InvalidState` - If a payout has been made, the schedule is fixed or
+    ///   the member has paid towards the current cycle
+    /// * `MaxMembersExceeded` - If the shares would exceed `max_members`
+    pub fn set_member_shares(
+        env: Env,
+        member: Address,
+        group_id: u64,
+        shares: u32,
+    ) -> Result<(), AjoError> {
+        pausable::ensure_not_paused(&env)?;
+        member.require_auth();
+
+        let mut group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        if group.is_complete {
+            return Err(AjoError::GroupComplete);
+        }
+        if group.state == crate::types::GroupState::Cancelled {
+            return Err(AjoError::GroupCancelled);
+        }
+        if !utils::is_member(&group.members, &member) {
+            return Err(AjoError::NotMember);
+        }
+        if shares == 0 {
+            return Err(AjoError::InvalidInput);
+        }
+        if group.payout_index > 0
+            || storage::get_payout_schedule(&env, group_id).is_some()
+            || utils::get_amount_paid(&env, &group, group.current_cycle, &member) > 0
+        {
+            return Err(AjoError::InvalidState);
+        }
+
+        let current = storage::get_member_shares(&env, group_id, &member);
+        let total = utils::total_shares(&env, &group) - current + shares;
+        if total > group.max_members {
+            return Err(AjoError::MaxMembersExceeded);
+        }
+
+        storage::store_member_shares(&env, group_id, &member, shares);
+        events::emit_member_shares_changed(&env, group_id, &member, shares);
+
+        let starting = group.state == crate::types::GroupState::Forming && total >= group.max_members;
+        if starting {
+            utils::start_cycles(&env, &mut group);
+            storage::store_group(&env, group_id, &group);
+        }
+        // Shares are stored apart from the group, so its open seats are reindexed here
+        storage::update_open_seat_index(&env, &group);
+        if !starting
+            && group.schedule_rule != crate::types::ScheduleRule::Unlocked
+            && total >= group.max_members
+        {
+            utils::lock_payout_schedule(&env, &group);
+        }
+
+        Ok(())
+    }
+
+    /// Get how many shares a member holds in a group (1 unless changed).
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The group
+    /// * `member` - The member's address
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `NotMember` - If the address is not a member
+    pub fn get_member_shares(env: Env, group_id: u64, member: Address) -> Result<u32, AjoError> {
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        if !utils::is_member(&group.members, &member) {
+            return Err(AjoError::NotMember);
+        }
+        Ok(storage::get_member_shares(&env, group_id, &member))
+    }
+
+    /// Check if an address is a member of a group.
+    ///
+    /// Returns whether the provided address is currently a member of the specified group.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The group to check
+    /// * `address` - The address to check
+    ///
+    /// # Returns
+    /// `true` if the address is a member, `false` otherwise
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    pub fn is_member(env: Env, group_id: u64, address: Address) -> Result<bool, AjoError> {
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        Ok(utils::is_member(&group.members, &address))
+    }
+
+    /// Contribute to the current cycle.
+    ///
+    /// Records a member's contribution for the current cycle and transfers tokens from
+    /// the member to the contract. Each member can contribute once per cycle.
+    /// Authentication is required.
+    ///
+    /// The function transfers the contribution amount from the member's token balance
+    /// to the contract. If the member has already paid part of the cycle through
+    /// [`contribute_partial`](Self::contribute_partial), only the outstanding balance
+    /// is transferred. Late contributions (after cycle ends but within grace period)
+    /// incur penalties. Contributions after grace period are rejected.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `member` - Address making the contribution (must authenticate)
+    /// * `group_id` - The group to contribute to
+    ///
+    /// # Returns
+    /// `Ok(())` on successful contribution and token transfer
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `NotMember` - If the address is not a member
+    /// * `AlreadyContributed` - If already contributed this cycle
+    /// * `GroupComplete` - If the group has completed all cycles
+    /// * `InvalidState` - If the group is still forming
+    /// * `GracePeriodExpired` - If contribution is too late (after grace period)
+    /// * `InsufficientBalance` - If member doesn't have enough tokens
+    /// * `TransferFailed` - If the token transfer fails
+    pub fn contribute(env: Env, member: Address, group_id: u64) -> Result<(), AjoError> {
+        // Check if paused
+        pausable::ensure_not_paused(&env)?;
+
+        // Require authentication
+        member.require_auth();
+
+        // Get group (single fetch)
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+
+        // Cache frequently accessed values
+        let group_id_cached = group.id;
+        let current_cycle = group.current_cycle;
+
+        // Check if group is complete
+        if group.is_complete {
+            return Err(AjoError::GroupComplete);
+        }
+
+        // Check if group is cancelled
+        if group.state == crate::types::GroupState::Cancelled {
+            return Err(AjoError::GroupCancelled);
+        }
+
+        // Check if group has started
+        if group.state == crate::types::GroupState::Forming {
+            return Err(AjoError::InvalidState);
+        }
+
+        // Check if member
+        if !utils::is_member(&group.members, &member) {
+            return Err(AjoError::NotMember);
+        }
+
+        // Check if already contributed
+        if storage::has_contributed(&env, group_id_cached, current_cycle, &member) {
+            return Err(AjoError::AlreadyContributed);
+        }
+
+        // Transfer the outstanding contribution and record it
+        utils::collect_contribution(&env, &group, &member, false)
+    }
+
+    /// Turn auto-debit of the member's contributions on or off.
+    ///
+    /// With auto-debit on, the member approves the contract once with the
+    /// token's `approve` function and
+    /// [`collect_contributions`](Self::collect_contributions) pulls each
+    /// cycle's contribution from that allowance.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `member` - The member (must authenticate)
+    /// * `group_id` - The group
+    /// * `enabled` - Whether contributions should be pulled automatically
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `GroupComplete` - If the group has completed all cycles
+    /// * `GroupCancelled` - If the group has been cancelled
+    /// * `NotMember` - If the address is not a member
+    pub fn set_auto_debit(
+        env: Env,
+        member: Address,
+        group_id: u64,
+        enabled: bool,
+    ) -> Result<(), AjoError> {
+        pausable::ensure_not_paused(&env)?;
+        member.require_auth();
+
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        if group.is_complete {
+            return Err(AjoError::GroupComplete);
+        }
+        if group.state == crate::types::GroupState::Cancelled {
+            return Err(AjoError::GroupCancelled);
+        }
+        if !utils::is_member(&group.members, &member) {
+            return Err(AjoError::NotMember);
+        }
+
+        let mut members = storage::get_auto_debit_members(&env, group_id);
+        match (members.first_index_of(&member), enabled) {
+            (None, true) => members.push_back(member.clone()),
+            (Some(index), false) => {
+                members.remove(index);
+            }
+            _ => {}
+        }
+        storage::store_auto_debit_members(&env, group_id, &members);
+
+        events::emit_auto_debit_set(&env, group_id, &member, enabled);
+
+        Ok(())
+    }
+
+    /// Get the members whose contributions are pulled automatically.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The group
+    pub fn get_auto_debit_members(env: Env, group_id: u64) -> Vec<Address> {
+        storage::get_auto_debit_members(&env, group_id)
+    }
+
+    /// Pull the current cycle's contribution from every auto-debit member.
+    ///
+    /// Anyone can call this from [`AUTO_DEBIT_WINDOW`](crate::utils::AUTO_DEBIT_WINDOW)
+    /// before the cycle ends until the grace period ends; contributions
+    /// pulled after the cycle end owe the late penalty. Members who already
+    /// contributed are skipped. A member whose allowance or balance does not
+    /// cover the amount due is reported and left to contribute themselves;
+    /// the rest of the batch still goes through.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The group to collect for
+    ///
+    /// # Returns
+    /// An [`AutoDebitReport`](crate::types::AutoDebitReport) of who was collected and who was not
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `GroupComplete` - If the group has completed all cycles
+    /// * `GroupCancelled` - If the group has been cancelled
+    /// * `InvalidState` - If the group is still forming
+    /// * `OutsideCycleWindow` - If the collection window is not open
+    /// * `TransferFailed` - If a token transfer fails
+    pub fn collect_contributions(
+        env: Env,
+        group_id: u64,
+    ) -> Result<crate::types::AutoDebitReport, AjoError> {
+        pausable::ensure_not_paused(&env)?;
+
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        if group.is_complete {
+            return Err(AjoError::GroupComplete);
+        }
+        if group.state == crate::types::GroupState::Cancelled {
+            return Err(AjoError::GroupCancelled);
+        }
+        if group.state == crate::types::GroupState::Forming {
+            return Err(AjoError::InvalidState);
+        }
+        if !utils::is_within_collection_window(&group, utils::get_current_timestamp(&env)) {
+            return Err(AjoError::OutsideCycleWindow);
+        }
+
+        let mut report = crate::types::AutoDebitReport {
+            cycle: group.current_cycle,
+            collected: Vec::new(&env),
+            insufficient_allowance: Vec::new(&env),
+            insufficient_balance: Vec::new(&env),
+        };
+        for member in storage::get_auto_debit_members(&env, group_id).iter() {
+            if !utils::is_member(&group.members, &member)
+                || storage::has_contributed(&env, group_id, group.current_cycle, &member)
+            {
+                continue;
+            }
+
+            match utils::collect_contribution(&env, &group, &member, true) {
+                Ok(()) => report.collected.push_back(member),
+                Err(error @ (AjoError::InsufficientAllowance | AjoError::InsufficientBalance)) => {
+                    events::emit_auto_debit_failed(
+                        &env,
+                        group_id,
+                        &member,
+                        group.current_cycle,
+                        error as u32,
+                    );
+                    if error == AjoError::InsufficientAllowance {
+                        report.insufficient_allowance.push_back(member);
+                    } else {
+                        report.insufficient_balance.push_back(member);
+                    }
+                }
+                Err(error) => return Err(error),
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// Prepay contributions for future cycles.
+    ///
+    /// The funds are escrowed and one cycle's contribution is applied each
+    /// time the group advances to a new cycle, so the member counts as having
+    /// contributed on time without calling [`contribute`](Self::contribute).
+    /// The current cycle is not covered. Unused prepayments are refunded when
+    /// the group completes or is cancelled, or when the member leaves.
+    ///
+    /// Any insurance premium charged on top of contributions is prepaid at
+    /// the member's current rate.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `member` - Address prepaying (must authenticate)
+    /// * `group_id` - The group to prepay
+    /// * `cycles` - Number of future cycles to cover
+    ///
+    /// # Returns
+    /// The member's prepayment after this call
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `GroupComplete` - If the group has completed all cycles
+    /// * `GroupCancelled` - If the group has been cancelled
+    /// * `NotMember` - If the address is not a member
+    /// * `InvalidInput` - If `cycles` is zero, more cycles would be
+    ///   prepaid than remain after the current one, or the group accepts
+    ///   several tokens
+    /// * `InsufficientBalance` - If the member cannot cover the prepayment
+    pub fn prepay_contributions(
+        env: Env,
+        member: Address,
+        group_id: u64,
+        cycles: u32,
+    ) -> Result<crate::types::Prepayment, AjoError> {
+        pausable::ensure_not_paused(&env)?;
+        member.require_auth();
+
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        if group.is_complete {
+            return Err(AjoError::GroupComplete);
+        }
+        if group.state == crate::types::GroupState::Cancelled {
+            return Err(AjoError::GroupCancelled);
+        }
+        if !utils::is_member(&group.members, &member) {
+            return Err(AjoError::NotMember);
+        }
+        if storage::get_multi_token_config(&env, group_id).is_some() {
+            return Err(AjoError::InvalidInput);
+        }
+
+        let prepaid = storage::get_prepayment(&env, group_id, &member)
+            .map(|prepayment| prepayment.cycles)
+            .unwrap_or(0);
+        let remaining = utils::payout_slot_count(&env, &group)
+            .saturating_sub(group.payout_index + 1);
+        if cycles == 0 || prepaid + cycles > remaining {
+            return Err(AjoError::InvalidInput);
+        }
+
+        utils::escrow_prepayment(&env, &group, &member, cycles)
+    }
+
+    /// Returns a member's prepaid contributions for a group, if any.
+    pub fn get_prepayment(
+        env: Env,
+        group_id: u64,
+        member: Address,
+    ) -> Option<crate::types::Prepayment> {
+        storage::get_prepayment(&env, group_id, &member)
+    }
+
+    /// Pay an installment towards the current cycle's contribution.
+    ///
+    /// Lets members spread a cycle's contribution over several payments. Each
+    /// call transfers `amount` to the contract and adds it to the member's
+    /// running total for the cycle. The member only counts as having
+    /// contributed once the running total reaches the group's
+    /// `contribution_amount`. If the completing installment is made during the
+    /// grace period, the late penalty is transferred along with it.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `member` - Address making the payment (must authenticate)
+    /// * `group_id` - The group to contribute to
+    /// * `amount` - Installment amount in token units
+    ///
+    /// # Returns
+    /// The member's running total for the cycle after this installment
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `NotMember` - If the address is not a member
+    /// * `AlreadyContributed` - If the cycle has already been paid in full
+    /// * `GroupComplete` - If the group has completed all cycles
+    /// * `GroupCancelled` - If the group has been cancelled
+    /// * `InvalidState` - If the group is still forming
+    /// * `ContributionAmountZero` - If `amount == 0`
+    /// * `ContributionAmountNegative` - If `amount < 0`
+    /// * `InvalidInput` - If `amount` is more than is still owed
+    /// * `GracePeriodExpired` - If the payment is made after the grace period
+    /// * `InsufficientBalance` - If member doesn't have enough tokens
+    pub fn contribute_partial(
+        env: Env,
+        member: Address,
+        group_id: u64,
+        amount: i128,
+    ) -> Result<i128, AjoError> {
+        pausable::ensure_not_paused(&env)?;
+        member.require_auth();
+
+        if amount == 0 {
+            return Err(AjoError::ContributionAmountZero);
+        } else if amount < 0 {
+            return Err(AjoError::ContributionAmountNegative);
+        }
+
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        let current_cycle = group.current_cycle;
+
+        if group.is_complete {
+            return Err(AjoError::GroupComplete);
+        }
+        if group.state == crate::types::GroupState::Cancelled {
+            return Err(AjoError::GroupCancelled);
+        }
+        if group.state == crate::types::GroupState::Forming {
+            return Err(AjoError::InvalidState);
+        }
+        if !utils::is_member(&group.members, &member) {
+            return Err(AjoError::NotMember);
+        }
+        if storage::has_contributed(&env, group.id, current_cycle, &member) {
+            return Err(AjoError::AlreadyContributed);
+        }
+
+        let contribution = utils::member_contribution(&env, &group, &member);
+        let remaining = contribution - utils::get_amount_paid(&env, &group, current_cycle, &member);
+        if amount > remaining {
+            return Err(AjoError::InvalidInput);
+        }
+
+        // A late installment that completes the cycle also carries the penalty
+        let (is_late, penalty) =
+            utils::assess_late_penalty(&group, contribution, utils::get_current_timestamp(&env))?;
+        let completes_cycle = amount == remaining;
+        // Auction discount shares credited to the member cover part of the installment
+        let credit = utils::use_auction_credit(&env, group.id, &member, amount);
+        let pot_amount = if completes_cycle { amount + penalty } else { amount };
+        let pot_amount = pot_amount - credit;
+        let transfer_amount = if completes_cycle {
+            pot_amount + crate::insurance::upfront_premium(&env, &group, &member, contribution)
+        } else {
+            pot_amount
+        };
+
+        let contract_address = env.current_contract_address();
+        crate::token::check_balance(&env, &group.token_address, &member, transfer_amount)?;
+        crate::token::transfer_token(
+            &env,
+            &group.token_address,
+            &member,
+            &contract_address,
+            transfer_amount,
+        )?;
+        crate::insurance::adjust_pot_liability(&env, &group.token_address, pot_amount);
+
+        let record = utils::record_payment(&env, &group, &member, amount);
+        events::emit_partial_contribution(
+            &env,
+            group.id,
+            &member,
+            current_cycle,
+            amount,
+            record.amount_paid,
+        );
+
+        // Flip the contribution flag once the cycle is paid in full
+        let deducted = if completes_cycle {
+            utils::complete_contribution(&env, &group, &member, is_late, penalty)
+        } else {
+            0
+        };
+        crate::yield_adapter::park(&env, &group, pot_amount - deducted)?;
+
+        Ok(record.amount_paid)
+    }
+
+    /// Get a member's running installment total for a cycle.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The group to check
+    /// * `cycle` - The cycle number
+    /// * `member` - The member's address
+    ///
+    /// # Returns
+    /// The member's [`PartialContribution`](crate::types::PartialContribution)
+    /// record, with nothing paid if they have not paid towards the cycle
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    pub fn get_contribution_progress(
+        env: Env,
+        group_id: u64,
+        cycle: u32,
+        member: Address,
+    ) -> Result<crate::types::PartialContribution, AjoError> {
+        storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        Ok(utils::partial_contribution(&env, group_id, cycle, &member))
+    }
+
+    /// Get contribution status for all members in a specific cycle.
+    ///
+    /// Returns an ordered list of all members paired with their contribution status
+    /// for the specified cycle. Member order matches the group's member list order.
+    /// Members who have only paid part of the cycle are reported as not contributed.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The group to check
+    /// * `cycle_number` - The cycle to check (typically use current_cycle from group)
+    ///
+    /// # Returns
+    /// Vector of (Address, bool) tuples where `true` indicates the member has contributed
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    pub fn get_contribution_status(
+        env: Env,
+        group_id: u64,
+        cycle_number: u32,
+    ) -> Result<Vec<(Address, bool)>, AjoError> {
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        let mut results = Vec::new(&env);
+        for member in group.members.iter() {
+            let paid = utils::has_paid_in_full(&env, &group, cycle_number, &member);
+            results.push_back((member, paid));
+        }
+        Ok(results)
+    }
+
+    /// Record every member who has not paid the current cycle in full as defaulted.
+    ///
+    /// Can be called by anyone once the grace period has ended. Each defaulter
+    /// gets a [`DefaultRecord`](crate::types::DefaultRecord), and the default is
+    /// counted in their penalty record and member stats. Afterwards
+    /// [`execute_payout`](Self::execute_payout) can proceed with whatever was
+    /// actually collected.
+    ///
+    /// If the group's `forfeit_payout_on_default` option is set, defaulters who
+    /// have not yet been paid lose their payout slot. The last unpaid slot is
+    /// never forfeited, so the current cycle always has a recipient.
+    ///
+    /// Calling this again for the same cycle only records new defaulters.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The group to check
+    ///
+    /// # Returns
+    /// The members newly recorded as defaulted
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `GroupComplete` - If the group has completed all cycles
+    /// * `GroupCancelled` - If the group has been cancelled
+    /// * `InvalidState` - If the group is still forming
+    /// * `OutsideCycleWindow` - If the grace period has not ended yet
+    pub fn mark_defaults(env: Env, group_id: u64) -> Result<Vec<Address>, AjoError> {
+        pausable::ensure_not_paused(&env)?;
+
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        if group.is_complete {
+            return Err(AjoError::GroupComplete);
+        }
+        if group.state == crate::types::GroupState::Cancelled {
+            return Err(AjoError::GroupCancelled);
+        }
+        if group.state == crate::types::GroupState::Forming {
+            return Err(AjoError::InvalidState);
+        }
+
+        // Contributions are accepted up to and including the grace period end
+        let now = utils::get_current_timestamp(&env);
+        if now <= utils::get_grace_period_end(&group) {
+            return Err(AjoError::OutsideCycleWindow);
+        }
+
+        let cycle = group.current_cycle;
+        let mut defaulters = storage::get_cycle_defaults(&env, group_id, cycle);
+        let mut forfeited = storage::get_forfeited_members(&env, group_id);
+        // Only members who have not been paid at all can forfeit, losing the
+        // slots for all of their shares
+        let mut open_slots = if group.forfeit_payout_on_default {
+            utils::payout_slot_count(&env, &group) - group.payout_index
+        } else {
+            0
+        };
+
+        let mut newly_defaulted = Vec::new(&env);
+        for member in group.members.iter() {
+            if utils::has_paid_in_full(&env, &group, cycle, &member)
+                || utils::is_member(&defaulters, &member)
+            {
+                continue;
+            }
+
+            let amount_paid = utils::get_amount_paid(&env, &group, cycle, &member);
+            let shares = storage::get_member_shares(&env, group_id, &member);
+            let payout_forfeited = group.forfeit_payout_on_default
+                && open_slots > shares
+                && !storage::has_received_payout(&env, group_id, &member)
+                && !utils::is_member(&forfeited, &member);
+            if payout_forfeited {
+                forfeited.push_back(member.clone());
+                open_slots -= shares;
+            }
+
+            // Escrowed collateral covers as much of the shortfall as it can
+            let amount_owed = utils::member_contribution(&env, &group, &member) - amount_paid;
+            let collateral_applied = utils::slash_collateral(&env, &group, &member, amount_owed);
+
+            let record = crate::types::DefaultRecord {
+                group_id,
+                cycle,
+                member: member.clone(),
+                amount_owed,
+                amount_paid,
+                marked_at: now,
+                payout_forfeited,
+                collateral_applied,
+            };
+            storage::store_default_record(&env, group_id, cycle, &member, &record);
+            defaulters.push_back(member.clone());
+
+            let mut penalty_record = storage::get_member_penalty(&env, group_id, &member)
+                .unwrap_or_else(|| utils::default_penalty_record(group_id, &member));
+            penalty_record.default_count += 1;
+            penalty_record.reliability_score = utils::calculate_reliability_score(
+                penalty_record.on_time_count,
+                utils::missed_count(&penalty_record),
+            );
+            storage::store_member_penalty(&env, group_id, &member, &penalty_record);
+
+            let mut stats = storage::get_member_stats(&env, &member)
+                .unwrap_or_else(|| utils::default_member_stats(&env, &member));
+            stats.defaulted_contributions += 1;
+            stats.last_incident_at = now;
+            storage::store_member_stats(&env, &member, &stats);
+
+            events::emit_member_defaulted(
+                &env,
+                group_id,
+                &member,
+                cycle,
+                record.amount_owed,
+                payout_forfeited,
+            );
+            newly_defaulted.push_back(member);
+        }
+
+        if !newly_defaulted.is_empty() {
+            storage::store_cycle_defaults(&env, group_id, cycle, &defaulters);
+            if group.forfeit_payout_on_default {
+                storage::store_forfeited_members(&env, group_id, &forfeited);
+            }
+        }
+
+        Ok(newly_defaulted)
+    }
+
+    /// Choose whether defaulting members lose their future payout slot.
+    ///
+    /// Only the group creator can change the policy.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `creator` - Address of the group creator (must authenticate)
+    /// * `group_id` - The group to update
+    /// * `forfeit_payout_on_default` - `true` if defaulters lose their slot
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `Unauthorized` - If the caller is not the group creator
+    /// * `GroupComplete` - If the group has completed all cycles
+    /// * `GroupCancelled` - If the group has been cancelled
+    pub fn set_default_policy(
+        env: Env,
+        creator: Address,
+        group_id: u64,
+        forfeit_payout_on_default: bool,
+    ) -> Result<(), AjoError> {
+        pausable::ensure_not_paused(&env)?;
+        creator.require_auth();
+
+        let mut group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        if group.creator != creator {
+            return Err(AjoError::Unauthorized);
+        }
+        if group.is_complete {
+            return Err(AjoError::GroupComplete);
+        }
+        if group.state == crate::types::GroupState::Cancelled {
+            return Err(AjoError::GroupCancelled);
+        }
+
+        group.forfeit_payout_on_default = forfeit_payout_on_default;
+        storage::store_group(&env, group_id, &group);
+
+        events::emit_default_policy_changed(&env, group_id, forfeit_payout_on_default);
+
+        Ok(())
+    }
+
+    /// Require members to escrow collateral when they join.
+    ///
+    /// Can only be set while the creator is the group's sole member and no
+    /// join requests are pending. The creator's own collateral is escrowed
+    /// (or returned) straight away. Collateral is slashed by
+    /// [`mark_defaults`](Self::mark_defaults) to cover missed contributions
+    /// and returned when the group completes or is cancelled.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `creator` - Address of the group creator (must authenticate)
+    /// * `group_id` - The group to update
+    /// * `collateral_amount` - Collateral per member in token units (0 to disable)
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `Unauthorized` - If the caller is not the group creator
+    /// * `InvalidInput` - If `collateral_amount` is negative
+    /// * `InvalidState` - If other members have joined or asked to join
+    /// * `InsufficientBalance` - If the creator cannot cover the collateral
+    pub fn set_collateral_amount(
+        env: Env,
+        creator: Address,
+        group_id: u64,
+        collateral_amount: i128,
+    ) -> Result<(), AjoError> {
+        pausable::ensure_not_paused(&env)?;
+        creator.require_auth();
+
+        let mut group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        if group.creator != creator {
+            return Err(AjoError::Unauthorized);
+        }
+        if collateral_amount < 0 {
+            return Err(AjoError::InvalidInput);
+        }
+        if group.members.len() > 1 || !storage::get_join_request_list(&env, group_id).is_empty() {
+            return Err(AjoError::InvalidState);
+        }
+
+        utils::return_collateral(&env, &group, &creator)?;
+        group.collateral_amount = collateral_amount;
+        utils::escrow_collateral(&env, &group, &creator)?;
+        storage::store_group(&env, group_id, &group);
+
+        Ok(())
+    }
+
+    /// Get the collateral a member currently has escrowed with a group.
+    pub fn get_member_collateral(env: Env, group_id: u64, member: Address) -> i128 {
+        storage::get_collateral(&env, group_id, &member)
+    }
+
+    /// Get the default records for a cycle, in member join order.
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    pub fn get_cycle_defaults(
+        env: Env,
+        group_id: u64,
+        cycle: u32,
+    ) -> Result<Vec<crate::types::DefaultRecord>, AjoError> {
+        storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+
+        let mut records = Vec::new(&env);
+        for member in storage::get_cycle_defaults(&env, group_id, cycle).iter() {
+            if let Some(record) = storage::get_default_record(&env, group_id, cycle, &member) {
+                records.push_back(record);
+            }
+        }
+        Ok(records)
+    }
+
+    /// Get the members who forfeited their payout slot by defaulting.
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    pub fn get_forfeited_members(env: Env, group_id: u64) -> Result<Vec<Address>, AjoError> {
+        storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        Ok(storage::get_forfeited_members(&env, group_id))
+    }
+
+    /// Execute payout for the current cycle.
+    ///
+    /// This is the core function that rotates payouts through group members.
+    /// It verifies that all members have contributed or been marked as defaulted,
+    /// calculates the total payout (including any penalties collected), transfers
+    /// tokens from the contract to the recipient, and advances the cycle. When
+    /// every remaining payout slot has been paid, the group is marked complete.
+    ///
+    /// Payout can only be executed after the grace period expires to ensure all
+    /// late contributions are collected.
+    ///
+    /// Process:
+    /// 1. Verifies every member has contributed or defaulted in the current cycle
+    /// 2. Ensures grace period has expired
+    /// 3. Withdraws the pot from the group's yield adapter, if it has one, and
+    ///    shares out the yield by the group's rule
+    /// 4. Calculates total payout (amount collected this cycle + penalties),
+    ///    covering defaults from the insurance reserve when insurance is enabled
+    /// 5. Verifies contract has sufficient token balance
+    /// 6. Transfers tokens from contract to recipient
+    /// 7. Records payout to the current recipient
+    /// 8. Emits payout event with penalty bonus
+    /// 9. Advances to next cycle (or marks complete if done), applying any
+    ///    prepaid contributions to the new cycle or refunding them on completion
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The group to execute payout for
+    ///
+    /// # Returns
+    /// `Ok(())` on successful payout execution and token transfer
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `IncompleteContributions` - If a member has neither contributed nor
+    ///   been marked as defaulted (see [`mark_defaults`](Self::mark_defaults))
+    /// * `GroupComplete` - If the group has already completed all payouts
+    /// * `InvalidState` - If the group is still forming
+    /// * `NoMembers` - If the group has no members (should never happen)
+    /// * `OutsideCycleWindow` - If grace period has not expired yet
+    /// * `InsufficientContractBalance` - If contract doesn't have enough tokens
+    /// * `TransferFailed` - If the token transfer fails
+    pub fn execute_payout(env: Env, group_id: u64) -> Result<(), AjoError> {
+        // Check if paused
+        pausable::ensure_not_paused(&env)?;
+
+        // Get group (single fetch)
+        let mut group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+
+        // Check if group is cancelled
+        if group.state == crate::types::GroupState::Cancelled {
+            return Err(AjoError::GroupCancelled);
+        }
+        if group.state == crate::types::GroupState::Forming {
+            return Err(AjoError::InvalidState);
+        }
+
+        // Check if group is complete
+        if group.is_complete {
+            return Err(AjoError::GroupComplete);
+        }
+
+        // Cache frequently accessed values
+        let group_id_cached = group.id;
+        let current_cycle = group.current_cycle;
+
+        // Check every member has contributed or defaulted, and total the pot
+        let collected = utils::cycle_collected_amount(&env, &group)?;
+
+        // Ensure grace period has expired before executing payout
+        let current_time = utils::get_current_timestamp(&env);
+        let grace_end = utils::get_grace_period_end(&group);
+        if current_time < grace_end {
+            // Still within grace period - delay payout
+            return Err(AjoError::OutsideCycleWindow);
+        }
+
+        // Get payout recipient using the group's ordering strategy
+        let payout_recipient = utils::determine_next_recipient(&env, &group)?;
+
+        // Bring the pot back from the yield adapter before paying it out; any
+        // loss there is made up from the insurance reserve where possible
+        let yield_earned = crate::yield_adapter::recall(&env, &group);
+        let shortfall = -yield_earned.min(0);
+        let shortfall = shortfall - crate::insurance::cover_shortfall(&env, &group, shortfall);
+
+        // Top up the pot from the insurance reserve for any defaults
+        let insured = crate::insurance::cover_cycle_defaults(&env, &group, &payout_recipient);
+
+        // Calculate payout amounts: collected pot (less any premiums deducted
+        // from it and any uncovered yield loss, plus any insured defaults) +
+        // collected penalties for this cycle
+        let base_payout = collected + insured
+            - shortfall
+            - storage::get_cycle_premiums(&env, group_id_cached, current_cycle);
+        // Auction winners give up their discount to the other members
+        let base_payout = base_payout
+            - utils::share_auction_discount(&env, &group, &payout_recipient, base_payout)?;
+        let penalty_bonus = storage::get_cycle_penalty_pool(&env, group_id_cached, current_cycle);
+        // Members on their own tier are paid their share of the pot
+        let payout_amount = utils::apply_tier_adjustment(&env, &group, &payout_recipient, base_payout)
+            + penalty_bonus;
+        // Yield is shared out by the group's rule, possibly on top of the payout
+        let payout_amount = payout_amount
+            + crate::yield_adapter::distribute(&env, &group, Some(&payout_recipient), yield_earned)?;
+
+        // Get contract address for token transfer
+        let contract_address = env.current_contract_address();
+
+        // Verify contract has sufficient balance
+        crate::token::check_contract_balance(
+            &env,
+            &group.token_address,
+            &contract_address,
+            payout_amount,
+        )?;
+
+        // Transfer tokens from contract to recipient
+        crate::token::transfer_token(
+            &env,
+            &group.token_address,
+            &contract_address,
+            &payout_recipient,
+            payout_amount,
+        )?;
+        crate::insurance::adjust_pot_liability(&env, &group.token_address, -payout_amount);
+        crate::insurance::ensure_solvent(&env, &group.token_address)?;
+
+        // Mark payout as received
+        storage::mark_payout_received(&env, group_id_cached, &payout_recipient);
+        utils::settle_tier_balances(&env, &group)?;
+
+        // Emit payout event with penalty information
+        if penalty_bonus > 0 {
+            events::emit_penalty_distributed(
+                &env,
+                group_id,
+                &payout_recipient,
+                group.current_cycle,
+                base_payout,
+                penalty_bonus,
+            );
+        }
+
+        events::emit_payout_executed(
+            &env,
+            group_id_cached,
+            &payout_recipient,
+            current_cycle,
+            payout_amount,
+        );
+
+        // Record the ordering decision for transparency
+        events::emit_payout_order_determined(
+            &env,
+            group_id_cached,
+            current_cycle,
+            &payout_recipient,
+            group.payout_strategy as u32,
+        );
+
+        // Advance payout index
+        group.payout_index += 1;
+
+        // Check if every remaining payout slot has been paid
+        if group.payout_index >= utils::payout_slot_count(&env, &group) {
+            // All members have received payout - mark complete
+            group.is_complete = true;
+            events::emit_group_completed(&env, group_id_cached);
+            // Members carrying on take their collateral into the next round
+            utils::roll_over(&env, &mut group);
+            utils::return_all_collateral(&env, &group)?;
+            utils::refund_all_prepayments(&env, &group)?;
+            utils::pay_out_auction_credits(&env, &group)?;
+            utils::close_tier_balances(&env, &group)?;
+        } else {
+            // Advance to next cycle
+            group.current_cycle += 1;
+            group.cycle_start_time = utils::get_current_timestamp(&env);
+        }
+
+        // Update storage (single write)
+        storage::store_group(&env, group_id, &group);
+
+        // Members who prepaid have contributed to the new cycle already
+        if !group.is_complete {
+            utils::apply_prepayments(&env, &group)?;
+        }
+
+        // Check and record group milestones
+        let milestones = utils::check_group_milestones(&env, &group);
+        for milestone in milestones.iter() {
+            let record = MilestoneRecord {
+                group_id: group.id,
+                milestone,
+                achieved_at: utils::get_current_timestamp(&env),
+                cycle_number: current_cycle,
+            };
+            storage::add_group_milestone(&env, group.id, &record);
+            events::emit_milestone_achieved(&env, group.id, record.milestone as u32, current_cycle);
+        }
+
+        // If group completed, update member stats for all members
+        if group.is_complete {
+            for member in group.members.iter() {
+                let mut stats = storage::get_member_stats(&env, &member)
+                    .unwrap_or_else(|| utils::default_member_stats(&env, &member));
+                stats.total_groups_completed += 1;
+                storage::store_member_stats(&env, &member, &stats);
+            }
+        }
+
+        Ok(())
+    }
+    // Insurance – automated claim verification & settlement
+
+    /// Automatically verify and settle an insurance claim based on on-chain data.
+    ///
+    /// Replaces the manual admin-approval flow with fully automated, trustless
+    
+    pub fn auto_verify_insurance_claim(env: Env, claim_id: u64) -> Result<(), AjoError> {
+        // Honour the global pause flag so this endpoint is blocked during emergencies.
+        pausable::ensure_not_paused(&env)?;
+
+        crate::insurance::auto_process_claim(&env, claim_id)
+    }
+
+    /// Get a group's insurance pool information for a token.
+    ///
+    /// Returns the current balance, total payouts, and pending claims count
+    /// for the group's insurance pool in the given token.
+    ///
+    /// # Arguments
+    /// * `env`           - The Soroban contract environment
+    /// * `group_id`      - The group that owns the pool
+    /// * `token_address` - Token contract address for the pool to query
+    ///
+    /// # Returns
+    /// `Ok(InsurancePool)` containing pool balance and statistics.
+    ///
+    /// # Errors
+    /// * `PoolNotFound` – no pool exists for the given group and token
+    pub fn get_insurance_pool_info(
+        env: Env,
+        group_id: u64,
+        token_address: Address,
+    ) -> Result<crate::types::InsurancePool, AjoError> {
+        crate::insurance::get_pool_info(&env, group_id, &token_address)
+    }
+
+    /// Choose how a group's insurance premiums are funded.
+    ///
+    /// Only the group creator can change the mode. Premiums already collected
+    /// are unaffected.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `creator` - Address of the group creator (must authenticate)
+    /// * `group_id` - The group to update
+    /// * `premium_mode` - The new [`PremiumMode`](crate::types::PremiumMode)
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `Unauthorized` - If the caller is not the group creator
+    /// * `GroupComplete` - If the group has completed all cycles
+    /// * `GroupCancelled` - If the group has been cancelled
+    pub fn set_insurance_premium_mode(
+        env: Env,
+        creator: Address,
+        group_id: u64,
+        premium_mode: crate::types::PremiumMode,
+    ) -> Result<(), AjoError> {
+        pausable::ensure_not_paused(&env)?;
+        creator.require_auth();
+
+        let mut group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        if group.creator != creator {
+            return Err(AjoError::Unauthorized);
+        }
+        if group.is_complete {
+            return Err(AjoError::GroupComplete);
+        }
+        if group.state == crate::types::GroupState::Cancelled {
+            return Err(AjoError::GroupCancelled);
+        }
+
+        group.insurance_config.premium_mode = premium_mode;
+        storage::store_group(&env, group_id, &group);
+        events::emit_premium_mode_changed(&env, group_id, premium_mode);
+
+        Ok(())
+    }
+
+    /// Choose how each member's insurance premium rate is set.
+    ///
+    /// With [`PremiumPricing::RiskBased`](crate::types::PremiumPricing::RiskBased)
+    /// the group's base rate is scaled by each member's risk factor and kept
+    /// between `floor_bps` and `cap_bps`. Only the group creator can change
+    /// pricing.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `creator` - Address of the group creator (must authenticate)
+    /// * `group_id` - The group to update
+    /// * `pricing` - The new [`PremiumPricing`](crate::types::PremiumPricing)
+    /// * `floor_bps` - Lowest rate a member can be charged
+    /// * `cap_bps` - Highest rate a member can be charged
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `Unauthorized` - If the caller is not the group creator
+    /// * `GroupComplete` - If the group has completed all cycles
+    /// * `GroupCancelled` - If the group has been cancelled
+    /// * `InvalidInput` - If `floor_bps <= rate_bps <= cap_bps` does not hold
+    pub fn set_premium_pricing(
+        env: Env,
+        creator: Address,
+        group_id: u64,
+        pricing: crate::types::PremiumPricing,
+        floor_bps: u32,
+        cap_bps: u32,
+    ) -> Result<(), AjoError> {
+        pausable::ensure_not_paused(&env)?;
+        creator.require_auth();
+
+        let mut group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        if group.creator != creator {
+            return Err(AjoError::Unauthorized);
+        }
+        if group.is_complete {
+            return Err(AjoError::GroupComplete);
+        }
+        if group.state == crate::types::GroupState::Cancelled {
+            return Err(AjoError::GroupCancelled);
+        }
+
+        let base = group.insurance_config.rate_bps;
+        if floor_bps > base || cap_bps < base || cap_bps > 10_000 {
+            return Err(AjoError::InvalidInput);
+        }
+
+        group.insurance_config.pricing = pricing;
+        group.insurance_config.floor_bps = floor_bps;
+        group.insurance_config.cap_bps = cap_bps;
+        storage::store_group(&env, group_id, &group);
+        events::emit_premium_pricing_changed(&env, group_id, pricing, floor_bps, cap_bps);
+
+        Ok(())
+    }
+
+    /// Get the premium rate a member would currently be charged, in basis points.
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    pub fn get_member_premium_rate(env: Env, group_id: u64, member: Address) -> Result<u32, AjoError> {
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        Ok(crate::insurance::premium_rate_bps(&env, &group, &member))
+    }
+
+    /// Get the premiums each member paid into the insurance reserve in a cycle.
+    pub fn get_cycle_member_premiums(
+        env: Env,
+        group_id: u64,
+        cycle: u32,
+    ) -> Vec<crate::types::MemberPremium> {
+        storage::get_member_premiums(&env, group_id, cycle)
+    }
+
+    /// Get the pot and insurance reserve totals the contract owes for a token.
+    pub fn get_token_liabilities(env: Env, token_address: Address) -> crate::types::TokenLiabilities {
+        crate::insurance::get_liabilities(&env, &token_address)
+    }
+
+    /// Check that the contract's balance of a token covers every pot plus
+    /// every insurance reserve.
+    ///
+    /// # Returns
+    /// `true` if the contract is solvent for the token
+    pub fn is_solvent(env: Env, token_address: Address) -> bool {
+        crate::insurance::ensure_solvent(&env, &token_address).is_ok()
+    }
+
+    // Query helpers
+    
+    pub fn is_complete(env: Env, group_id: u64) -> Result<bool, AjoError> {
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        Ok(group.is_complete)
+    }
+
+    /// Get comprehensive group status.
+    ///
+    /// Returns a detailed snapshot of the group's current state, including cycle
+    /// information, contribution status, payout progress, and timing data.
+    /// This is the primary function for checking a group's overall progress.
+    ///
+    /// Returns information about:
+    /// - Current cycle number and progress
+    /// - Next recipient for payout
+    /// - Members who have contributed and those who are pending
+    /// - Cycle timing (start time, end time, whether cycle is active)
+    /// - Whether the group is complete
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The unique group identifier
+    ///
+    /// # Returns
+    /// A `GroupStatus` struct containing comprehensive group state information
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    pub fn get_group_status(env: Env, group_id: u64) -> Result<GroupStatus, AjoError> {
+        // Get the group data (single fetch)
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+
+        // Cache frequently accessed values
+        let current_time = utils::get_current_timestamp(&env);
+        let _member_count = group.members.len();
+        let current_cycle = group.current_cycle;
+
+        // Calculate cycle timing
+        let cycle_end_time = group.cycle_start_time + group.cycle_duration;
+        let grace_period_end_time = utils::get_grace_period_end(&group);
+        let is_cycle_active = current_time < cycle_end_time;
+        let is_in_grace_period = utils::is_within_grace_period(&group, current_time);
+
+        // Get penalty pool for current cycle
+        let cycle_penalty_pool = storage::get_cycle_penalty_pool(&env, group_id, group.current_cycle);
+
+        // Build pending_contributors list
+        let mut contributions_received: u32 = 0;
+        let mut pending_contributors = Vec::new(&env);
+        let mut total_collected: i128 = 0;
+
+        // Single pass through members to check contributions
+        for member in group.members.iter() {
+            let paid = utils::get_amount_paid(&env, &group, current_cycle, &member);
+            total_collected += paid;
+            if paid >= utils::member_contribution(&env, &group, &member) {
+                contributions_received += 1;
+            } else {
+                pending_contributors.push_back(member);
+            }
+        }
+
+        // Determine next recipient according to the configured strategy without
+        // committing payout-order storage from a read-only status query.
+        let (has_next_recipient, next_recipient) = if group.is_complete {
+            (false, group.creator.clone())
+        } else {
+            match utils::preview_next_recipient(&env, &group) {
+                Ok(recipient) => (true, recipient),
+                Err(_) => (false, group.creator.clone()),
+            }
+        };
+
+        // Build and return status
+        Ok(GroupStatus {
+            group_id: group.id,
+            current_cycle: group.current_cycle,
+            has_next_recipient,
+            next_recipient,
+            contributions_received,
+            total_members: group.members.len() as u32,
+            pending_contributors,
+            is_complete: group.is_complete,
+            is_cycle_active,
+            cycle_start_time: group.cycle_start_time,
+            cycle_end_time,
+            current_time,
+            cycle_penalty_pool,
+            is_in_grace_period,
+            grace_period_end_time,
+            total_collected,
+            collateral_amount: group.collateral_amount,
+            collateral_balances: utils::collateral_balances(&env, &group),
+        })
+    }
+
+    /// Set or update metadata for an Ajo group.
+    ///
+    /// Only the group creator can set or update metadata.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The unique group identifier
+    /// * `name` - The name of the group
+    /// * `description` - The description of the group
+    /// * `rules` - The rules of the group
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `Unauthorized` - If the caller is not the group creator
+    /// * `MetadataTooLong` - If any field exceeds its length limit
+    pub fn set_group_metadata(
+        env: Env,
+        group_id: u64,
+        name: soroban_sdk::String,
+        description: soroban_sdk::String,
+        rules: soroban_sdk::String,
+    ) -> Result<(), AjoError> {
+        // Validate lengths
+        if name.len() > crate::types::MAX_NAME_LENGTH
+            || description.len() > crate::types::MAX_DESCRIPTION_LENGTH
+            || rules.len() > crate::types::MAX_RULES_LENGTH
+        {
+            return Err(AjoError::MetadataTooLong);
+        }
+
+        // Get group to verify existence and check creator
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+
+        // Require creator authentication
+        group.creator.require_auth();
+
+        // Create and store metadata
+        let metadata = GroupMetadata {
+            name,
+            description,
+            rules,
+        };
+        storage::store_group_metadata(&env, group_id, &metadata);
+
+        Ok(())
+    }
+
+    /// Get metadata for an Ajo group.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The unique group identifier
+    ///
+    /// # Returns
+    /// The group metadata
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If metadata for the group doesn't exist
+    pub fn get_group_metadata(env: Env, group_id: u64) -> Result<GroupMetadata, AjoError> {
+        storage::get_group_metadata(&env, group_id).ok_or(AjoError::GroupNotFound)
+    }
+
+    /// Get member penalty statistics.
+    ///
+    /// Returns the penalty record for a member in a specific group, including
+    /// late contribution count, on-time count, total penalties paid, and reliability score.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The unique group identifier
+    /// * `member` - The member's address
+    ///
+    /// # Returns
+    /// The member's penalty record
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group doesn't exist
+    /// * `NotMember` - If the address is not a member of the group
+    pub fn get_member_penalty_record(
+        env: Env,
+        group_id: u64,
+        member: Address,
+    ) -> Result<crate::types::MemberPenaltyRecord, AjoError> {
+        // Verify group exists
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+
+        // Verify member
+        if !utils::is_member(&group.members, &member) {
+            return Err(AjoError::NotMember);
+        }
+
+        // Get penalty record or return default
+        Ok(storage::get_member_penalty(&env, group_id, &member)
+            .unwrap_or_else(|| utils::default_penalty_record(group_id, &member)))
+    }
+
+    /// Get detailed contribution record for a member in a specific cycle.
+    ///
+    /// Returns the full contribution record including timing, penalty information,
+    /// and whether the contribution was late.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The unique group identifier
+    /// * `cycle` - The cycle number
+    /// * `member` - The member's address
+    ///
+    /// # Returns
+    /// The detailed contribution record
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group doesn't exist or contribution record doesn't exist
+    pub fn get_contribution_detail(
+        env: Env,
+        group_id: u64,
+        cycle: u32,
+        member: Address,
+    ) -> Result<crate::types::ContributionRecord, AjoError> {
+        storage::get_contribution_detail(&env, group_id, cycle, &member)
+            .ok_or(AjoError::GroupNotFound)
+    }
+
+    /// Get the penalty pool for a specific cycle.
+    ///
+    /// Returns the total penalties collected during a cycle, which will be
+    /// distributed to the payout recipient along with regular contributions.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The unique group identifier
+    /// * `cycle` - The cycle number
+    ///
+    /// # Returns
+    /// Total penalty amount in stroops
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group doesn't exist
+    pub fn get_cycle_penalty_pool(env: Env, group_id: u64, cycle: u32) -> Result<i128, AjoError> {
+        // Verify group exists
+        storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+
+        Ok(storage::get_cycle_penalty_pool(&env, group_id, cycle))
+    }
+
+    /// Cancel a group and refund all members.
+    ///
+    /// Only the group creator can cancel a group, and only before the first payout.
+    /// All members who have contributed will receive their token contributions back,
+    /// including any partial installments paid towards the current cycle.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `creator` - Address of the group creator
+    /// * `group_id` - The unique group identifier
+    ///
+    /// # Returns
+    /// `Ok(())` on successful cancellation and refunds
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group doesn't exist
+    /// * `Unauthorized` - If the caller is not the group creator
+    /// * `CannotCancelAfterPayout` - If any payout has been executed
+    /// * `GroupCancelled` - If the group is already cancelled
+    /// * `GroupComplete` - If the group is already complete
+    /// * `TransferFailed` - If any token refund transfer fails
+    pub fn cancel_group(env: Env, creator: Address, group_id: u64) -> Result<(), AjoError> {
+        pausable::ensure_not_paused(&env)?;
+        creator.require_auth();
+
+        let mut group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+
+        // Verify creator
+        if group.creator != creator {
+            return Err(AjoError::Unauthorized);
+        }
+
+        // Check if already cancelled or complete
+        if group.state == crate::types::GroupState::Cancelled {
+            return Err(AjoError::GroupCancelled);
+        }
+        if group.state == crate::types::GroupState::Complete {
+            return Err(AjoError::GroupComplete);
+        }
+
+        // Cannot cancel after first payout
+        if group.payout_index > 0 {
+            return Err(AjoError::CannotCancelAfterPayout);
+        }
+
+        // Refund each member who contributed
+        utils::refund_cycle_contributions(
+            &env,
+            &group,
+            crate::types::RefundReason::CreatorCancellation,
+        )?;
+        utils::return_all_collateral(&env, &group)?;
+        utils::refund_all_prepayments(&env, &group)?;
+
+        // Update group state
+        group.state = crate::types::GroupState::Cancelled;
+        storage::store_group(&env, group_id, &group);
+
+        // Emit cancellation event
+        events::emit_group_cancelled(
+            &env,
+            group_id,
+            &creator,
+            group.members.len(),
+            group.contribution_amount,
+        );
+
+        Ok(())
+    }
+
+    /// Request a refund for a group that has failed to complete.
+    ///
+    /// Any member can request a refund if the cycle deadline has passed and
+    /// not all members have contributed. This initiates a voting period where
+    /// members can vote on whether to approve the refund.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `requester` - Address of the member requesting the refund
+    /// * `group_id` - The unique group identifier
+    ///
+    /// # Returns
+    /// `Ok(())` on successful request creation
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group doesn't exist
+    /// * `NotMember` - If the requester is not a member
+    /// * `GroupCancelled` - If the group is already cancelled
+    /// * `GroupComplete` - If the group is already complete
+    /// * `CycleNotExpired` - If the cycle deadline hasn't passed
+    /// * `RefundRequestExists` - If a refund request already exists
+    pub fn request_refund(env: Env, requester: Address, group_id: u64) -> Result<(), AjoError> {
+        pausable::ensure_not_paused(&env)?;
+        requester.require_auth();
+
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+
+        // Verify member
+        if !utils::is_member(&group.members, &requester) {
+            return Err(AjoError::NotMember);
+        }
+
+        // Check group state
+        if group.state == crate::types::GroupState::Cancelled {
+            return Err(AjoError::GroupCancelled);
+        }
+        if group.state == crate::types::GroupState::Complete {
+            return Err(AjoError::GroupComplete);
+        }
+
+        // Check if cycle has expired (past grace period)
+        let now = utils::get_current_timestamp(&env);
+        let grace_end = utils::get_grace_period_end(&group);
+        if now <= grace_end {
+            return Err(AjoError::CycleNotExpired);
+        }
+
+        // Check if refund request already exists
+        if storage::has_refund_request(&env, group_id) {
+            return Err(AjoError::RefundRequestExists);
+        }
+
+        // Create refund request
+        let voting_deadline = now + crate::types::VOTING_PERIOD;
+        let request = crate::types::RefundRequest {
+            group_id,
+            requester: requester.clone(),
+            created_at: now,
+            voting_deadline,
+            votes_for: 0,
+            votes_against: 0,
+            executed: false,
+            approved: false,
+        };
+
+        storage::store_refund_request(&env, group_id, &request);
+
+        // Emit event
+        events::emit_refund_requested(&env, group_id, &requester, voting_deadline);
+
+        Ok(())
+    }
+
+    /// Vote on a refund request.
+    ///
+    /// Members can vote in favor or against a refund request during the voting period.
+    /// Each member can only vote once.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `voter` - Address of the voting member
+    /// * `group_id` - The unique group identifier
+    /// * `in_favor` - true to vote in favor, false to vote against
+    ///
+    /// # Returns
+    /// `Ok(())` on successful vote
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group doesn't exist
+    /// * `NotMember` - If the voter is not a member
+    /// * `NoRefundRequest` - If no refund request exists
+    /// * `AlreadyVoted` - If the member has already voted
+    /// * `VotingPeriodEnded` - If the voting period has ended
+    pub fn vote_refund(
+        env: Env,
+        voter: Address,
+        group_id: u64,
+        in_favor: bool,
+    ) -> Result<(), AjoError> {
+        pausable::ensure_not_paused(&env)?;
+        voter.require_auth();
+
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+
+        // Verify member
+        if !utils::is_member(&group.members, &voter) {
+            return Err(AjoError::NotMember);
+        }
+
+        // Get refund request
+        let mut request = storage::get_refund_request(&env, group_id)
+            .ok_or(AjoError::NoRefundRequest)?;
+
+        // Check if already voted
+        if storage::has_voted(&env, group_id, &voter) {
+            return Err(AjoError::AlreadyVoted);
+        }
+
+        // Check voting period
+        let now = utils::get_current_timestamp(&env);
+        if now > request.voting_deadline {
+            return Err(AjoError::VotingPeriodEnded);
+        }
+
+        // Record vote
+        let vote = crate::types::RefundVote {
+            group_id,
+            voter: voter.clone(),
+            in_favor,
+            timestamp: now,
+        };
+        storage::store_refund_vote(&env, group_id, &voter, &vote);
+
+        // Update vote counts
+        if in_favor {
+            request.votes_for += 1;
+        } else {
+            request.votes_against += 1;
+        }
+        storage::store_refund_request(&env, group_id, &request);
+
+        // Emit event
+        events::emit_refund_vote(&env, group_id, &voter, in_favor);
+
+        Ok(())
+    }
+
+    /// Execute a refund after voting period ends.
+    ///
+    /// Can be called by any member after the voting period ends. If the refund
+    /// is approved (>51% votes in favor), all members receive token refunds
+    /// based on their contributions, including partial installments.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `executor` - Address executing the refund
+    /// * `group_id` - The unique group identifier
+    ///
+    /// # Returns
+    /// `Ok(())` on successful execution and token refunds
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group doesn't exist
+    /// * `NoRefundRequest` - If no refund request exists
+    /// * `VotingPeriodActive` - If the voting period hasn't ended
+    /// * `RefundNotApproved` - If the refund wasn't approved
+    /// * `RefundAlreadyExecuted` - If the refund has already been executed
+    /// * `TransferFailed` - If any token refund transfer fails
+    pub fn execute_refund(env: Env, executor: Address, group_id: u64) -> Result<(), AjoError> {
+        pausable::ensure_not_paused(&env)?;
+        executor.require_auth();
+
+        Self::close_refund_vote(env, group_id)
+    }
+
+    /// Closes a refund vote whose voting period has ended, refunding the
+    /// group if it was approved.
+    fn close_refund_vote(env: Env, group_id: u64) -> Result<(), AjoError> {
+        let mut group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        let mut request = storage::get_refund_request(&env, group_id)
+            .ok_or(AjoError::NoRefundRequest)?;
+
+        // Check if already executed
+        if request.executed {
+            return Err(AjoError::RefundAlreadyExecuted);
+        }
+
+        // Check voting period ended
+        let now = utils::get_current_timestamp(&env);
+        if now <= request.voting_deadline {
+            return Err(AjoError::VotingPeriodActive);
+        }
+
+        // Calculate approval percentage
+        let total_votes = request.votes_for + request.votes_against;
+        let approval_percentage = if total_votes > 0 {
+            (request.votes_for * 100) / total_votes
+        } else {
+            0
+        };
+
+        // Check if approved
+        if approval_percentage < crate::types::REFUND_APPROVAL_THRESHOLD {
+            request.executed = true;
+            request.approved = false;
+            storage::store_refund_request(&env, group_id, &request);
+            return Err(AjoError::RefundNotApproved);
+        }
+
+        // Process refunds for all members who contributed
+        utils::refund_cycle_contributions(&env, &group, crate::types::RefundReason::MemberVote)?;
+        utils::return_all_collateral(&env, &group)?;
+        utils::refund_all_prepayments(&env, &group)?;
+        utils::pay_out_auction_credits(&env, &group)?;
+        utils::close_tier_balances(&env, &group)?;
+
+        // Update request and group state
+        request.executed = true;
+        request.approved = true;
+        storage::store_refund_request(&env, group_id, &request);
+
+        group.state = crate::types::GroupState::Cancelled;
+        storage::store_group(&env, group_id, &group);
+
+        Ok(())
+    }
+
+    /// Emergency refund by admin.
+    ///
+    /// Allows the contract admin to force a refund in case of disputes or emergencies.
+    /// All members who have contributed receive their token contributions back,
+    /// including partial installments.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `admin` - Address of the contract admin
+    /// * `group_id` - The unique group identifier
+    ///
+    /// # Returns
+    /// `Ok(())` on successful emergency refund and token transfers
+    ///
+    /// # Errors
+    /// * `Unauthorized` - If the caller is not the admin
+    /// * `GroupNotFound` - If the group doesn't exist
+    /// * `GroupCancelled` - If the group is already cancelled
+    /// * `TransferFailed` - If any token refund transfer fails
+    pub fn emergency_refund(env: Env, admin: Address, group_id: u64) -> Result<(), AjoError> {
+        admin.require_auth();
+
+        // Verify admin
+        let stored_admin = storage::get_admin(&env).ok_or(AjoError::Unauthorized)?;
+        if admin != stored_admin {
+            return Err(AjoError::Unauthorized);
+        }
+
+        let mut group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+
+        // Check if already cancelled
+        if group.state == crate::types::GroupState::Cancelled {
+            return Err(AjoError::GroupCancelled);
+        }
+
+        // Process refunds for all members who contributed
+        let total_refunded = utils::refund_cycle_contributions(
+            &env,
+            &group,
+            crate::types::RefundReason::EmergencyRefund,
+        )?;
+        utils::return_all_collateral(&env, &group)?;
+        utils::refund_all_prepayments(&env, &group)?;
+        utils::pay_out_auction_credits(&env, &group)?;
+        utils::close_tier_balances(&env, &group)?;
+
+        // Update group state
+        group.state = crate::types::GroupState::Cancelled;
+        storage::store_group(&env, group_id, &group);
+
+        // Emit emergency refund event
+        events::emit_emergency_refund(&env, group_id, &admin, total_refunded);
+
+        Ok(())
+    }
+
+    /// Get refund request for a group.
+    ///
+    /// Returns the current refund request if one exists.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The unique group identifier
+    ///
+    /// # Returns
+    /// The refund request
+    ///
+    /// # Errors
+    /// * `NoRefundRequest` - If no refund request exists
+    pub fn get_refund_request(
+        env: Env,
+        group_id: u64,
+    ) -> Result<crate::types::RefundRequest, AjoError> {
+        storage::get_refund_request(&env, group_id).ok_or(AjoError::NoRefundRequest)
+    }
+
+    /// Get refund record for a member.
+    ///
+    /// Returns the refund record if the member has received a refund.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The unique group identifier
+    /// * `member` - The member's address
+    ///
+    /// # Returns
+    /// The refund record
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If no refund record exists
+pub fn get_refund_record(
+        env: Env,
+        group_id: u64,
+        member: Address,
+    ) -> Result<crate::types::RefundRecord, AjoError> {
+        storage::get_refund_record(&env, group_id, &member).ok_or(AjoError::GroupNotFound)
+    }
+
+    /// Get the contract's token balance for a specific token.
+    ///
+    /// Returns the amount of tokens held by the contract for a given token address.
+    /// Useful for checking if the contract has sufficient funds for payouts.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `token_address` - Address of the token contract
+    ///
+    /// # Returns
+    /// The token balance held by the contract
+    pub fn get_contract_balance(env: Env, token_address: Address) -> i128 {
+        let contract_address = env.current_contract_address();
+        crate::token::get_balance(&env, &token_address, &contract_address)
+    }
+
+    /// File an insurance claim for non-payment.
+    pub fn file_insurance_claim(
+        env: Env,
+        claimant: Address,
+        group_id: u64,
+        cycle: u32,
+        defaulter: Address,
+        amount: i128,
+    ) -> Result<u64, AjoError> {
+        claimant.require_auth();
+        crate::insurance::file_claim(&env, group_id, cycle, claimant, defaulter, amount)
+    }
+
+    /// Process (approve/reject) an insurance claim.
+    /// Only the contract admin can process claims.
+    pub fn process_insurance_claim(
+        env: Env,
+        admin: Address,
+        claim_id: u64,
+        approved: bool,
+    ) -> Result<(), AjoError> {
+        let contract_admin = storage::get_admin(&env).ok_or(AjoError::Unauthorized)?;
+        if admin != contract_admin {
+            return Err(AjoError::Unauthorized);
+        }
+        contract_admin.require_auth();
+        crate::insurance::process_claim(&env, claim_id, approved)
+    }
+
+    /// Get a group's insurance pool details for a specific token.
+    pub fn get_insurance_pool(
+        env: Env,
+        group_id: u64,
+        token_address: Address,
+    ) -> Result<crate::types::InsurancePool, AjoError> {
+        storage::get_insurance_pool(&env, group_id, &token_address).ok_or(AjoError::PoolNotFound)
+    }
+
+    /// Get insurance claim details.
+    pub fn get_insurance_claim(env: Env, claim_id: u64) -> Result<crate::types::InsuranceClaim, AjoError> {
+        storage::get_insurance_claim(&env, claim_id).ok_or(AjoError::InvalidClaim)
+    }
+
+    /// Get the insurance claim filed or paid for a member's default in a cycle.
+    pub fn get_default_claim(
+        env: Env,
+        group_id: u64,
+        cycle: u32,
+        defaulter: Address,
+    ) -> Result<crate::types::InsuranceClaim, AjoError> {
+        let claim_id = storage::get_default_claim(&env, group_id, cycle, &defaulter)
+            .ok_or(AjoError::InvalidClaim)?;
+        storage::get_insurance_claim(&env, claim_id).ok_or(AjoError::InvalidClaim)
+    }
+
+    /// Get risk score for a member.
+    pub fn get_member_risk_score(env: Env, member: Address) -> u32 {
+        crate::insurance::get_member_risk_score(&env, &member)
+    }
+
+    /// Get a member's risk score along with how it was derived.
+    pub fn get_member_risk_breakdown(env: Env, member: Address) -> crate::types::RiskScoreBreakdown {
+        crate::insurance::member_risk_breakdown(&env, &member)
+    }
+
+    /// Get risk rating for a group.
+    pub fn get_group_risk_rating(env: Env, group_id: u64) -> Result<u32, AjoError> {
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        Ok(crate::insurance::get_group_risk_rating(&env, &group))
+    }
+
+    // ── Dynamic payout ordering ───────────────────────────────────────────────
+
+    /// Create a new Ajo group with an explicit payout ordering strategy.
+    ///
+    /// Identical to [`create_group`] except the caller chooses one of the five
+    /// [`PayoutOrderingStrategy`] variants.  For `Sequential` behaviour,
+    /// [`create_group`] is preferred; this function targets groups that want
+    /// `Random`, `VotingBased`, `ContributionBased`, or `NeedBased` ordering.
+    ///
+    /// A `Random` group's grace period is its window for revealing secrets,
+    /// so it must not be zero (`InvalidGracePeriod`).
+    pub fn create_group_with_ordering(
+        env: Env,
+        creator: Address,
+        token_address: Address,
+        contribution_amount: i128,
+        cycle_duration: u64,
+        max_members: u32,
+        grace_period: u64,
+        penalty_rate: u32,
+        insurance_rate_bps: u32,
+        payout_strategy: PayoutOrderingStrategy,
+    ) -> Result<u64, AjoError> {
+        utils::validate_group_params(contribution_amount, cycle_duration, max_members)?;
+        utils::validate_penalty_params(grace_period, penalty_rate)?;
+        if payout_strategy == PayoutOrderingStrategy::Random && grace_period == 0 {
+            return Err(AjoError::InvalidGracePeriod);
+        }
+        pausable::ensure_not_paused(&env)?;
+        creator.require_auth();
+
+        let group_id = storage::get_next_group_id(&env);
+        let mut members = Vec::new(&env);
+        members.push_back(creator.clone());
+        let now = utils::get_current_timestamp(&env);
+
+        let group = Group {
+            id: group_id,
+            creator: creator.clone(),
+            token_address,
+            contribution_amount,
+            cycle_duration,
+            max_members,
+            members,
+            current_cycle: 1,
+            payout_index: 0,
+            created_at: now,
+            cycle_start_time: now,
+            is_complete: false,
+            grace_period,
+            penalty_rate,
+            state: crate::types::GroupState::Active,
+            insurance_config: crate::types::InsuranceConfig {
+                rate_bps: insurance_rate_bps,
+                is_enabled: insurance_rate_bps > 0,
+                premium_mode: crate::types::PremiumMode::OnTop,
+                pricing: crate::types::PremiumPricing::Flat,
+                floor_bps: insurance_rate_bps,
+                cap_bps: insurance_rate_bps,
+            },
+            payout_strategy,
+            access_type: crate::types::GroupAccessType::Open,
+            forfeit_payout_on_default: false,
+            collateral_amount: 0,
+            swap_requires_approval: false,
+            discount_settlement: crate::types::DiscountSettlement::PayOut,
+            schedule_rule: crate::types::ScheduleRule::Unlocked,
+            min_members: 0,
+            formation_deadline: 0,
+            allow_late_join: false,
+            rollover_enabled: false,
+            round: 1,
+            predecessor_id: 0,
+            successor_id: 0,
+        };
+
+        storage::store_group(&env, group_id, &group);
+        events::emit_group_created(&env, group_id, &creator, contribution_amount, max_members);
+        Ok(group_id)
+    }
+
+    /// Cast a vote for the next payout recipient.
+    ///
+    /// Only callable for groups whose strategy is [`PayoutOrderingStrategy::VotingBased`].
+    /// Each member may vote once per cycle; calling again replaces the existing
+    /// vote (last-vote-wins semantics).
+    ///
+    /// # Arguments
+    /// * `voter`    - The member casting the vote (must authenticate).
+    /// * `group_id` - The group the vote applies to.
+    /// * `nominee`  - The member being nominated to receive the next payout.
+    ///
+    /// # Errors
+    /// * `GroupNotFound`         — group does not exist.
+    /// * `InvalidStrategy`       — strategy is not voting-based.
+    /// * `NotMember`             — voter or nominee is not a group member.
+    /// * `AlreadyReceivedPayout` — nominee has already been paid.
+    /// * `GroupComplete`         — all payouts have been distributed.
+    /// * `GroupCancelled`        — group was cancelled.
+    pub fn vote_for_next_recipient(
+        env: Env,
+        voter: Address,
+        group_id: u64,
+        nominee: Address,
+    ) -> Result<(), AjoError> {
+        pausable::ensure_not_paused(&env)?;
+        voter.require_auth();
+
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+
+        // Guard: strategy must support voting
+        if group.payout_strategy != PayoutOrderingStrategy::VotingBased {
+            return Err(AjoError::InvalidStrategy);
+        }
+
+        // Guard: group must be active
+        if group.is_complete {
+            return Err(AjoError::GroupComplete);
+        }
+        if group.state == crate::types::GroupState::Cancelled {
+            return Err(AjoError::GroupCancelled);
+        }
+
+        // Guard: both voter and nominee must be members
+        if !utils::is_member(&group.members, &voter) {
+            return Err(AjoError::NotMember);
+        }
+        if !utils::is_member(&group.members, &nominee) {
+            return Err(AjoError::NotMember);
+        }
+
+        // Guard: nominee must not have already received a payout for every share
+        if !utils::has_payout_remaining(&env, group_id, &nominee) {
+            return Err(AjoError::AlreadyReceivedPayout);
+        }
+
+        let vote = crate::types::PayoutVote {
+            group_id,
+            cycle: group.current_cycle,
+            voter: voter.clone(),
+            nominee: nominee.clone(),
+            timestamp: utils::get_current_timestamp(&env),
         };
-        storage::store_group_metadata(&env, group_id, &metadata);
+
+        storage::store_payout_vote(&env, group_id, group.current_cycle, &voter, &vote);
+        events::emit_payout_vote(&env, group_id, &voter, &nominee, group.current_cycle);
 
         Ok(())
     }
 
-    /// Get metadata for an Ajo group.
-    ///
-    /// # Arguments
-    /// * `env` - The Soroban contract environment
-    /// * `group_id` - The unique group identifier
+    /// Get the payout order for a specific cycle.
     ///
-    /// # Returns
-    /// The group metadata
+    /// Returns the [`PayoutOrder`](crate::types::PayoutOrder) written by
+    /// `execute_payout` for audit and history purposes. For cycles that have
+    /// not paid out yet, returns the projected recipient instead, with
+    /// `determined_at` set to `0`: any upcoming cycle for sequential groups
+    /// and groups with a fixed schedule, only the current cycle for other
+    /// strategies. Projections follow position swaps.
     ///
     /// # Errors
-    /// * `GroupNotFound` - If metadata for the group doesn't exist
-    pub fn get_group_metadata(env: Env, group_id: u64) -> Result<GroupMetadata, AjoError> {
-        storage::get_group_metadata(&env, group_id).ok_or(AjoError::GroupNotFound)
+    /// * `GroupNotFound` — no payout order has been recorded or can be
+    ///   projected for this cycle.
+    pub fn get_payout_order(
+        env: Env,
+        group_id: u64,
+        cycle: u32,
+    ) -> Result<crate::types::PayoutOrder, AjoError> {
+        if let Some(order) = storage::get_payout_order(&env, group_id, cycle) {
+            return Ok(order);
+        }
+
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        if group.is_complete {
+            return Err(AjoError::GroupNotFound);
+        }
+        let scheduled = group.payout_strategy == PayoutOrderingStrategy::Sequential
+            || storage::get_payout_schedule(&env, group_id).is_some();
+        let recipient = if scheduled {
+            utils::projected_sequential_recipient(&env, &group, cycle)
+        } else if cycle == group.current_cycle {
+            utils::preview_next_recipient(&env, &group).ok()
+        } else {
+            None
+        };
+
+        recipient
+            .map(|recipient| crate::types::PayoutOrder {
+                group_id,
+                cycle,
+                recipient,
+                selection_method: group.payout_strategy,
+                determined_at: 0,
+                seed: BytesN::from_array(&env, &[0u8; 32]),
+            })
+            .ok_or(AjoError::GroupNotFound)
     }
 
-    /// Get member penalty statistics.
+    // ── Fixed payout schedules ────────────────────────────────────────────────
+
+    /// Choose how the group's whole payout rotation is fixed once it fills.
     ///
-    /// Returns the penalty record for a member in a specific group, including
-    /// late contribution count, on-time count, total penalties paid, and reliability score.
+    /// With any rule other than `Unlocked`, the rotation is drawn when the
+    /// last member joins (immediately if the group is already full) and
+    /// `execute_payout` follows it in place of the payout strategy. A
+    /// `Shuffled` rotation is seeded by the ledger PRNG, which validators can
+    /// influence.
     ///
     /// # Arguments
     /// * `env` - The Soroban contract environment
-    /// * `group_id` - The unique group identifier
-    /// * `member` - The member's address
-    ///
-    /// # Returns
-    /// The member's penalty record
+    /// * `creator` - Address of the group creator (must authenticate)
+    /// * `group_id` - The group to update
+    /// * `rule` - How to order the rotation
     ///
     /// # Errors
-    /// * `GroupNotFound` - If the group doesn't exist
-    /// * `NotMember` - If the address is not a member of the group
-    pub fn get_member_penalty_record(
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `Unauthorized` - If the caller is not the group creator
+    /// * `GroupComplete` - If the group has completed all cycles
+    /// * `GroupCancelled` - If the group has been cancelled
+    /// * `InvalidState` - If the schedule was already drawn or a payout has
+    ///   been made
+    pub fn set_payout_schedule_rule(
         env: Env,
+        creator: Address,
         group_id: u64,
-        member: Address,
-    ) -> Result<crate::types::MemberPenaltyRecord, AjoError> {
-        // Verify group exists
-        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        rule: crate::types::ScheduleRule,
+    ) -> Result<(), AjoError> {
+        pausable::ensure_not_paused(&env)?;
+        creator.require_auth();
 
-        // Verify member
-        if !utils::is_member(&group.members, &member) {
-            return Err(AjoError::NotMember);
+        let mut group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        if group.creator != creator {
+            return Err(AjoError::Unauthorized);
+        }
+        if group.is_complete {
+            return Err(AjoError::GroupComplete);
+        }
+        if group.state == crate::types::GroupState::Cancelled {
+            return Err(AjoError::GroupCancelled);
+        }
+        if group.payout_index > 0 || storage::get_payout_schedule(&env, group_id).is_some() {
+            return Err(AjoError::InvalidState);
         }
 
-        // Get penalty record or return default
-        Ok(storage::get_member_penalty(&env, group_id, &member).unwrap_or(
-            crate::types::MemberPenaltyRecord {
-                member: member.clone(),
-                group_id,
-                late_count: 0,
-                on_time_count: 0,
-                total_penalties: 0,
-                reliability_score: 100,
-            },
-        ))
+        group.schedule_rule = rule;
+        storage::store_group(&env, group_id, &group);
+
+        events::emit_schedule_rule_changed(&env, group_id, rule as u32);
+
+        if rule != crate::types::ScheduleRule::Unlocked && utils::total_shares(&env, &group) >= group.max_members {
+            utils::lock_payout_schedule(&env, &group);
+        }
+
+        Ok(())
     }
 
-    /// Get detailed contribution record for a member in a specific cycle.
-    ///
-    /// Returns the full contribution record including timing, penalty information,
-    /// and whether the contribution was late.
-    ///
-    /// # Arguments
-    /// * `env` - The Soroban contract environment
-    /// * `group_id` - The unique group identifier
-    /// * `cycle` - The cycle number
-    /// * `member` - The member's address
-    ///
-    /// # Returns
-    /// The detailed contribution record
+    /// List every cycle's recipient and projected payout date from the
+    /// group's fixed schedule.
     ///
     /// # Errors
-    /// * `GroupNotFound` - If the group doesn't exist or contribution record doesn't exist
-    pub fn get_contribution_detail(
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `InvalidState` - If no schedule has been drawn
+    pub fn get_payout_schedule(
         env: Env,
         group_id: u64,
-        cycle: u32,
-        member: Address,
-    ) -> Result<crate::types::ContributionRecord, AjoError> {
-        storage::get_contribution_detail(&env, group_id, cycle, &member)
-            .ok_or(AjoError::GroupNotFound)
+    ) -> Result<Vec<crate::types::ScheduledPayout>, AjoError> {
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        let schedule =
+            storage::get_payout_schedule(&env, group_id).ok_or(AjoError::InvalidState)?;
+        Ok(utils::scheduled_payouts(&env, &group, &schedule))
     }
 
-    /// Get the penalty pool for a specific cycle.
-    ///
-    /// Returns the total penalties collected during a cycle, which will be
-    /// distributed to the payout recipient along with regular contributions.
-    ///
-    /// # Arguments
-    /// * `env` - The Soroban contract environment
-    /// * `group_id` - The unique group identifier
-    /// * `cycle` - The cycle number
-    ///
-    /// # Returns
-    /// Total penalty amount in stroops
+    /// Get the stored schedule, including the rule and seed it was drawn
+    /// with, so the draw can be verified.
     ///
     /// # Errors
-    /// * `GroupNotFound` - If the group doesn't exist
-    pub fn get_cycle_penalty_pool(env: Env, group_id: u64, cycle: u32) -> Result<i128, AjoError> {
-        // Verify group exists
-        storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
-
-        Ok(storage::get_cycle_penalty_pool(&env, group_id, cycle))
+    /// * `InvalidState` - If no schedule has been drawn
+    pub fn get_payout_schedule_draw(
+        env: Env,
+        group_id: u64,
+    ) -> Result<crate::types::PayoutSchedule, AjoError> {
+        storage::get_payout_schedule(&env, group_id).ok_or(AjoError::InvalidState)
     }
 
-    /// Cancel a group and refund all members.
+    // ── Commit-reveal randomness ──────────────────────────────────────────────
+
+    /// Commit to a secret for the current cycle's random draw.
     ///
-    /// Only the group creator can cancel a group, and only before the first payout.
-    /// All members who have contributed will receive their token contributions back.
+    /// Only for [`PayoutOrderingStrategy::Random`] groups, until the cycle's
+    /// contribution deadline. `commitment` must be `sha256(secret)` for a
+    /// 32-byte secret revealed with `reveal_random_seed` during the grace period.
     ///
     /// # Arguments
     /// * `env` - The Soroban contract environment
-    /// * `creator` - Address of the group creator
-    /// * `group_id` - The unique group identifier
-    ///
-    /// # Returns
-    /// `Ok(())` on successful cancellation and refunds
+    /// * `member` - The committing member (must authenticate)
+    /// * `group_id` - The group
+    /// * `commitment` - `sha256(secret)`
     ///
     /// # Errors
-    /// * `GroupNotFound` - If the group doesn't exist
-    /// * `OnlyCreatorCanCancel` - If the caller is not the group creator
-    /// * `CannotCancelAfterPayout` - If any payout has been executed
-    /// * `GroupCancelled` - If the group is already cancelled
-    /// * `GroupComplete` - If the group is already complete
-    /// * `TransferFailed` - If any token refund transfer fails
-    pub fn cancel_group(env: Env, creator: Address, group_id: u64) -> Result<(), AjoError> {
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `InvalidStrategy` - If the group does not use random ordering
+    /// * `GroupComplete` - If the group has completed all cycles
+    /// * `GroupCancelled` - If the group has been cancelled
+    /// * `NotMember` - If the address is not a member
+    /// * `OutsideCycleWindow` - If the contribution deadline has passed
+    /// * `InvalidState` - If the member already committed this cycle
+    pub fn commit_random_seed(
+        env: Env,
+        member: Address,
+        group_id: u64,
+        commitment: BytesN<32>,
+    ) -> Result<(), AjoError> {
         pausable::ensure_not_paused(&env)?;
-        creator.require_auth();
-
-        let mut group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        member.require_auth();
 
-        // Verify creator
-        if group.creator != creator {
-            return Err(AjoError::OnlyCreatorCanCancel);
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        if group.payout_strategy != PayoutOrderingStrategy::Random {
+            return Err(AjoError::InvalidStrategy);
+        }
+        if group.is_complete {
+            return Err(AjoError::GroupComplete);
         }
-
-        // Check if already cancelled or complete
         if group.state == crate::types::GroupState::Cancelled {
             return Err(AjoError::GroupCancelled);
         }
-        if group.state == crate::types::GroupState::Complete {
-            return Err(AjoError::GroupComplete);
+        if !utils::is_member(&group.members, &member) {
+            return Err(AjoError::NotMember);
         }
 
-        // Cannot cancel after first payout
-        if group.payout_index > 0 {
-            return Err(AjoError::CannotCancelAfterPayout);
+        let now = utils::get_current_timestamp(&env);
+        if now > group.cycle_start_time + group.cycle_duration {
+            return Err(AjoError::OutsideCycleWindow);
         }
 
-        // Calculate refunds for each member who contributed
-        let contract_address = env.current_contract_address();
-        
-        for member in group.members.iter() {
-            if storage::has_contributed(&env, group_id, group.current_cycle, &member) {
-                let refund_amount = group.contribution_amount;
-
-                // Transfer tokens back to member
-                crate::token::transfer_token(
-                    &env,
-                    &group.token_address,
-                    &contract_address,
-                    &member,
-                    refund_amount,
-                )?;
-
-                // Store refund record
-                let refund_record = crate::types::RefundRecord {
-                    group_id,
-                    member: member.clone(),
-                    amount: refund_amount,
-                    timestamp: utils::get_current_timestamp(&env),
-                    reason: crate::types::RefundReason::CreatorCancellation,
-                };
-                storage::store_refund_record(&env, group_id, &member, &refund_record);
-
-                // Emit refund event
-                events::emit_refund_processed(&env, group_id, &member, refund_amount, 0);
-            }
+        let cycle = group.current_cycle;
+        let mut commitments = storage::get_random_commitments(&env, group_id, cycle);
+        if commitments.iter().any(|existing| existing.member == member) {
+            return Err(AjoError::InvalidState);
         }
+        commitments.push_back(crate::types::RandomCommitment {
+            member: member.clone(),
+            commitment,
+            revealed: false,
+            secret: BytesN::from_array(&env, &[0u8; 32]),
+            committed_at: now,
+        });
+        storage::store_random_commitments(&env, group_id, cycle, &commitments);
 
-        // Update group state
-        group.state = crate::types::GroupState::Cancelled;
-        storage::store_group(&env, group_id, &group);
-
-        // Emit cancellation event
-        events::emit_group_cancelled(
-            &env,
-            group_id,
-            &creator,
-            group.members.len(),
-            group.contribution_amount,
-        );
+        events::emit_random_committed(&env, group_id, cycle, &member);
 
         Ok(())
     }
 
-    /// Request a refund for a group that has failed to complete.
+    /// Reveal the secret behind a commitment during the cycle's grace period,
+    /// between the contribution deadline and the earliest payout.
     ///
-    /// Any member can request a refund if the cycle deadline has passed and
-    /// not all members have contributed. This initiates a voting period where
-    /// members can vote on whether to approve the refund.
+    /// Members who commit but never reveal are left out of the cycle's draw.
     ///
     /// # Arguments
     /// * `env` - The Soroban contract environment
-    /// * `requester` - Address of the member requesting the refund
-    /// * `group_id` - The unique group identifier
-    ///
-    /// # Returns
-    /// `Ok(())` on successful request creation
+    /// * `member` - The committing member (must authenticate)
+    /// * `group_id` - The group
+    /// * `secret` - The secret whose hash was committed
     ///
     /// # Errors
-    /// * `GroupNotFound` - If the group doesn't exist
-    /// * `NotMember` - If the requester is not a member
-    /// * `GroupCancelled` - If the group is already cancelled
-    /// * `GroupComplete` - If the group is already complete
-    /// * `CycleNotExpired` - If the cycle deadline hasn't passed
-    /// * `RefundRequestExists` - If a refund request already exists
-    pub fn request_refund(env: Env, requester: Address, group_id: u64) -> Result<(), AjoError> {
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `InvalidStrategy` - If the group does not use random ordering
+    /// * `OutsideCycleWindow` - If the contribution deadline has not passed or
+    ///   the grace period has ended
+    /// * `InvalidInput` - If the member did not commit this cycle, or if `sha256(secret)`
+    ///   does not match the commitment
+    /// * `InvalidState` - If the secret was already revealed
+    pub fn reveal_random_seed(
+        env: Env,
+        member: Address,
+        group_id: u64,
+        secret: BytesN<32>,
+    ) -> Result<(), AjoError> {
         pausable::ensure_not_paused(&env)?;
-        requester.require_auth();
+        member.require_auth();
 
         let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
-
-        // Verify member
-        if !utils::is_member(&group.members, &requester) {
-            return Err(AjoError::NotMember);
-        }
-
-        // Check group state
-        if group.state == crate::types::GroupState::Cancelled {
-            return Err(AjoError::GroupCancelled);
-        }
-        if group.state == crate::types::GroupState::Complete {
-            return Err(AjoError::GroupComplete);
+        if group.payout_strategy != PayoutOrderingStrategy::Random {
+            return Err(AjoError::InvalidStrategy);
         }
-
-        // Check if cycle has expired (past grace period)
         let now = utils::get_current_timestamp(&env);
-        let grace_end = utils::get_grace_period_end(&group);
-        if now <= grace_end {
-            return Err(AjoError::CycleNotExpired);
-        }
-
-        // Check if refund request already exists
-        if storage::has_refund_request(&env, group_id) {
-            return Err(AjoError::RefundRequestExists);
-        }
-
-        // Create refund request
-        let voting_deadline = now + crate::types::VOTING_PERIOD;
-        let request = crate::types::RefundRequest {
-            group_id,
-            requester: requester.clone(),
-            created_at: now,
-            voting_deadline,
-            votes_for: 0,
-            votes_against: 0,
-            executed: false,
-            approved: false,
-        };
+        if now <= group.cycle_start_time + group.cycle_duration
+            || now >= utils::get_grace_period_end(&group)
+        {
+            return Err(AjoError::OutsideCycleWindow);
+        }
 
-        storage::store_refund_request(&env, group_id, &request);
+        let cycle = group.current_cycle;
+        let mut commitments = storage::get_random_commitments(&env, group_id, cycle);
+        let index = commitments
+            .iter()
+            .position(|existing| existing.member == member)
+            .ok_or(AjoError::InvalidInput)? as u32;
+        let mut commitment = commitments.get(index).ok_or(AjoError::InvalidInput)?;
+        if commitment.revealed {
+            return Err(AjoError::InvalidState);
+        }
+        let hash: BytesN<32> = env.crypto().sha256(&secret.clone().into()).to_bytes();
+        if hash != commitment.commitment {
+            return Err(AjoError::InvalidInput);
+        }
 
-        // Emit event
-        events::emit_refund_requested(&env, group_id, &requester, voting_deadline);
+        commitment.revealed = true;
+        commitment.secret = secret;
+        commitments.set(index, commitment);
+        storage::store_random_commitments(&env, group_id, cycle, &commitments);
+
+        events::emit_random_revealed(&env, group_id, cycle, &member);
 
         Ok(())
     }
 
-    /// Vote on a refund request.
+    /// List the commitments made towards a cycle's random draw, in commit order.
     ///
-    /// Members can vote in favor or against a refund request during the voting period.
-    /// Each member can only vote once.
+    /// Together with the seed recorded in the cycle's
+    /// [`PayoutOrder`](crate::types::PayoutOrder), this lets anyone re-compute
+    /// the draw.
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    pub fn get_random_commitments(
+        env: Env,
+        group_id: u64,
+        cycle: u32,
+    ) -> Result<Vec<crate::types::RandomCommitment>, AjoError> {
+        storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        Ok(storage::get_random_commitments(&env, group_id, cycle))
+    }
+
+    // ── Need-based selection ──────────────────────────────────────────────────
+
+    /// Declare a need for an early payout in a need-based group.
+    ///
+    /// Declaring again replaces the earlier declaration and clears its
+    /// endorsements. Evidence itself stays off-chain; only its hash is stored.
     ///
     /// # Arguments
     /// * `env` - The Soroban contract environment
-    /// * `voter` - Address of the voting member
-    /// * `group_id` - The unique group identifier
-    /// * `in_favor` - true to vote in favor, false to vote against
-    ///
-    /// # Returns
-    /// `Ok(())` on successful vote
+    /// * `member` - The declaring member (must authenticate)
+    /// * `group_id` - The group
+    /// * `category` - What the payout is needed for
+    /// * `urgency` - From 1 (can wait) to 5 (critical)
+    /// * `needed_by` - Unix timestamp by which the funds are needed
+    /// * `evidence_hash` - Hash of the off-chain evidence
     ///
     /// # Errors
-    /// * `GroupNotFound` - If the group doesn't exist
-    /// * `NotMember` - If the voter is not a member
-    /// * `NoRefundRequest` - If no refund request exists
-    /// * `AlreadyVoted` - If the member has already voted
-    /// * `VotingPeriodEnded` - If the voting period has ended
-    pub fn vote_refund(
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `InvalidStrategy` - If the group is not need-based
+    /// * `GroupComplete` - If the group has completed all cycles
+    /// * `GroupCancelled` - If the group has been cancelled
+    /// * `NotMember` - If the address is not a member
+    /// * `AlreadyReceivedPayout` - If the member has already been paid
+    /// * `InvalidInput` - If urgency is outside 1–5
+    pub fn declare_need(
         env: Env,
-        voter: Address,
+        member: Address,
         group_id: u64,
-        in_favor: bool,
+        category: crate::types::NeedCategory,
+        urgency: u32,
+        needed_by: u64,
+        evidence_hash: BytesN<32>,
     ) -> Result<(), AjoError> {
         pausable::ensure_not_paused(&env)?;
-        voter.require_auth();
+        member.require_auth();
 
         let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
-
-        // Verify member
-        if !utils::is_member(&group.members, &voter) {
+        if group.payout_strategy != PayoutOrderingStrategy::NeedBased {
+            return Err(AjoError::InvalidStrategy);
+        }
+        if group.is_complete {
+            return Err(AjoError::GroupComplete);
+        }
+        if group.state == crate::types::GroupState::Cancelled {
+            return Err(AjoError::GroupCancelled);
+        }
+        if !utils::is_member(&group.members, &member) {
             return Err(AjoError::NotMember);
         }
-
-        // Get refund request
-        let mut request = storage::get_refund_request(&env, group_id)
-            .ok_or(AjoError::NoRefundRequest)?;
-
-        // Check if already voted
-        if storage::has_voted(&env, group_id, &voter) {
-            return Err(AjoError::AlreadyVoted);
+        if !utils::has_payout_remaining(&env, group_id, &member) {
+            return Err(AjoError::AlreadyReceivedPayout);
         }
-
-        // Check voting period
-        let now = utils::get_current_timestamp(&env);
-        if now > request.voting_deadline {
-            return Err(AjoError::VotingPeriodEnded);
+        if !(crate::types::MIN_NEED_URGENCY..=crate::types::MAX_NEED_URGENCY).contains(&urgency) {
+            return Err(AjoError::InvalidInput);
         }
 
-        // Record vote
-        let vote = crate::types::RefundVote {
+        let declaration = crate::types::NeedDeclaration {
             group_id,
-            voter: voter.clone(),
-            in_favor,
-            timestamp: now,
+            member: member.clone(),
+            category,
+            urgency,
+            needed_by,
+            evidence_hash,
+            endorsers: Vec::new(&env),
+            declared_cycle: group.current_cycle,
+            declared_at: utils::get_current_timestamp(&env),
         };
-        storage::store_refund_vote(&env, group_id, &voter, &vote);
-
-        // Update vote counts
-        if in_favor {
-            request.votes_for += 1;
-        } else {
-            request.votes_against += 1;
-        }
-        storage::store_refund_request(&env, group_id, &request);
+        storage::store_need_declaration(&env, &declaration);
 
-        // Emit event
-        events::emit_refund_vote(&env, group_id, &voter, in_favor);
+        events::emit_need_declared(&env, group_id, &member, category as u32, urgency);
 
         Ok(())
     }
 
-    /// Execute a refund after voting period ends.
-    ///
-    /// Can be called by any member after the voting period ends. If the refund
-    /// is approved (>51% votes in favor), all members receive token refunds
-    /// based on their contributions.
+    /// Endorse another member's need declaration.
     ///
     /// # Arguments
     /// * `env` - The Soroban contract environment
-    /// * `executor` - Address executing the refund
-    /// * `group_id` - The unique group identifier
+    /// * `endorser` - The endorsing member (must authenticate)
+    /// * `group_id` - The group
+    /// * `member` - The member whose declaration is endorsed
     ///
     /// # Returns
-    /// `Ok(())` on successful execution and token refunds
+    /// The declaration's endorsement count
     ///
     /// # Errors
-    /// * `GroupNotFound` - If the group doesn't exist
-    /// * `NoRefundRequest` - If no refund request exists
-    /// * `VotingPeriodActive` - If the voting period hasn't ended
-    /// * `RefundNotApproved` - If the refund wasn't approved
-    /// * `RefundAlreadyExecuted` - If the refund has already been executed
-    /// * `TransferFailed` - If any token refund transfer fails
-    pub fn execute_refund(env: Env, executor: Address, group_id: u64) -> Result<(), AjoError> {
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `NotMember` - If the endorser is not a member
+    /// * `InvalidInput` - If the member has not declared a need
+    /// * `Unauthorized` - If the endorser is the declaring member
+    /// * `AlreadyVoted` - If the endorser already endorsed this declaration
+    pub fn endorse_need(
+        env: Env,
+        endorser: Address,
+        group_id: u64,
+        member: Address,
+    ) -> Result<u32, AjoError> {
         pausable::ensure_not_paused(&env)?;
-        executor.require_auth();
+        endorser.require_auth();
 
-        let mut group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
-        let mut request = storage::get_refund_request(&env, group_id)
-            .ok_or(AjoError::NoRefundRequest)?;
-
-        // Check if already executed
-        if request.executed {
-            return Err(AjoError::RefundAlreadyExecuted);
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        if group.is_complete {
+            return Err(AjoError::GroupComplete);
         }
-
-        // Check voting period ended
-        let now = utils::get_current_timestamp(&env);
-        if now <= request.voting_deadline {
-            return Err(AjoError::VotingPeriodActive);
+        if group.state == crate::types::GroupState::Cancelled {
+            return Err(AjoError::GroupCancelled);
         }
-
-        // Calculate approval percentage
-        let total_votes = request.votes_for + request.votes_against;
-        let approval_percentage = if total_votes > 0 {
-            (request.votes_for * 100) / total_votes
-        } else {
-            0
-        };
-
-        // Check if approved
-        if approval_percentage < crate::types::REFUND_APPROVAL_THRESHOLD {
-            request.executed = true;
-            request.approved = false;
-            storage::store_refund_request(&env, group_id, &request);
-            return Err(AjoError::RefundNotApproved);
+        if !utils::is_member(&group.members, &endorser) {
+            return Err(AjoError::NotMember);
+        }
+        let mut declaration = storage::get_need_declaration(&env, group_id, &member)
+            .ok_or(AjoError::InvalidInput)?;
+        if endorser == member {
+            return Err(AjoError::Unauthorized);
+        }
+        if utils::is_member(&declaration.endorsers, &endorser) {
+            return Err(AjoError::AlreadyVoted);
         }
 
-        // Process refunds for all members who contributed
-        let contract_address = env.current_contract_address();
-        
-        for member in group.members.iter() {
-            if storage::has_contributed(&env, group_id, group.current_cycle, &member) {
-                let refund_amount = group.contribution_amount;
-
-                // Transfer tokens back to member
-                crate::token::transfer_token(
-                    &env,
-                    &group.token_address,
-                    &contract_address,
-                    &member,
-                    refund_amount,
-                )?;
-
-                // Store refund record
-                let refund_record = crate::types::RefundRecord {
-                    group_id,
-                    member: member.clone(),
-                    amount: refund_amount,
-                    timestamp: now,
-                    reason: crate::types::RefundReason::MemberVote,
-                };
-                storage::store_refund_record(&env, group_id, &member, &refund_record);
+        declaration.endorsers.push_back(endorser.clone());
+        storage::store_need_declaration(&env, &declaration);
 
-                // Emit refund event
-                events::emit_refund_processed(&env, group_id, &member, refund_amount, 1);
-            }
-        }
+        let endorsements = declaration.endorsers.len();
+        events::emit_need_endorsed(&env, group_id, &endorser, &member, endorsements);
 
-        // Update request and group state
-        request.executed = true;
-        request.approved = true;
-        storage::store_refund_request(&env, group_id, &request);
+        Ok(endorsements)
+    }
 
-        group.state = crate::types::GroupState::Cancelled;
-        storage::store_group(&env, group_id, &group);
+    /// Get a member's need declaration.
+    ///
+    /// # Errors
+    /// * `InvalidInput` - If the member has not declared a need
+    pub fn get_need_declaration(
+        env: Env,
+        group_id: u64,
+        member: Address,
+    ) -> Result<crate::types::NeedDeclaration, AjoError> {
+        storage::get_need_declaration(&env, group_id, &member).ok_or(AjoError::InvalidInput)
+    }
 
-        Ok(())
+    /// Get the score a member's need declaration currently carries in selection.
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `InvalidInput` - If the member has not declared a need
+    pub fn get_need_score(env: Env, group_id: u64, member: Address) -> Result<u32, AjoError> {
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        let declaration = storage::get_need_declaration(&env, group_id, &member)
+            .ok_or(AjoError::InvalidInput)?;
+        Ok(utils::need_score(&group, &declaration))
     }
 
-    /// Emergency refund by admin.
+    // ── Payout auctions ───────────────────────────────────────────────────────
+
+    /// Bid a discount to receive the current cycle's pot.
     ///
-    /// Allows the contract admin to force a refund in case of disputes or emergencies.
-    /// All members who have contributed receive their token contributions back.
+    /// Only callable for [`PayoutOrderingStrategy::Auction`] groups, until the
+    /// cycle's contribution deadline (`cycle_start_time + cycle_duration`).
+    /// The largest discount wins the pot at payout and is shared among the
+    /// other members. Bidding again replaces the member's earlier bid.
     ///
     /// # Arguments
     /// * `env` - The Soroban contract environment
-    /// * `admin` - Address of the contract admin
-    /// * `group_id` - The unique group identifier
-    ///
-    /// # Returns
-    /// `Ok(())` on successful emergency refund and token transfers
+    /// * `member` - The bidding member (must authenticate)
+    /// * `group_id` - The group
+    /// * `discount` - Amount of the pot the member gives up
     ///
     /// # Errors
-    /// * `Unauthorized` - If the caller is not the admin
-    /// * `GroupNotFound` - If the group doesn't exist
-    /// * `GroupCancelled` - If the group is already cancelled
-    /// * `TransferFailed` - If any token refund transfer fails
-    pub fn emergency_refund(env: Env, admin: Address, group_id: u64) -> Result<(), AjoError> {
-        admin.require_auth();
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `InvalidStrategy` - If the group does not use auction ordering
+    /// * `GroupComplete` - If the group has completed all cycles
+    /// * `GroupCancelled` - If the group has been cancelled
+    /// * `NotMember` - If the bidder is not a member
+    /// * `AlreadyReceivedPayout` - If the bidder has already been paid
+    /// * `InvalidInput` - If the discount is not positive and below the pot, or
+    ///   the bidder forfeited their slot
+    /// * `OutsideCycleWindow` - If the cycle's contribution deadline has passed
+    pub fn place_payout_bid(
+        env: Env,
+        member: Address,
+        group_id: u64,
+        discount: i128,
+    ) -> Result<(), AjoError> {
+        pausable::ensure_not_paused(&env)?;
+        member.require_auth();
 
-        // Verify admin
-        let stored_admin = storage::get_admin(&env).ok_or(AjoError::Unauthorized)?;
-        if admin != stored_admin {
-            return Err(AjoError::Unauthorized);
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        if group.payout_strategy != PayoutOrderingStrategy::Auction {
+            return Err(AjoError::InvalidStrategy);
+        }
+        if group.is_complete {
+            return Err(AjoError::GroupComplete);
         }
-
-        let mut group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
-
-        // Check if already cancelled
         if group.state == crate::types::GroupState::Cancelled {
             return Err(AjoError::GroupCancelled);
         }
+        if !utils::is_member(&group.members, &member) {
+            return Err(AjoError::NotMember);
+        }
+        if !utils::has_payout_remaining(&env, group_id, &member) {
+            return Err(AjoError::AlreadyReceivedPayout);
+        }
+        if discount <= 0
+            || discount >= utils::calculate_payout_amount(&env, &group)
+            || utils::is_member(&storage::get_forfeited_members(&env, group_id), &member)
+        {
+            return Err(AjoError::InvalidInput);
+        }
 
         let now = utils::get_current_timestamp(&env);
-        let mut total_refunded = 0i128;
-        let contract_address = env.current_contract_address();
+        if now > group.cycle_start_time + group.cycle_duration {
+            return Err(AjoError::OutsideCycleWindow);
+        }
 
-        // Process refunds for all members who contributed
-        for member in group.members.iter() {
-            if storage::has_contributed(&env, group_id, group.current_cycle, &member) {
-                let refund_amount = group.contribution_amount;
-                total_refunded += refund_amount;
+        // Replace any earlier bid; the new one queues behind existing bids
+        let cycle = group.current_cycle;
+        let mut bids = storage::get_cycle_bids(&env, group_id, cycle);
+        if let Some(index) = bids.iter().position(|bid| bid.bidder == member) {
+            bids.remove(index as u32);
+        }
+        bids.push_back(crate::types::PayoutBid {
+            group_id,
+            cycle,
+            bidder: member.clone(),
+            discount,
+            timestamp: now,
+        });
+        storage::store_cycle_bids(&env, group_id, cycle, &bids);
 
-                // Transfer tokens back to member
-                crate::token::transfer_token(
-                    &env,
-                    &group.token_address,
-                    &contract_address,
-                    &member,
-                    refund_amount,
-                )?;
+        events::emit_payout_bid(&env, group_id, cycle, &member, discount);
 
-                // Store refund record
-                let refund_record = crate::types::RefundRecord {
-                    group_id,
-                    member: member.clone(),
-                    amount: refund_amount,
-                    timestamp: now,
-                    reason: crate::types::RefundReason::EmergencyRefund,
-                };
-                storage::store_refund_record(&env, group_id, &member, &refund_record);
+        Ok(())
+    }
 
-                // Emit refund event
-                events::emit_refund_processed(&env, group_id, &member, refund_amount, 2);
-            }
+    /// List the bids placed in a cycle, in the order they were placed.
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    pub fn get_cycle_bids(
+        env: Env,
+        group_id: u64,
+        cycle: u32,
+    ) -> Result<Vec<crate::types::PayoutBid>, AjoError> {
+        storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        Ok(storage::get_cycle_bids(&env, group_id, cycle))
+    }
+
+    /// Choose how auction discounts reach the other members.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `creator` - Address of the group creator (must authenticate)
+    /// * `group_id` - The group to update
+    /// * `settlement` - Pay shares out at payout, or credit them against the
+    ///   next contribution
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `Unauthorized` - If the caller is not the group creator
+    /// * `GroupComplete` - If the group has completed all cycles
+    /// * `GroupCancelled` - If the group has been cancelled
+    pub fn set_discount_settlement(
+        env: Env,
+        creator: Address,
+        group_id: u64,
+        settlement: crate::types::DiscountSettlement,
+    ) -> Result<(), AjoError> {
+        pausable::ensure_not_paused(&env)?;
+        creator.require_auth();
+
+        let mut group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        if group.creator != creator {
+            return Err(AjoError::Unauthorized);
+        }
+        if group.is_complete {
+            return Err(AjoError::GroupComplete);
+        }
+        if group.state == crate::types::GroupState::Cancelled {
+            return Err(AjoError::GroupCancelled);
         }
 
-        // Update group state
-        group.state = crate::types::GroupState::Cancelled;
+        group.discount_settlement = settlement;
         storage::store_group(&env, group_id, &group);
 
-        // Emit emergency refund event
-        events::emit_emergency_refund(&env, group_id, &admin, total_refunded);
+        events::emit_discount_settlement_changed(&env, group_id, settlement as u32);
 
         Ok(())
     }
 
-    /// Get refund request for a group.
+    /// Get a member's unused auction credit in a group.
+    pub fn get_auction_credit(env: Env, group_id: u64, member: Address) -> i128 {
+        storage::get_auction_credit(&env, group_id, &member)
+    }
+
+    // ── Payout position swaps ─────────────────────────────────────────────────
+
+    /// Propose exchanging payout positions with another member.
     ///
-    /// Returns the current refund request if one exists.
+    /// Only available for sequentially ordered groups. Neither member may have
+    /// been paid yet. The counterparty completes the swap with
+    /// `accept_position_swap`.
     ///
     /// # Arguments
     /// * `env` - The Soroban contract environment
-    /// * `group_id` - The unique group identifier
+    /// * `proposer` - The member proposing the swap (must authenticate)
+    /// * `group_id` - The group
+    /// * `counterparty` - The member whose position the proposer wants
     ///
     /// # Returns
-    /// The refund request
+    /// The new swap's ID
     ///
     /// # Errors
-    /// * `NoRefundRequest` - If no refund request exists
-    pub fn get_refund_request(
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `GroupComplete` - If the group has completed all cycles
+    /// * `GroupCancelled` - If the group has been cancelled
+    /// * `InvalidStrategy` - If the group does not use sequential ordering
+    /// * `NotMember` - If either address is not a member
+    /// * `InvalidInput` - If both addresses are the same or either forfeited their slot
+    /// * `AlreadyReceivedPayout` - If either member has already been paid
+    pub fn propose_position_swap(
         env: Env,
+        proposer: Address,
         group_id: u64,
-    ) -> Result<crate::types::RefundRequest, AjoError> {
-        storage::get_refund_request(&env, group_id).ok_or(AjoError::NoRefundRequest)
+        counterparty: Address,
+    ) -> Result<u64, AjoError> {
+        pausable::ensure_not_paused(&env)?;
+        proposer.require_auth();
+
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        if group.is_complete {
+            return Err(AjoError::GroupComplete);
+        }
+        if group.state == crate::types::GroupState::Cancelled {
+            return Err(AjoError::GroupCancelled);
+        }
+        utils::validate_position_swap(&env, &group, &proposer, &counterparty)?;
+
+        let swap = crate::types::PositionSwap {
+            id: storage::get_next_swap_id(&env),
+            group_id,
+            proposer: proposer.clone(),
+            counterparty: counterparty.clone(),
+            status: crate::types::SwapStatus::Proposed,
+            proposed_at: utils::get_current_timestamp(&env),
+            closed_at: 0,
+        };
+        storage::store_position_swap(&env, &swap);
+
+        events::emit_swap_proposed(&env, group_id, swap.id, &proposer, &counterparty);
+
+        Ok(swap.id)
     }
 
-    /// Get refund record for a member.
+    /// Accept a position swap proposed to you.
     ///
-    /// Returns the refund record if the member has received a refund.
+    /// Exchanges the two members' positions immediately, unless the group
+    /// requires the creator's approval, in which case the swap waits for
+    /// `approve_position_swap`.
     ///
     /// # Arguments
     /// * `env` - The Soroban contract environment
-    /// * `group_id` - The unique group identifier
-    /// * `member` - The member's address
-    ///
-    /// # Returns
-    /// The refund record
+    /// * `counterparty` - The member the swap was proposed to (must authenticate)
+    /// * `group_id` - The group
+    /// * `swap_id` - The swap to accept
     ///
     /// # Errors
-    /// * `GroupNotFound` - If no refund record exists
-pub fn get_refund_record(
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `InvalidInput` - If the swap does not exist in this group
+    /// * `Unauthorized` - If the caller is not the swap's counterparty
+    /// * `InvalidState` - If the swap is not waiting for the counterparty
+    /// * `AlreadyReceivedPayout` - If either member has been paid since the proposal
+    pub fn accept_position_swap(
         env: Env,
+        counterparty: Address,
         group_id: u64,
-        member: Address,
-    ) -> Result<crate::types::RefundRecord, AjoError> {
-        storage::get_refund_record(&env, group_id, &member).ok_or(AjoError::GroupNotFound)
+        swap_id: u64,
+    ) -> Result<(), AjoError> {
+        pausable::ensure_not_paused(&env)?;
+        counterparty.require_auth();
+
+        let mut group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        let mut swap = storage::get_position_swap(&env, swap_id)
+            .filter(|swap| swap.group_id == group_id)
+            .ok_or(AjoError::InvalidInput)?;
+        if swap.counterparty != counterparty {
+            return Err(AjoError::Unauthorized);
+        }
+        if swap.status != crate::types::SwapStatus::Proposed {
+            return Err(AjoError::InvalidState);
+        }
+        if group.is_complete {
+            return Err(AjoError::GroupComplete);
+        }
+        if group.state == crate::types::GroupState::Cancelled {
+            return Err(AjoError::GroupCancelled);
+        }
+
+        if group.swap_requires_approval {
+            utils::validate_position_swap(&env, &group, &swap.proposer, &swap.counterparty)?;
+            swap.status = crate::types::SwapStatus::AwaitingApproval;
+            storage::store_position_swap(&env, &swap);
+            events::emit_swap_accepted(&env, group_id, swap_id);
+            return Ok(());
+        }
+
+        utils::swap_positions(&env, &mut group, &swap.proposer, &swap.counterparty)?;
+        swap.status = crate::types::SwapStatus::Completed;
+        swap.closed_at = utils::get_current_timestamp(&env);
+        storage::store_position_swap(&env, &swap);
+
+        events::emit_positions_swapped(&env, group_id, swap_id, &swap.proposer, &swap.counterparty);
+
+        Ok(())
     }
 
-    /// Get the contract's token balance for a specific token.
+    /// Approve a swap both members have agreed to.
     ///
-    /// Returns the amount of tokens held by the contract for a given token address.
-    /// Useful for checking if the contract has sufficient funds for payouts.
+    /// Only needed when the group requires approval for swaps.
     ///
     /// # Arguments
     /// * `env` - The Soroban contract environment
-    /// * `token_address` - Address of the token contract
+    /// * `creator` - The group creator (must authenticate)
+    /// * `group_id` - The group
+    /// * `swap_id` - The swap to approve
     ///
-    /// # Returns
-    /// The token balance held by the contract
-    pub fn get_contract_balance(env: Env, token_address: Address) -> i128 {
-        let contract_address = env.current_contract_address();
-        crate::token::get_balance(&env, &token_address, &contract_address)
-    }
-
-    /// File an insurance claim for non-payment.
-    pub fn file_insurance_claim(
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `Unauthorized` - If the caller is not the group creator
+    /// * `InvalidInput` - If the swap does not exist in this group
+    /// * `InvalidState` - If the swap is not waiting for approval
+    /// * `AlreadyReceivedPayout` - If either member has been paid since the proposal
+    pub fn approve_position_swap(
         env: Env,
-        claimant: Address,
+        creator: Address,
         group_id: u64,
-        cycle: u32,
-        defaulter: Address,
-        amount: i128,
-    ) -> Result<u64, AjoError> {
-        claimant.require_auth();
-        crate::insurance::file_claim(&env, group_id, cycle, claimant, defaulter, amount)
-    }
-
-    /// Process (approve/reject) an insurance claim.
-    /// Only the contract admin can process claims.
-    pub fn process_insurance_claim(
-        env: Env,
-        admin: Address,
-        claim_id: u64,
-        approved: bool,
+        swap_id: u64,
     ) -> Result<(), AjoError> {
-        let contract_admin = storage::get_admin(&env).ok_or(AjoError::Unauthorized)?;
-        contract_admin.require_auth();
-        crate::insurance::process_claim(&env, claim_id, approved)
-    }
+        pausable::ensure_not_paused(&env)?;
+        creator.require_auth();
 
-    /// Get insurance pool details for a specific token.
-    pub fn get_insurance_pool(env: Env, token_address: Address) -> Result<crate::types::InsurancePool, AjoError> {
-        storage::get_insurance_pool(&env, &token_address).ok_or(AjoError::PoolNotFound)
-    }
+        let mut group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        if group.creator != creator {
+            return Err(AjoError::Unauthorized);
+        }
+        let mut swap = storage::get_position_swap(&env, swap_id)
+            .filter(|swap| swap.group_id == group_id)
+            .ok_or(AjoError::InvalidInput)?;
+        if swap.status != crate::types::SwapStatus::AwaitingApproval {
+            return Err(AjoError::InvalidState);
+        }
+        if group.is_complete {
+            return Err(AjoError::GroupComplete);
+        }
+        if group.state == crate::types::GroupState::Cancelled {
+            return Err(AjoError::GroupCancelled);
+        }
 
-    /// Get insurance claim details.
-    pub fn get_insurance_claim(env: Env, claim_id: u64) -> Result<crate::types::InsuranceClaim, AjoError> {
-        storage::get_insurance_claim(&env, claim_id).ok_or(AjoError::InvalidClaim)
-    }
+        utils::swap_positions(&env, &mut group, &swap.proposer, &swap.counterparty)?;
+        swap.status = crate::types::SwapStatus::Completed;
+        swap.closed_at = utils::get_current_timestamp(&env);
+        storage::store_position_swap(&env, &swap);
 
-    /// Get risk score for a member.
-    pub fn get_member_risk_score(env: Env, member: Address) -> u32 {
-        crate::insurance::get_member_risk_score(&env, &member)
-    }
+        events::emit_positions_swapped(&env, group_id, swap_id, &swap.proposer, &swap.counterparty);
 
-    /// Get risk rating for a group.
-    pub fn get_group_risk_rating(env: Env, group_id: u64) -> Result<u32, AjoError> {
-        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
-        Ok(crate::insurance::get_group_risk_rating(&env, &group))
+        Ok(())
     }
 
-    // ── Dynamic payout ordering ───────────────────────────────────────────────
-
-    /// Create a new Ajo group with an explicit payout ordering strategy.
+    /// Withdraw a position swap that has not completed.
     ///
-    /// Identical to [`create_group`] except the caller chooses one of the five
-    /// [`PayoutOrderingStrategy`] variants.  For `Sequential` behaviour,
-    /// [`create_group`] is preferred; this function targets groups that want
-    /// `Random`, `VotingBased`, `ContributionBased`, or `NeedBased` ordering.
-    pub fn create_group_with_ordering(
+    /// Either member can cancel, and the creator can decline a swap that is
+    /// waiting for approval.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `caller` - The proposer, counterparty or group creator (must authenticate)
+    /// * `group_id` - The group
+    /// * `swap_id` - The swap to cancel
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `InvalidInput` - If the swap does not exist in this group
+    /// * `Unauthorized` - If the caller may not cancel the swap
+    /// * `InvalidState` - If the swap has already completed or been cancelled
+    pub fn cancel_position_swap(
         env: Env,
-        creator: Address,
-        token_address: Address,
-        contribution_amount: i128,
-        cycle_duration: u64,
-        max_members: u32,
-        grace_period: u64,
-        penalty_rate: u32,
-        insurance_rate_bps: u32,
-        payout_strategy: PayoutOrderingStrategy,
-    ) -> Result<u64, AjoError> {
-        utils::validate_group_params(contribution_amount, cycle_duration, max_members)?;
-        utils::validate_penalty_params(grace_period, penalty_rate)?;
+        caller: Address,
+        group_id: u64,
+        swap_id: u64,
+    ) -> Result<(), AjoError> {
         pausable::ensure_not_paused(&env)?;
-        creator.require_auth();
+        caller.require_auth();
 
-        let group_id = storage::get_next_group_id(&env);
-        let mut members = Vec::new(&env);
-        members.push_back(creator.clone());
-        let now = utils::get_current_timestamp(&env);
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        let mut swap = storage::get_position_swap(&env, swap_id)
+            .filter(|swap| swap.group_id == group_id)
+            .ok_or(AjoError::InvalidInput)?;
+
+        let is_party = caller == swap.proposer || caller == swap.counterparty;
+        let creator_declining = caller == group.creator
+            && swap.status == crate::types::SwapStatus::AwaitingApproval;
+        if !is_party && !creator_declining {
+            return Err(AjoError::Unauthorized);
+        }
+        if swap.status != crate::types::SwapStatus::Proposed
+            && swap.status != crate::types::SwapStatus::AwaitingApproval
+        {
+            return Err(AjoError::InvalidState);
+        }
 
-        let group = Group {
-            id: group_id,
-            creator: creator.clone(),
-            token_address,
-            contribution_amount,
-            cycle_duration,
-            max_members,
-            members,
-            current_cycle: 1,
-            payout_index: 0,
-            created_at: now,
-            cycle_start_time: now,
-            is_complete: false,
-            grace_period,
-            penalty_rate,
-            state: crate::types::GroupState::Active,
-            insurance_config: crate::types::InsuranceConfig {
-                rate_bps: insurance_rate_bps,
-                is_enabled: insurance_rate_bps > 0,
-            },
-            payout_strategy,
-            access_type: crate::types::GroupAccessType::Open,
-        };
+        swap.status = crate::types::SwapStatus::Cancelled;
+        swap.closed_at = utils::get_current_timestamp(&env);
+        storage::store_position_swap(&env, &swap);
 
-        storage::store_group(&env, group_id, &group);
-        events::emit_group_created(&env, group_id, &creator, contribution_amount, max_members);
-        Ok(group_id)
+        events::emit_swap_cancelled(&env, group_id, swap_id, &caller);
+
+        Ok(())
     }
 
-    /// Cast a vote for the next payout recipient.
-    ///
-    /// Only callable for groups whose strategy is [`PayoutOrderingStrategy::VotingBased`]
-    /// or [`PayoutOrderingStrategy::NeedBased`].  Each member may vote once per cycle;
-    /// calling again replaces the existing vote (last-vote-wins semantics).
+    /// Choose whether position swaps need the creator's approval.
     ///
     /// # Arguments
-    /// * `voter`    - The member casting the vote (must authenticate).
-    /// * `group_id` - The group the vote applies to.
-    /// * `nominee`  - The member being nominated to receive the next payout.
+    /// * `env` - The Soroban contract environment
+    /// * `creator` - Address of the group creator (must authenticate)
+    /// * `group_id` - The group to update
+    /// * `requires_approval` - `true` if accepted swaps wait for the creator
     ///
     /// # Errors
-    /// * `GroupNotFound`         — group does not exist.
-    /// * `VotingNotOpen`         — strategy is not voting-based.
-    /// * `NotMember`             — voter or nominee is not a group member.
-    /// * `AlreadyReceivedPayout` — nominee has already been paid.
-    /// * `GroupComplete`         — all payouts have been distributed.
-    /// * `GroupCancelled`        — group was cancelled.
-    pub fn vote_for_next_recipient(
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `Unauthorized` - If the caller is not the group creator
+    /// * `GroupComplete` - If the group has completed all cycles
+    /// * `GroupCancelled` - If the group has been cancelled
+    pub fn set_swap_approval(
         env: Env,
-        voter: Address,
+        creator: Address,
         group_id: u64,
-        nominee: Address,
+        requires_approval: bool,
     ) -> Result<(), AjoError> {
         pausable::ensure_not_paused(&env)?;
-        voter.require_auth();
-
-        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        creator.require_auth();
 
-        // Guard: strategy must support voting
-        if group.payout_strategy != PayoutOrderingStrategy::VotingBased
-            && group.payout_strategy != PayoutOrderingStrategy::NeedBased
-        {
-            return Err(AjoError::VotingNotOpen);
+        let mut group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        if group.creator != creator {
+            return Err(AjoError::Unauthorized);
         }
-
-        // Guard: group must be active
         if group.is_complete {
             return Err(AjoError::GroupComplete);
         }
@@ -1585,46 +4418,39 @@ pub fn get_refund_record(
             return Err(AjoError::GroupCancelled);
         }
 
-        // Guard: both voter and nominee must be members
-        if !utils::is_member(&group.members, &voter) {
-            return Err(AjoError::NotMember);
-        }
-        if !utils::is_member(&group.members, &nominee) {
-            return Err(AjoError::NotMember);
-        }
-
-        // Guard: nominee must not have already received their payout
-        if storage::has_received_payout(&env, group_id, &nominee) {
-            return Err(AjoError::AlreadyReceivedPayout);
-        }
-
-        let vote = crate::types::PayoutVote {
-            group_id,
-            cycle: group.current_cycle,
-            voter: voter.clone(),
-            nominee: nominee.clone(),
-            timestamp: utils::get_current_timestamp(&env),
-        };
+        group.swap_requires_approval = requires_approval;
+        storage::store_group(&env, group_id, &group);
 
-        storage::store_payout_vote(&env, group_id, group.current_cycle, &voter, &vote);
-        events::emit_payout_vote(&env, group_id, &voter, &nominee, group.current_cycle);
+        events::emit_swap_policy_changed(&env, group_id, requires_approval);
 
         Ok(())
     }
 
-    /// Get the committed payout order recorded for a specific cycle.
-    ///
-    /// Returns the [`PayoutOrder`](crate::types::PayoutOrder) written by
-    /// `execute_payout` for audit and history purposes.
+    /// Get a position swap by ID.
+    ///
+    /// # Errors
+    /// * `InvalidInput` - If the swap does not exist
+    pub fn get_position_swap(env: Env, swap_id: u64) -> Result<crate::types::PositionSwap, AjoError> {
+        storage::get_position_swap(&env, swap_id).ok_or(AjoError::InvalidInput)
+    }
+
+    /// List every position swap proposed in a group, oldest first.
     ///
     /// # Errors
-    /// * `GroupNotFound` — no payout order has been recorded for this cycle yet.
-    pub fn get_payout_order(
+    /// * `GroupNotFound` - If the group does not exist
+    pub fn get_group_position_swaps(
         env: Env,
         group_id: u64,
-        cycle: u32,
-    ) -> Result<crate::types::PayoutOrder, AjoError> {
-        storage::get_payout_order(&env, group_id, cycle).ok_or(AjoError::GroupNotFound)
+    ) -> Result<Vec<crate::types::PositionSwap>, AjoError> {
+        storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+
+        let mut swaps = Vec::new(&env);
+        for swap_id in storage::get_group_swap_ids(&env, group_id).iter() {
+            if let Some(swap) = storage::get_position_swap(&env, swap_id) {
+                swaps.push_back(swap);
+            }
+        }
+        Ok(swaps)
     }
 
     // ── Contribution reminders & notifications ────────────────────────────────
@@ -1717,73 +4543,7 @@ pub fn get_refund_record(
             return Err(AjoError::GroupComplete);
         }
 
-        let now = utils::get_current_timestamp(&env);
-        let cycle_end = group.cycle_start_time + group.cycle_duration;
-        let grace_end = utils::get_grace_period_end(&group);
-
-        let mut reminded = Vec::new(&env);
-
-        for member in group.members.iter() {
-            // Skip members who already contributed this cycle
-            if storage::has_contributed(&env, group_id, group.current_cycle, &member) {
-                continue;
-            }
-
-            // Only remind members who have opted in
-            let prefs = match storage::get_notification_preferences(&env, &member) {
-                Some(p) if p.enabled => p,
-                _ => continue,
-            };
-
-            // Determine which reminder type applies right now
-            let reminder_type = if now < cycle_end {
-                // Before the deadline — check the member's lead-time threshold
-                let secs_until_deadline = cycle_end - now;
-                let threshold_secs = prefs.reminder_hours_before * 3600;
-                if secs_until_deadline <= threshold_secs {
-                    Some(crate::types::ReminderType::ContributionDue)
-                } else {
-                    None
-                }
-            } else if now <= grace_end && prefs.grace_period_reminders {
-                Some(crate::types::ReminderType::GracePeriod)
-            } else if now > grace_end {
-                Some(crate::types::ReminderType::Overdue)
-            } else {
-                None
-            };
-
-            if let Some(rtype) = reminder_type {
-                let record = crate::types::ReminderRecord {
-                    group_id,
-                    cycle: group.current_cycle,
-                    member: member.clone(),
-                    reminder_type: rtype,
-                    triggered_at: now,
-                    deadline: cycle_end,
-                };
-
-                storage::store_reminder_record(
-                    &env,
-                    group_id,
-                    group.current_cycle,
-                    &member,
-                    &record,
-                );
-
-                events::emit_reminder_triggered(
-                    &env,
-                    group_id,
-                    &member,
-                    rtype as u32,
-                    cycle_end,
-                );
-
-                reminded.push_back(member);
-            }
-        }
-
-        Ok(reminded)
+        Ok(utils::send_reminders(&env, &group, false))
     }
 
     /// Retrieve the reminder record for a member in a specific group and cycle.
@@ -1798,6 +4558,8 @@ pub fn get_refund_record(
     ) -> Result<crate::types::ReminderRecord, AjoError> {
         storage::get_reminder_record(&env, group_id, cycle, &member)
             .ok_or(AjoError::GroupNotFound)
+    }
+
     // ── Milestones & Achievements ─────────────────────────────────────────
 
     /// Returns all milestones achieved by a group.
@@ -1829,6 +4591,48 @@ pub fn get_refund_record(
             .unwrap_or_else(|| utils::default_member_stats(&env, &member)))
     }
 
+    /// Returns a member's standing in the groups they belong to, one page at
+    /// a time in ascending group id order.
+    ///
+    /// Pass `0` as `cursor` for the first page and the last `group_id`
+    /// returned for the next. Groups the member left or was removed from are
+    /// not included; completed and cancelled groups are. Groups created before
+    /// members' group lists existed are only included once
+    /// [`backfill_group_indexes`](Self::backfill_group_indexes) has reached them.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `member` - The member to report on
+    /// * `cursor` - Only groups with a higher id are returned
+    /// * `limit` - Maximum number of groups to return (at most `MAX_GROUP_PAGE_SIZE`)
+    ///
+    /// # Returns
+    /// One [`PortfolioEntry`](crate::types::PortfolioEntry) per group
+    pub fn get_member_portfolio(
+        env: Env,
+        member: Address,
+        cursor: u64,
+        limit: u32,
+    ) -> Vec<crate::types::PortfolioEntry> {
+        let limit = limit.min(crate::types::MAX_GROUP_PAGE_SIZE);
+        let group_ids = storage::get_member_groups(&env, &member);
+        let start = match group_ids.binary_search(cursor) {
+            Ok(index) => index + 1,
+            Err(index) => index,
+        };
+
+        let mut portfolio = Vec::new(&env);
+        for group_id in group_ids.iter().skip(start as usize) {
+            if portfolio.len() >= limit {
+                break;
+            }
+            if let Some(group) = storage::get_group(&env, group_id) {
+                portfolio.push_back(utils::portfolio_entry(&env, &group, &member));
+            }
+        }
+        portfolio
+    }
+
     // ── Multi-token support ───────────────────────────────────────────────
 
     /// Create a new multi-token Ajo group that accepts contributions in
@@ -1903,9 +4707,25 @@ pub fn get_refund_record(
             insurance_config: crate::types::InsuranceConfig {
                 rate_bps: insurance_rate_bps,
                 is_enabled: insurance_rate_bps > 0,
+                premium_mode: crate::types::PremiumMode::OnTop,
+                pricing: crate::types::PremiumPricing::Flat,
+                floor_bps: insurance_rate_bps,
+                cap_bps: insurance_rate_bps,
             },
             payout_strategy: PayoutOrderingStrategy::Sequential,
             access_type: crate::types::GroupAccessType::Open,
+            forfeit_payout_on_default: false,
+            collateral_amount: 0,
+            swap_requires_approval: false,
+            discount_settlement: crate::types::DiscountSettlement::PayOut,
+            schedule_rule: crate::types::ScheduleRule::Unlocked,
+            min_members: 0,
+            formation_deadline: 0,
+            allow_late_join: false,
+            rollover_enabled: false,
+            round: 1,
+            predecessor_id: 0,
+            successor_id: 0,
         };
 
         storage::store_group(&env, group_id, &group);
@@ -1969,6 +4789,9 @@ pub fn get_refund_record(
         if group.state == crate::types::GroupState::Cancelled {
             return Err(AjoError::GroupCancelled);
         }
+        if group.state == crate::types::GroupState::Forming {
+            return Err(AjoError::InvalidState);
+        }
         if !utils::is_member(&group.members, &member) {
             return Err(AjoError::NotMember);
         }
@@ -1986,22 +4809,24 @@ pub fn get_refund_record(
 
         // Calculate the required amount in the chosen token's units
         let required_amount = utils::calculate_equivalent_amount(
-            group.contribution_amount,
+            utils::member_contribution(&env, &group, &member),
             primary_weight,
             token_cfg.weight,
         );
 
         let contract_address = env.current_contract_address();
 
-        // Balance check and transfer
-        crate::token::check_balance(&env, &token_address, &member, required_amount)?;
+        // Balance check and transfer (plus any premium paid on top)
+        let premium = crate::insurance::upfront_premium(&env, &group, &member, required_amount);
+        crate::token::check_balance(&env, &token_address, &member, required_amount + premium)?;
         crate::token::transfer_token(
             &env,
             &token_address,
             &member,
             &contract_address,
-            required_amount,
+            required_amount + premium,
         )?;
+        crate::insurance::adjust_pot_liability(&env, &token_address, required_amount);
 
         // Mark the standard contribution flag (keeps existing payout logic working)
         storage::store_contribution(&env, group.id, group.current_cycle, &member, true);
@@ -2015,26 +4840,19 @@ pub fn get_refund_record(
         };
         storage::store_token_contribution(&env, group.id, group.current_cycle, &member, &tk_record);
 
+        // Insurance premium goes to the group's reserve for this token
+        let deducted =
+            crate::insurance::collect_premium(&env, &group, &token_address, &member, required_amount);
+
         // Track per-token balance for multi-token payout
         storage::add_group_token_balance(
             &env,
             group.id,
             group.current_cycle,
             &token_address,
-            required_amount,
+            required_amount - deducted,
         );
 
-        // Insurance premium
-        if group.insurance_config.is_enabled {
-            let premium = crate::insurance::calculate_premium(
-                required_amount,
-                group.insurance_config.rate_bps,
-            );
-            if premium > 0 {
-                crate::insurance::deposit_to_pool(&env, &token_address, premium);
-            }
-        }
-
         events::emit_token_contribution(
             &env,
             group.id,
@@ -2076,12 +4894,13 @@ pub fn get_refund_record(
         if group.state == crate::types::GroupState::Cancelled {
             return Err(AjoError::GroupCancelled);
         }
+        if group.state == crate::types::GroupState::Forming {
+            return Err(AjoError::InvalidState);
+        }
         if group.is_complete {
             return Err(AjoError::GroupComplete);
         }
-        if !utils::all_members_contributed(&env, &group) {
-            return Err(AjoError::IncompleteContributions);
-        }
+        utils::cycle_collected_amount(&env, &group)?;
 
         let current_time = utils::get_current_timestamp(&env);
         let grace_end = utils::get_grace_period_end(&group);
@@ -2096,6 +4915,9 @@ pub fn get_refund_record(
         let contract_address = env.current_contract_address();
         let current_cycle = group.current_cycle;
 
+        // Insured defaults are covered in the group's primary token
+        let insured = crate::insurance::cover_cycle_defaults(&env, &group, &payout_recipient);
+
         // Transfer each token's accumulated balance to the recipient
         for tc in mt_config.accepted_tokens.iter() {
             let balance = storage::get_group_token_balance(
@@ -2104,15 +4926,15 @@ pub fn get_refund_record(
                 current_cycle,
                 &tc.address,
             );
-            if balance > 0 {
-                // Add any penalty bonus proportionally from the primary token pool
-                let penalty_bonus = if tc.address == group.token_address {
-                    storage::get_cycle_penalty_pool(&env, group.id, current_cycle)
-                } else {
-                    0
-                };
-                let payout_amount = balance + penalty_bonus;
-
+            // Add any penalty bonus and insured defaults to the primary token pool,
+            // which is paid even if nobody contributed in the primary token
+            let penalty_bonus = if tc.address == group.token_address {
+                storage::get_cycle_penalty_pool(&env, group.id, current_cycle) + insured
+            } else {
+                0
+            };
+            let payout_amount = balance + penalty_bonus;
+            if payout_amount > 0 {
                 crate::token::check_contract_balance(
                     &env,
                     &tc.address,
@@ -2127,6 +4949,8 @@ pub fn get_refund_record(
                     &payout_recipient,
                     payout_amount,
                 )?;
+                crate::insurance::adjust_pot_liability(&env, &tc.address, -payout_amount);
+                crate::insurance::ensure_solvent(&env, &tc.address)?;
 
                 events::emit_multi_token_payout(
                     &env,
@@ -2151,10 +4975,14 @@ pub fn get_refund_record(
 
         // Advance cycle or mark complete
         group.payout_index += 1;
-        if group.payout_index >= group.members.len() as u32 {
+        if group.payout_index >= utils::payout_slot_count(&env, &group) {
             group.is_complete = true;
             group.state = crate::types::GroupState::Complete;
             events::emit_group_completed(&env, group.id);
+            // Members carrying on take their collateral into the next round
+            utils::roll_over(&env, &mut group);
+            utils::return_all_collateral(&env, &group)?;
+            utils::refund_all_prepayments(&env, &group)?;
         } else {
             group.current_cycle += 1;
             group.cycle_start_time = utils::get_current_timestamp(&env);
@@ -2279,7 +5107,7 @@ pub fn get_refund_record(
             votes_for_action: 0,
             votes_against_action: 0,
             proposed_resolution,
-            final_resolution: None,
+            final_resolution: crate::types::DisputeResolution::NoAction,
         };
 
         storage::store_dispute(&env, dispute_id, &dispute);
@@ -2301,9 +5129,9 @@ pub fn get_refund_record(
     /// # Errors
     /// * `DisputeNotFound` – dispute doesn't exist
     /// * `DisputeAlreadyResolved` – dispute is already resolved
-    /// * `NotDisputeMember` – voter is not a member of the group
-    /// * `AlreadyVotedOnDispute` – voter has already voted
-    /// * `VotingPeriodEndedDispute` – voting period has ended
+    /// * `NotMember` – voter is not a member of the group
+    /// * `AlreadyVoted` – voter has already voted
+    /// * `VotingPeriodEnded` – voting period has ended
     pub fn vote_on_dispute(
         env: Env,
         voter: Address,
@@ -2324,16 +5152,16 @@ pub fn get_refund_record(
 
         let group = storage::get_group(&env, dispute.group_id).ok_or(AjoError::GroupNotFound)?;
         if !utils::is_member(&group.members, &voter) {
-            return Err(AjoError::NotDisputeMember);
+            return Err(AjoError::NotMember);
         }
 
         if storage::has_voted_on_dispute(&env, dispute_id, &voter) {
-            return Err(AjoError::AlreadyVotedOnDispute);
+            return Err(AjoError::AlreadyVoted);
         }
 
         let now = utils::get_current_timestamp(&env);
         if now > dispute.voting_deadline {
-            return Err(AjoError::VotingPeriodEndedDispute);
+            return Err(AjoError::VotingPeriodEnded);
         }
 
         let vote = crate::types::DisputeVote {
@@ -2374,6 +5202,11 @@ pub fn get_refund_record(
         pausable::ensure_not_paused(&env)?;
         resolver.require_auth();
 
+        Self::close_dispute(env, dispute_id)
+    }
+
+    /// Applies or rejects a dispute whose voting period has ended.
+    fn close_dispute(env: Env, dispute_id: u64) -> Result<(), AjoError> {
         let mut dispute = storage::get_dispute(&env, dispute_id)
             .ok_or(AjoError::DisputeNotFound)?;
 
@@ -2395,7 +5228,16 @@ pub fn get_refund_record(
 
         if approved {
             dispute.status = crate::types::DisputeStatus::Resolved;
-            dispute.final_resolution = Some(dispute.proposed_resolution);
+            dispute.final_resolution = dispute.proposed_resolution;
+
+            // Count the outcome against the defendant's risk history
+            if dispute.proposed_resolution != crate::types::DisputeResolution::NoAction {
+                let mut stats = storage::get_member_stats(&env, &dispute.defendant)
+                    .unwrap_or_else(|| utils::default_member_stats(&env, &dispute.defendant));
+                stats.disputes_lost += 1;
+                stats.last_incident_at = now;
+                storage::store_member_stats(&env, &dispute.defendant, &stats);
+            }
 
             // Apply resolution
             match dispute.proposed_resolution {
@@ -2418,14 +5260,7 @@ pub fn get_refund_record(
                         dispute.group_id,
                         &dispute.defendant,
                     )
-                    .unwrap_or(crate::types::MemberPenaltyRecord {
-                        member: dispute.defendant.clone(),
-                        group_id: dispute.group_id,
-                        late_count: 0,
-                        on_time_count: 0,
-                        total_penalties: 0,
-                        reliability_score: 100,
-                    });
+                    .unwrap_or_else(|| utils::default_penalty_record(dispute.group_id, &dispute.defendant));
                     penalty_record.late_count += 1;
                     penalty_record.total_penalties += penalty_amount;
                     storage::store_member_penalty(&env, dispute.group_id, &dispute.defendant, &penalty_record);
@@ -2463,11 +5298,11 @@ pub fn get_refund_record(
             }
         } else {
             dispute.status = crate::types::DisputeStatus::Rejected;
-            dispute.final_resolution = Some(crate::types::DisputeResolution::NoAction);
+            dispute.final_resolution = crate::types::DisputeResolution::NoAction;
         }
 
         storage::store_dispute(&env, dispute_id, &dispute);
-        events::emit_dispute_resolved(&env, dispute_id, dispute.group_id, dispute.final_resolution.unwrap());
+        events::emit_dispute_resolved(&env, dispute_id, dispute.group_id, dispute.final_resolution);
 
         Ok(())
     }
@@ -2485,6 +5320,359 @@ pub fn get_refund_record(
         storage::get_group_dispute_ids(&env, group_id)
     }
 
+    // ── Keepers ───────────────────────────────────────────────────────────
+
+    /// Perform every time-based transition that is due for a group, paying the
+    /// caller a bounty from the group's keeper reserve.
+    ///
+    /// Anyone can crank a group. In order, a crank:
+    /// 1. Closes a refund vote whose voting period has ended
+    /// 2. Resolves disputes whose voting period has ended
+    /// 3. Settles pending insurance claims for recorded defaults once the grace
+    ///    period has ended (claims the pool cannot cover yet stay pending)
+    /// 4. Marks members who missed the cycle as defaulted
+    /// 5. Executes the cycle's payout once every member has paid or defaulted
+    /// 6. Sends contribution reminders members have not already received
+    ///
+    /// The bounty is the group's keeper fee, or the protocol default if it has
+    /// none, applied to its contribution amount and capped at what is left in
+    /// its reserve. Groups without a reserve can still be cranked for free.
+    /// Members can file claims at will, so a crank that only settles claims
+    /// pays no bounty.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The group to crank
+    /// * `caller` - The keeper, who receives the bounty
+    ///
+    /// # Returns
+    /// A [`CrankReport`](crate::types::CrankReport) of what was done
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `GroupComplete` - If the group has completed all cycles
+    /// * `GroupCancelled` - If the group has been cancelled
+    /// * `InvalidState` - If no transition was due
+    /// * Any error from a transition that was due but failed
+    pub fn crank(
+        env: Env,
+        group_id: u64,
+        caller: Address,
+    ) -> Result<crate::types::CrankReport, AjoError> {
+        pausable::ensure_not_paused(&env)?;
+        caller.require_auth();
+
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        if group.is_complete {
+            return Err(AjoError::GroupComplete);
+        }
+        if group.state == crate::types::GroupState::Cancelled {
+            return Err(AjoError::GroupCancelled);
+        }
+
+        let now = utils::get_current_timestamp(&env);
+        let mut report = crate::types::CrankReport {
+            refund_vote_closed: false,
+            disputes_resolved: 0,
+            claims_settled: 0,
+            defaults_marked: 0,
+            payout_executed: false,
+            reminders_sent: 0,
+            bounty: 0,
+        };
+
+        if let Some(request) = storage::get_refund_request(&env, group_id) {
+            if !request.executed && now > request.voting_deadline {
+                // A rejected request is still closed
+                match Self::close_refund_vote(env.clone(), group_id) {
+                    Ok(()) | Err(AjoError::RefundNotApproved) => report.refund_vote_closed = true,
+                    Err(err) => return Err(err),
+                }
+            }
+        }
+
+        for dispute_id in storage::get_group_dispute_ids(&env, group_id).iter() {
+            let dispute = match storage::get_dispute(&env, dispute_id) {
+                Some(dispute) => dispute,
+                None => continue,
+            };
+            let open = dispute.status != crate::types::DisputeStatus::Resolved
+                && dispute.status != crate::types::DisputeStatus::Rejected;
+            if open && now > dispute.voting_deadline {
+                Self::close_dispute(env.clone(), dispute_id)?;
+                report.disputes_resolved += 1;
+            }
+        }
+
+        // A refund or dispute may have changed the group
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        let running = group.state != crate::types::GroupState::Cancelled
+            && group.state != crate::types::GroupState::Forming;
+        let grace_end = utils::get_grace_period_end(&group);
+
+        if group.state != crate::types::GroupState::Cancelled && now >= grace_end {
+            let mut still_pending = Vec::new(&env);
+            for claim_id in storage::get_pending_claims(&env, group_id).iter() {
+                let Some(claim) = storage::get_insurance_claim(&env, claim_id) else {
+                    continue;
+                };
+                // Claims not backed by a recorded default are left to the admin
+                if storage::get_default_record(&env, group_id, claim.cycle, &claim.defaulter)
+                    .is_none()
+                {
+                    still_pending.push_back(claim_id);
+                    continue;
+                }
+                match crate::insurance::auto_process_claim(&env, claim_id) {
+                    Ok(()) => report.claims_settled += 1,
+                    Err(AjoError::InsufficientPoolBalance) => still_pending.push_back(claim_id),
+                    // Settled some other way since it was filed
+                    Err(AjoError::ClaimAlreadyProcessed) => {}
+                    Err(err) => return Err(err),
+                }
+            }
+            storage::store_pending_claims(&env, group_id, &still_pending);
+        }
+
+        if running && now > grace_end {
+            report.defaults_marked = Self::mark_defaults(env.clone(), group_id)?.len();
+        }
+
+        if running
+            && now >= grace_end
+            && utils::cycle_collected_amount(&env, &group).is_ok()
+            && utils::preview_next_recipient(&env, &group).is_ok()
+        {
+            if storage::get_multi_token_config(&env, group_id).is_some() {
+                Self::execute_multi_token_payout(env.clone(), group_id)?;
+            } else {
+                Self::execute_payout(env.clone(), group_id)?;
+            }
+            report.payout_executed = true;
+        }
+
+        // Reminders are for whichever cycle is now open
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        if running && !group.is_complete {
+            report.reminders_sent = utils::send_reminders(&env, &group, true).len();
+        }
+
+        let bountied = report.refund_vote_closed
+            || report.disputes_resolved > 0
+            || report.defaults_marked > 0
+            || report.payout_executed
+            || report.reminders_sent > 0;
+        if !bountied && report.claims_settled == 0 {
+            return Err(AjoError::InvalidState);
+        }
+
+        let reserve = storage::get_keeper_reserve(&env, group_id);
+        let bounty = if bountied {
+            utils::keeper_bounty(&env, &group).min(reserve)
+        } else {
+            0
+        };
+        if bounty > 0 {
+            crate::token::transfer_token(
+                &env,
+                &group.token_address,
+                &env.current_contract_address(),
+                &caller,
+                bounty,
+            )?;
+            storage::store_keeper_reserve(&env, group_id, reserve - bounty);
+            crate::insurance::adjust_keeper_liability(&env, &group.token_address, -bounty);
+        }
+        report.bounty = bounty;
+
+        events::emit_cranked(&env, group_id, &caller, bounty);
+
+        Ok(report)
+    }
+
+    /// Set the protocol-wide keeper bounty used by groups without their own.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `fee_bps` - Bounty in basis points of a group's contribution amount
+    ///
+    /// # Errors
+    /// * `Unauthorized` - If the contract has no admin
+    /// * `InvalidInput` - If `fee_bps` exceeds `MAX_KEEPER_FEE_BPS`
+    pub fn set_default_keeper_fee(env: Env, fee_bps: u32) -> Result<(), AjoError> {
+        let admin = storage::get_admin(&env).ok_or(AjoError::Unauthorized)?;
+        admin.require_auth();
+
+        if fee_bps > crate::types::MAX_KEEPER_FEE_BPS {
+            return Err(AjoError::InvalidInput);
+        }
+
+        storage::store_default_keeper_fee(&env, fee_bps);
+        events::emit_keeper_fee_set(&env, 0, fee_bps);
+
+        Ok(())
+    }
+
+    /// Set a group's own keeper bounty, overriding the protocol default.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `creator` - The group creator
+    /// * `group_id` - The group to configure
+    /// * `fee_bps` - Bounty in basis points of the group's contribution amount
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `Unauthorized` - If the caller is not the group creator
+    /// * `GroupComplete` - If the group has completed all cycles
+    /// * `GroupCancelled` - If the group has been cancelled
+    /// * `InvalidInput` - If `fee_bps` exceeds `MAX_KEEPER_FEE_BPS`
+    pub fn set_keeper_fee(
+        env: Env,
+        creator: Address,
+        group_id: u64,
+        fee_bps: u32,
+    ) -> Result<(), AjoError> {
+        pausable::ensure_not_paused(&env)?;
+        creator.require_auth();
+
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        if group.creator != creator {
+            return Err(AjoError::Unauthorized);
+        }
+        if group.is_complete {
+            return Err(AjoError::GroupComplete);
+        }
+        if group.state == crate::types::GroupState::Cancelled {
+            return Err(AjoError::GroupCancelled);
+        }
+        if fee_bps > crate::types::MAX_KEEPER_FEE_BPS {
+            return Err(AjoError::InvalidInput);
+        }
+
+        storage::store_keeper_fee(&env, group_id, fee_bps);
+        events::emit_keeper_fee_set(&env, group_id, fee_bps);
+
+        Ok(())
+    }
+
+    /// Add funds to a group's keeper reserve, from which crank bounties are paid.
+    ///
+    /// Anyone can fund a group's reserve, in the group's token.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `funder` - The account paying in
+    /// * `group_id` - The group to fund
+    /// * `amount` - Amount to add
+    ///
+    /// # Returns
+    /// The reserve after funding
+    ///
+    /// # Errors
+    /// * `ContributionAmountZero` - If `amount` is not positive
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `GroupComplete` - If the group has completed all cycles
+    /// * `GroupCancelled` - If the group has been cancelled
+    /// * `InsufficientBalance` - If the funder cannot cover `amount`
+    pub fn fund_keeper_reserve(
+        env: Env,
+        funder: Address,
+        group_id: u64,
+        amount: i128,
+    ) -> Result<i128, AjoError> {
+        pausable::ensure_not_paused(&env)?;
+        funder.require_auth();
+
+        if amount <= 0 {
+            return Err(AjoError::ContributionAmountZero);
+        }
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        if group.is_complete {
+            return Err(AjoError::GroupComplete);
+        }
+        if group.state == crate::types::GroupState::Cancelled {
+            return Err(AjoError::GroupCancelled);
+        }
+
+        crate::token::check_balance(&env, &group.token_address, &funder, amount)?;
+        crate::token::transfer_token(
+            &env,
+            &group.token_address,
+            &funder,
+            &env.current_contract_address(),
+            amount,
+        )?;
+
+        let reserve = storage::get_keeper_reserve(&env, group_id) + amount;
+        storage::store_keeper_reserve(&env, group_id, reserve);
+        crate::insurance::adjust_keeper_liability(&env, &group.token_address, amount);
+        events::emit_keeper_reserve_changed(&env, group_id, &funder, amount, reserve);
+
+        Ok(reserve)
+    }
+
+    /// Return what is left of a finished group's keeper reserve to its creator.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `creator` - The group creator
+    /// * `group_id` - The group whose reserve to withdraw
+    ///
+    /// # Returns
+    /// The amount withdrawn
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `Unauthorized` - If the caller is not the group creator
+    /// * `InvalidState` - If the group is neither complete nor cancelled
+    pub fn withdraw_keeper_reserve(
+        env: Env,
+        creator: Address,
+        group_id: u64,
+    ) -> Result<i128, AjoError> {
+        pausable::ensure_not_paused(&env)?;
+        creator.require_auth();
+
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        if group.creator != creator {
+            return Err(AjoError::Unauthorized);
+        }
+        if !group.is_complete && group.state != crate::types::GroupState::Cancelled {
+            return Err(AjoError::InvalidState);
+        }
+
+        let reserve = storage::get_keeper_reserve(&env, group_id);
+        if reserve > 0 {
+            crate::token::transfer_token(
+                &env,
+                &group.token_address,
+                &env.current_contract_address(),
+                &creator,
+                reserve,
+            )?;
+            storage::store_keeper_reserve(&env, group_id, 0);
+            crate::insurance::adjust_keeper_liability(&env, &group.token_address, -reserve);
+            events::emit_keeper_reserve_changed(&env, group_id, &creator, -reserve, 0);
+        }
+
+        Ok(reserve)
+    }
+
+    /// Returns the bounty the next crank of a group would pay.
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    pub fn get_keeper_bounty(env: Env, group_id: u64) -> Result<i128, AjoError> {
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        Ok(utils::keeper_bounty(&env, &group).min(storage::get_keeper_reserve(&env, group_id)))
+    }
+
+    /// Returns a group's keeper reserve.
+    pub fn get_keeper_reserve(env: Env, group_id: u64) -> i128 {
+        storage::get_keeper_reserve(&env, group_id)
+    }
+
     // ── Group templates ───────────────────────────────────────────────────
 
     /// Create a group using a predefined template.
@@ -1,6 +1,11 @@
 use soroban_sdk::contracterror;
 
 /// Error codes for the Ajo contract.
+///
+/// A contract spec error type holds at most 50 cases, so new failures should
+/// reuse the closest existing variant (often [`InvalidInput`](Self::InvalidInput)
+/// or [`InvalidState`](Self::InvalidState)) rather than add one. Codes are
+/// stable once released.
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
@@ -11,7 +16,8 @@ pub enum AjoError {
     /// Can't join because the group is already at its member limit.
     MaxMembersExceeded = 2,
 
-    /// This account is already part of the group.
+    /// This account is already part of the group, or has already used its
+    /// invitation.
     AlreadyMember = 3,
 
     /// Address isn't a member of the group.
@@ -53,7 +59,8 @@ pub enum AjoError {
     /// Only the creator or authorized members can do this.
     Unauthorized = 15,
 
-    /// Contribution outside active cycle window
+    /// Contribution, bid, commitment or reveal outside the part of the cycle
+    /// that accepts it.
     OutsideCycleWindow = 16,
 
     /// Negative amounts aren't allowed for contributions.
@@ -67,12 +74,6 @@ pub enum AjoError {
     
     /// The contract is currently paused and cannot execute this operation.
     ContractPaused = 21,
-    
-    /// Only the admin can pause the contract.
-    UnauthorizedPause = 22,
-    
-    /// Only the admin can unpause the contract.
-    UnauthorizedUnpause = 23,
 
     /// Contribution is too late - grace period has expired.
     GracePeriodExpired = 24,
@@ -86,19 +87,17 @@ pub enum AjoError {
     /// Metadata field exceeds maximum length.
     MetadataTooLong = 27,
 
-    /// Cannot cancel group after first payout.
+    /// Cannot cancel the group, or leave it, after the first payout.
     CannotCancelAfterPayout = 28,
 
-    /// Only the group creator can cancel the group.
-    OnlyCreatorCanCancel = 29,
-
     /// Refund request already exists for this group.
     RefundRequestExists = 30,
 
     /// No active refund request for this group.
     NoRefundRequest = 31,
 
-    /// Member has already voted on this refund request.
+    /// Member has already voted on this refund request or dispute, or has
+    /// already endorsed this need declaration.
     AlreadyVoted = 32,
 
     /// Voting period has not ended yet.
@@ -116,9 +115,6 @@ pub enum AjoError {
     /// Cannot request refund before cycle deadline.
     CycleNotExpired = 37,
 
-    /// Token contract address is invalid or not found.
-    InvalidTokenAddress = 38,
-
     /// Contract has insufficient token balance for payout.
     InsufficientContractBalance = 39,
 
@@ -128,7 +124,8 @@ pub enum AjoError {
     /// Insurance claim not found or invalid.
     InvalidClaim = 41,
 
-    /// Claim has already been processed (approved or rejected).
+    /// Claim has already been processed (approved or rejected), or a claim
+    /// already exists for the default.
     ClaimAlreadyProcessed = 42,
 
     /// Insurance pool has insufficient balance for payout.
@@ -137,12 +134,10 @@ pub enum AjoError {
     /// Insurance pool for token not found.
     PoolNotFound = 44,
 
-    /// Invalid or unsupported payout ordering strategy.
+    /// Invalid or unsupported payout ordering strategy, or the action is not
+    /// available under the group's strategy.
     InvalidStrategy = 45,
 
-    /// Voting is not open for this group's payout strategy.
-    VotingNotOpen = 46,
-
     /// No eligible members remain for payout selection (all have been paid).
     NoEligibleMembers = 47,
 
@@ -152,7 +147,7 @@ pub enum AjoError {
     TokenNotAccepted = 48,
 
     /// Invalid multi-token configuration (empty list, duplicates, zero weight,
-    /// or too many tokens).
+    /// or too many tokens), or a feature multi-token groups do not support.
     InvalidMultiTokenConfig = 49,
 
     /// Group does not have multi-token configuration.
@@ -169,13 +164,14 @@ pub enum AjoError {
     /// The dispute has already been resolved.
     DisputeAlreadyResolved = 53,
 
-    /// This address has already voted on this dispute.
-    AlreadyVotedOnDispute = 54,
+    // ── General errors ────────────────────────────────────────────────────
 
-    /// The voting period for this dispute has ended.
-    VotingPeriodEndedDispute = 55,
+    /// An argument is out of range, or names a record (invitation, join
+    /// request, swap, need declaration, commitment) that does not exist.
+    InvalidInput = 57,
 
-    /// The caller is not a member of the dispute's group.
-    NotDisputeMember = 56,
+    /// The group, or the request or swap being acted on, is not in a state
+    /// that allows this action: not started, already started, still active,
+    /// locked while funds or a schedule are in place, or already done.
+    InvalidState = 58,
 }
-
@@ -19,6 +19,18 @@ pub fn emit_member_joined(env: &Env, group_id: u64, member: &Address) {
     env.events().publish(topics, member);
 }
 
+/// Emit an event when a member leaves a group
+pub fn emit_member_left(env: &Env, group_id: u64, member: &Address, refunded: i128) {
+    let topics = (symbol_short!("left"), group_id);
+    env.events().publish(topics, (member, refunded));
+}
+
+/// Emit an event when a member hands their slot to a replacement
+pub fn emit_membership_transferred(env: &Env, group_id: u64, from: &Address, to: &Address) {
+    let topics = (symbol_short!("mbr_xfer"), group_id);
+    env.events().publish(topics, (from, to));
+}
+
 /// Emit an event when a member contributes
 pub fn emit_contribution_made(
     env: &Env,
@@ -31,6 +43,19 @@ pub fn emit_contribution_made(
     env.events().publish(topics, (member, amount));
 }
 
+/// Emit an event when a member pays an installment towards a cycle
+pub fn emit_partial_contribution(
+    env: &Env,
+    group_id: u64,
+    member: &Address,
+    cycle: u32,
+    amount: i128,
+    total_paid: i128,
+) {
+    let topics = (symbol_short!("partial"), group_id, cycle);
+    env.events().publish(topics, (member, amount, total_paid));
+}
+
 /// Emit an event when a payout is executed
 pub fn emit_payout_executed(
     env: &Env,
@@ -81,6 +106,19 @@ pub fn emit_late_contribution(
     env.events().publish(topics, (member, amount, penalty));
 }
 
+/// Emit an event when a member is recorded as defaulted for a cycle
+pub fn emit_member_defaulted(
+    env: &Env,
+    group_id: u64,
+    member: &Address,
+    cycle: u32,
+    amount_owed: i128,
+    payout_forfeited: bool,
+) {
+    let topics = (symbol_short!("defaulted"), group_id, cycle);
+    env.events().publish(topics, (member, amount_owed, payout_forfeited));
+}
+
 /// Emit an event when penalties are distributed with payout
 pub fn emit_penalty_distributed(
     env: &Env,
@@ -158,6 +196,235 @@ pub fn emit_payout_order_determined(
     env.events().publish(topics, (recipient, strategy));
 }
 
+/// Emit an event when a group's payout rotation is fixed
+pub fn emit_schedule_locked(env: &Env, group_id: u64, rule: u32, seed: &soroban_sdk::BytesN<32>) {
+    let topics = (symbol_short!("schedule"), group_id);
+    env.events().publish(topics, (rule, seed.clone()));
+}
+
+/// Emit an event when the creator chooses how the payout rotation is fixed
+pub fn emit_schedule_rule_changed(env: &Env, group_id: u64, rule: u32) {
+    let topics = (symbol_short!("schedrule"), group_id);
+    env.events().publish(topics, rule);
+}
+
+/// Emit an event when a member changes how many shares they hold
+pub fn emit_member_shares_changed(env: &Env, group_id: u64, member: &Address, shares: u32) {
+    let topics = (symbol_short!("shares"), group_id);
+    env.events().publish(topics, (member, shares));
+}
+
+/// Emit an event when a member's own contribution tier is set
+pub fn emit_contribution_tier_set(env: &Env, group_id: u64, member: &Address, amount: i128) {
+    let topics = (symbol_short!("tier"), group_id);
+    env.events().publish(topics, (member, amount));
+}
+
+/// Emit an event when held-back pot is paid to a higher-tier recipient
+pub fn emit_tier_settled(env: &Env, group_id: u64, member: &Address, amount: i128, still_owed: i128) {
+    let topics = (symbol_short!("tierpaid"), group_id);
+    env.events().publish(topics, (member, amount, still_owed));
+}
+
+/// Emit an event when a member's tier balance is cleared as a group closes
+pub fn emit_tier_closed(env: &Env, group_id: u64, member: &Address, refunded: i128, written_off: i128) {
+    let topics = (symbol_short!("tierdone"), group_id);
+    env.events().publish(topics, (member, refunded, written_off));
+}
+
+/// Emit an event when a group enters its forming phase
+pub fn emit_formation_set(env: &Env, group_id: u64, min_members: u32, deadline: u64, allow_late_join: bool) {
+    let topics = (symbol_short!("forming"), group_id);
+    env.events().publish(topics, (min_members, deadline, allow_late_join));
+}
+
+/// Emit an event when a forming group starts its first cycle
+pub fn emit_group_started(env: &Env, group_id: u64, member_count: u32, started_at: u64) {
+    let topics = (symbol_short!("started"), group_id);
+    env.events().publish(topics, (member_count, started_at));
+}
+
+/// Emit an event when a group that never started expires
+pub fn emit_formation_expired(env: &Env, group_id: u64) {
+    let topics = (symbol_short!("expired"), group_id);
+    env.events().publish(topics, ());
+}
+
+/// Emit an event when the creator turns automatic rollover on or off
+pub fn emit_rollover_set(env: &Env, group_id: u64, enabled: bool) {
+    let topics = (symbol_short!("rollover"), group_id);
+    env.events().publish(topics, enabled);
+}
+
+/// Emit an event when a member opts in or out of the next round
+pub fn emit_rollover_opt_in(env: &Env, group_id: u64, member: &Address, opted_in: bool) {
+    let topics = (symbol_short!("rollin"), group_id);
+    env.events().publish(topics, (member, opted_in));
+}
+
+/// Emit an event when a completed group opens its next round
+pub fn emit_round_opened(env: &Env, group_id: u64, successor_id: u64, round: u32) {
+    let topics = (symbol_short!("newround"), group_id);
+    env.events().publish(topics, (successor_id, round));
+}
+
+/// Emit an event when a keeper bounty rate is set, for a group or by default (group 0)
+pub fn emit_keeper_fee_set(env: &Env, group_id: u64, fee_bps: u32) {
+    let topics = (symbol_short!("keepfee"), group_id);
+    env.events().publish(topics, fee_bps);
+}
+
+/// Emit an event when a group's keeper reserve is topped up or withdrawn
+pub fn emit_keeper_reserve_changed(env: &Env, group_id: u64, account: &Address, delta: i128, reserve: i128) {
+    let topics = (symbol_short!("keeprsv"), group_id);
+    env.events().publish(topics, (account, delta, reserve));
+}
+
+/// Emit an event when a keeper cranks a group
+pub fn emit_cranked(env: &Env, group_id: u64, keeper: &Address, bounty: i128) {
+    let topics = (symbol_short!("cranked"), group_id);
+    env.events().publish(topics, (keeper, bounty));
+}
+
+/// Emit an event when a member prepays contributions for future cycles
+pub fn emit_contributions_prepaid(env: &Env, group_id: u64, member: &Address, cycles: u32, amount: i128) {
+    let topics = (symbol_short!("prepaid"), group_id);
+    env.events().publish(topics, (member, cycles, amount));
+}
+
+/// Emit an event when a prepaid contribution is applied to a new cycle
+pub fn emit_prepayment_applied(env: &Env, group_id: u64, member: &Address, cycle: u32, amount: i128) {
+    let topics = (symbol_short!("prepaply"), group_id);
+    env.events().publish(topics, (member, cycle, amount));
+}
+
+/// Emit an event when unused prepaid contributions are refunded
+pub fn emit_prepayment_refunded(env: &Env, group_id: u64, member: &Address, amount: i128) {
+    let topics = (symbol_short!("prep_ref"), group_id);
+    env.events().publish(topics, (member, amount));
+}
+
+/// Emit an event when a member turns auto-debit on or off
+pub fn emit_auto_debit_set(env: &Env, group_id: u64, member: &Address, enabled: bool) {
+    let topics = (symbol_short!("autodebit"), group_id);
+    env.events().publish(topics, (member, enabled));
+}
+
+/// Emit an event when a member's auto-debit could not be collected
+pub fn emit_auto_debit_failed(env: &Env, group_id: u64, member: &Address, cycle: u32, error: u32) {
+    let topics = (symbol_short!("debitfail"), group_id);
+    env.events().publish(topics, (member, cycle, error));
+}
+
+/// Emit an event when the admin allowlists or removes a yield adapter
+pub fn emit_yield_adapter_allowed(env: &Env, adapter: &Address, token: &Address, allowed: bool) {
+    let topics = (symbol_short!("yldallow"), adapter.clone());
+    env.events().publish(topics, (token, allowed));
+}
+
+/// Emit an event when a group starts parking its pot with a yield adapter
+pub fn emit_yield_enabled(env: &Env, group_id: u64, adapter: &Address, distribution: u32) {
+    let topics = (symbol_short!("yield_on"), group_id);
+    env.events().publish(topics, (adapter, distribution));
+}
+
+/// Emit an event when a group stops parking its pot
+pub fn emit_yield_disabled(env: &Env, group_id: u64) {
+    let topics = (symbol_short!("yield_off"), group_id);
+    env.events().publish(topics, ());
+}
+
+/// Emit an event when contributions are parked with a yield adapter
+pub fn emit_funds_parked(env: &Env, group_id: u64, adapter: &Address, amount: i128) {
+    let topics = (symbol_short!("parked"), group_id);
+    env.events().publish(topics, (adapter, amount));
+}
+
+/// Emit an event when parked funds are withdrawn along with their yield
+pub fn emit_yield_withdrawn(env: &Env, group_id: u64, principal: i128, yield_earned: i128) {
+    let topics = (symbol_short!("yielded"), group_id);
+    env.events().publish(topics, (principal, yield_earned));
+}
+
+/// Emit an event when a member commits to a secret for the random draw
+pub fn emit_random_committed(env: &Env, group_id: u64, cycle: u32, member: &Address) {
+    let topics = (symbol_short!("rcommit"), group_id, cycle);
+    env.events().publish(topics, member);
+}
+
+/// Emit an event when a member reveals their secret for the random draw
+pub fn emit_random_revealed(env: &Env, group_id: u64, cycle: u32, member: &Address) {
+    let topics = (symbol_short!("rreveal"), group_id, cycle);
+    env.events().publish(topics, member);
+}
+
+/// Emit an event when a member declares a need for an early payout
+pub fn emit_need_declared(env: &Env, group_id: u64, member: &Address, category: u32, urgency: u32) {
+    let topics = (symbol_short!("need"), group_id);
+    env.events().publish(topics, (member, category, urgency));
+}
+
+/// Emit an event when a member endorses another member's need declaration
+pub fn emit_need_endorsed(env: &Env, group_id: u64, endorser: &Address, member: &Address, endorsements: u32) {
+    let topics = (symbol_short!("endorse"), group_id);
+    env.events().publish(topics, (endorser, member, endorsements));
+}
+
+/// Emit an event when a member bids a discount for the current pot
+pub fn emit_payout_bid(env: &Env, group_id: u64, cycle: u32, bidder: &Address, discount: i128) {
+    let topics = (symbol_short!("bid"), group_id, cycle);
+    env.events().publish(topics, (bidder, discount));
+}
+
+/// Emit an event when an auction winner's discount is shared among the other members
+pub fn emit_discount_shared(
+    env: &Env,
+    group_id: u64,
+    cycle: u32,
+    winner: &Address,
+    discount: i128,
+    share: i128,
+) {
+    let topics = (symbol_short!("discount"), group_id, cycle);
+    env.events().publish(topics, (winner, discount, share));
+}
+
+/// Emit an event when the creator changes how auction discounts are settled
+pub fn emit_discount_settlement_changed(env: &Env, group_id: u64, settlement: u32) {
+    let topics = (symbol_short!("discmode"), group_id);
+    env.events().publish(topics, settlement);
+}
+
+/// Emit an event when a member proposes swapping payout positions
+pub fn emit_swap_proposed(env: &Env, group_id: u64, swap_id: u64, proposer: &Address, counterparty: &Address) {
+    let topics = (symbol_short!("swapprop"), group_id);
+    env.events().publish(topics, (swap_id, proposer, counterparty));
+}
+
+/// Emit an event when both members agree to a swap that awaits the creator
+pub fn emit_swap_accepted(env: &Env, group_id: u64, swap_id: u64) {
+    let topics = (symbol_short!("swapacc"), group_id);
+    env.events().publish(topics, swap_id);
+}
+
+/// Emit an event when two members' payout positions are exchanged
+pub fn emit_positions_swapped(env: &Env, group_id: u64, swap_id: u64, proposer: &Address, counterparty: &Address) {
+    let topics = (symbol_short!("swapped"), group_id);
+    env.events().publish(topics, (swap_id, proposer, counterparty));
+}
+
+/// Emit an event when a position swap is withdrawn
+pub fn emit_swap_cancelled(env: &Env, group_id: u64, swap_id: u64, cancelled_by: &Address) {
+    let topics = (symbol_short!("swapcncl"), group_id);
+    env.events().publish(topics, (swap_id, cancelled_by));
+}
+
+/// Emit an event when the creator changes whether swaps need approval
+pub fn emit_swap_policy_changed(env: &Env, group_id: u64, requires_approval: bool) {
+    let topics = (symbol_short!("swappol"), group_id);
+    env.events().publish(topics, requires_approval);
+}
+
 /// Emit an event when a member updates their notification preferences
 pub fn emit_preferences_updated(env: &Env, member: &Address) {
     let topics = (symbol_short!("notpref"),);
@@ -264,3 +531,130 @@ pub fn emit_dispute_resolved(
     let topics = (symbol_short!("disres"), dispute_id);
     env.events().publish(topics, (group_id, resolution));
 }
+
+/// Emit an event when the insurance reserve covers a member's default at payout
+pub fn emit_default_covered(
+    env: &Env,
+    group_id: u64,
+    cycle: u32,
+    claim_id: u64,
+    defaulter: &Address,
+    amount: i128,
+) {
+    let topics = (symbol_short!("defcover"), group_id, cycle);
+    env.events().publish(topics, (claim_id, defaulter, amount));
+}
+
+/// Emit an event when an insurance claim is filed
+pub fn emit_claim_filed(env: &Env, claim_id: u64, group_id: u64, cycle: u32) {
+    let topics = (symbol_short!("clm_file"), group_id, cycle);
+    env.events().publish(topics, claim_id);
+}
+
+/// Emit an event when an insurance claim is approved and paid out
+pub fn emit_claim_approved(env: &Env, claim_id: u64, group_id: u64, claimant: &Address, amount: i128) {
+    let topics = (symbol_short!("clm_ok"), group_id);
+    env.events().publish(topics, (claim_id, claimant, amount));
+}
+
+/// Emit an event when an insurance claim is rejected
+pub fn emit_claim_rejected(env: &Env, claim_id: u64, group_id: u64) {
+    let topics = (symbol_short!("clm_rej"), group_id);
+    env.events().publish(topics, claim_id);
+}
+
+/// Emit an event with the outcome of an automatic claim verification
+pub fn emit_claim_verification_result(
+    env: &Env,
+    claim_id: u64,
+    group_id: u64,
+    verified: bool,
+    is_valid: bool,
+) {
+    let topics = (symbol_short!("clm_vrfy"), group_id);
+    env.events().publish(topics, (claim_id, verified, is_valid));
+}
+
+/// Emit an event when a member escrows collateral with a group
+pub fn emit_collateral_deposited(env: &Env, group_id: u64, member: &Address, amount: i128) {
+    let topics = (symbol_short!("col_dep"), group_id);
+    env.events().publish(topics, (member, amount));
+}
+
+/// Emit an event when a defaulter's collateral is slashed into the pot
+pub fn emit_collateral_slashed(env: &Env, group_id: u64, member: &Address, cycle: u32, amount: i128) {
+    let topics = (symbol_short!("col_slash"), group_id, cycle);
+    env.events().publish(topics, (member, amount));
+}
+
+/// Emit an event when escrowed collateral is returned to a member
+pub fn emit_collateral_returned(env: &Env, group_id: u64, member: &Address, amount: i128) {
+    let topics = (symbol_short!("col_ret"), group_id);
+    env.events().publish(topics, (member, amount));
+}
+
+/// Emit an event when a group's default policy is changed
+pub fn emit_default_policy_changed(env: &Env, group_id: u64, forfeit_payout_on_default: bool) {
+    let topics = (symbol_short!("defpolicy"), group_id);
+    env.events().publish(topics, forfeit_payout_on_default);
+}
+
+/// Emit an event when a group's insurance premium mode is changed
+pub fn emit_premium_mode_changed(env: &Env, group_id: u64, premium_mode: crate::types::PremiumMode) {
+    let topics = (symbol_short!("premmode"), group_id);
+    env.events().publish(topics, premium_mode as u32);
+}
+
+/// Emit an event when a group's insurance premium pricing is changed
+pub fn emit_premium_pricing_changed(
+    env: &Env,
+    group_id: u64,
+    pricing: crate::types::PremiumPricing,
+    floor_bps: u32,
+    cap_bps: u32,
+) {
+    let topics = (symbol_short!("premprice"), group_id);
+    env.events().publish(topics, (pricing as u32, floor_bps, cap_bps));
+}
+
+/// Emit an event when a group's access type is changed
+pub fn emit_access_type_changed(env: &Env, group_id: u64, access_type: crate::types::GroupAccessType) {
+    let topics = (symbol_short!("access"), group_id);
+    env.events().publish(topics, access_type);
+}
+
+/// Emit an event when an address is invited to a group
+pub fn emit_member_invited(env: &Env, group_id: u64, invitee: &Address, expires_at: u64) {
+    let topics = (symbol_short!("invited"), group_id);
+    env.events().publish(topics, (invitee, expires_at));
+}
+
+/// Emit an event when an invitation is revoked
+pub fn emit_invitation_revoked(env: &Env, group_id: u64, invitee: &Address) {
+    let topics = (symbol_short!("inv_rev"), group_id);
+    env.events().publish(topics, invitee);
+}
+
+/// Emit an event when an invitation is accepted
+pub fn emit_invitation_accepted(env: &Env, group_id: u64, invitee: &Address) {
+    let topics = (symbol_short!("inv_acc"), group_id);
+    env.events().publish(topics, invitee);
+}
+
+/// Emit an event when an address requests to join a group
+pub fn emit_join_requested(env: &Env, group_id: u64, requester: &Address) {
+    let topics = (symbol_short!("join_req"), group_id);
+    env.events().publish(topics, requester);
+}
+
+/// Emit an event when a join request is approved
+pub fn emit_join_approved(env: &Env, group_id: u64, requester: &Address) {
+    let topics = (symbol_short!("join_ok"), group_id);
+    env.events().publish(topics, requester);
+}
+
+/// Emit an event when a join request is rejected
+pub fn emit_join_rejected(env: &Env, group_id: u64, requester: &Address) {
+    let topics = (symbol_short!("join_rej"), group_id);
+    env.events().publish(topics, requester);
+}
@@ -1,6 +1,9 @@
 use soroban_sdk::{Address, Env};
 use crate::storage;
-use crate::types::{InsuranceClaim, ClaimStatus, InsurancePool, Group};
+use crate::types::{
+    ClaimStatus, Group, InsuranceClaim, InsurancePool, MemberPremium, PremiumMode, PremiumPricing,
+    RefundReason, RiskScoreBreakdown, TokenLiabilities,
+};
 use crate::errors::AjoError;
 use crate::utils;
 use crate::events;
@@ -10,18 +13,263 @@ pub fn calculate_premium(amount: i128, rate_bps: u32) -> i128 {
     (amount * (rate_bps as i128)) / 10000
 }
 
-/// Adds funds to the insurance pool for a token.
-pub fn deposit_to_pool(env: &Env, token: &Address, amount: i128) {
-    let mut pool = storage::get_insurance_pool(env, token).unwrap_or(InsurancePool {
+/// Scales a member's premium between 0.5x and 2x the base rate.
+///
+/// Starts at 0.5x plus 1x times the share of the member's contributions that
+/// were not on time (members with no history start at 1x), then adds 0.1x per
+/// late payment or default in this group, up to 0.5x. Expressed in basis
+/// points of the base rate.
+pub fn member_risk_factor_bps(env: &Env, group_id: u64, member: &Address) -> u32 {
+    let stats = storage::get_member_stats(env, member)
+        .unwrap_or_else(|| utils::default_member_stats(env, member));
+    let payments = stats.on_time_contributions + stats.late_contributions + stats.defaulted_contributions;
+    let mut factor = 5_000
+        + ((payments - stats.on_time_contributions) * 10_000)
+            .checked_div(payments)
+            .unwrap_or(5_000);
+
+    if let Some(record) = storage::get_member_penalty(env, group_id, member) {
+        factor += (utils::missed_count(&record) * 1_000).min(5_000);
+    }
+
+    factor
+}
+
+/// Premium rate for a member's contributions to a group, in basis points.
+///
+/// Flat pricing charges every member `rate_bps`. Risk-based pricing scales
+/// `rate_bps` by [`member_risk_factor_bps`] and bounds the result by the
+/// group's floor and cap.
+pub fn premium_rate_bps(env: &Env, group: &Group, member: &Address) -> u32 {
+    let config = &group.insurance_config;
+    match config.pricing {
+        PremiumPricing::Flat => config.rate_bps,
+        PremiumPricing::RiskBased => {
+            let factor = member_risk_factor_bps(env, group.id, member);
+            let rate = (config.rate_bps as u64 * factor as u64 / 10_000) as u32;
+            rate.clamp(config.floor_bps, config.cap_bps)
+        }
+    }
+}
+
+/// Premium a member pays on top of `amount` when contributing.
+///
+/// Returns `0` when insurance is disabled or premiums are deducted from the pot.
+pub fn upfront_premium(env: &Env, group: &Group, member: &Address, amount: i128) -> i128 {
+    if group.insurance_config.is_enabled
+        && group.insurance_config.premium_mode == PremiumMode::OnTop
+    {
+        calculate_premium(amount, premium_rate_bps(env, group, member))
+    } else {
+        0
+    }
+}
+
+/// Total premium `member` has paid into the reserve for the current cycle.
+pub fn member_cycle_premium(env: &Env, group: &Group, member: &Address) -> i128 {
+    storage::get_member_premiums(env, group.id, group.current_cycle)
+        .iter()
+        .filter(|premium| premium.member == *member)
+        .map(|premium| premium.amount)
+        .sum()
+}
+
+/// Moves a member's premium for a contribution of `amount` into the group's reserve.
+///
+/// With [`PremiumMode::OnTop`] the premium was transferred in addition to the
+/// contribution. With [`PremiumMode::DeductFromPot`] it is taken out of the
+/// cycle's pot and recorded so the payout is reduced accordingly. Must be
+/// called before the member's history is updated for this contribution so
+/// the rate matches [`upfront_premium`].
+///
+/// # Returns
+/// The amount deducted from the pot (`0` for `OnTop`)
+pub fn collect_premium(env: &Env, group: &Group, token: &Address, member: &Address, amount: i128) -> i128 {
+    if !group.insurance_config.is_enabled {
+        return 0;
+    }
+    let rate_bps = premium_rate_bps(env, group, member);
+    let premium = calculate_premium(amount, rate_bps);
+    if premium <= 0 {
+        return 0;
+    }
+
+    deposit_to_pool(env, group.id, token, premium);
+    storage::add_member_premium(
+        env,
+        group.id,
+        group.current_cycle,
+        &MemberPremium { member: member.clone(), rate_bps, amount: premium },
+    );
+    match group.insurance_config.premium_mode {
+        PremiumMode::OnTop => 0,
+        PremiumMode::DeductFromPot => {
+            adjust_pot_liability(env, token, -premium);
+            let deducted = storage::get_cycle_premiums(env, group.id, group.current_cycle);
+            storage::store_cycle_premiums(env, group.id, group.current_cycle, deducted + premium);
+            premium
+        }
+    }
+}
+
+/// Adds funds to the insurance pool for a group and token.
+pub fn deposit_to_pool(env: &Env, group_id: u64, token: &Address, amount: i128) {
+    let mut pool = storage::get_insurance_pool(env, group_id, token).unwrap_or_else(empty_pool);
+    pool.balance += amount;
+    pool.total_premiums += amount;
+    storage::store_insurance_pool(env, group_id, token, &pool);
+
+    let mut liabilities = storage::get_token_liabilities(env, token);
+    liabilities.insurance_reserves += amount;
+    storage::store_token_liabilities(env, token, &liabilities);
+}
+
+/// Takes funds out of the insurance pool for a group and token.
+///
+/// # Errors
+/// * `PoolNotFound` - If the group has no pool for the token
+/// * `InsufficientPoolBalance` - If the reserve cannot cover `amount`
+pub fn withdraw_from_pool(env: &Env, group_id: u64, token: &Address, amount: i128) -> Result<(), AjoError> {
+    let mut pool = storage::get_insurance_pool(env, group_id, token).ok_or(AjoError::PoolNotFound)?;
+    if pool.balance < amount {
+        return Err(AjoError::InsufficientPoolBalance);
+    }
+    pool.balance -= amount;
+    pool.total_payouts += amount;
+    storage::store_insurance_pool(env, group_id, token, &pool);
+
+    let mut liabilities = storage::get_token_liabilities(env, token);
+    liabilities.insurance_reserves -= amount;
+    storage::store_token_liabilities(env, token, &liabilities);
+    Ok(())
+}
+
+/// Returns premiums deducted from the current cycle's pot to the pot so that
+/// contributions can be refunded in full.
+///
+/// Only releases what the reserve still holds.
+pub fn release_cycle_premiums(env: &Env, group: &Group) {
+    let deducted = storage::get_cycle_premiums(env, group.id, group.current_cycle);
+    if deducted <= 0 {
+        return;
+    }
+    let Some(mut pool) = storage::get_insurance_pool(env, group.id, &group.token_address) else {
+        return;
+    };
+    let released = deducted.min(pool.balance);
+    pool.balance -= released;
+    pool.total_premiums -= released;
+    storage::store_insurance_pool(env, group.id, &group.token_address, &pool);
+    storage::store_cycle_premiums(env, group.id, group.current_cycle, deducted - released);
+
+    let mut liabilities = storage::get_token_liabilities(env, &group.token_address);
+    liabilities.insurance_reserves -= released;
+    liabilities.pots += released;
+    storage::store_token_liabilities(env, &group.token_address, &liabilities);
+}
+
+/// Moves up to `shortfall` from the group's insurance reserve into its pot to
+/// make up for funds lost at a yield adapter.
+///
+/// Only covers what the reserve still holds.
+///
+/// # Returns
+/// The amount covered
+pub fn cover_shortfall(env: &Env, group: &Group, shortfall: i128) -> i128 {
+    if shortfall <= 0 {
+        return 0;
+    }
+    let Some(mut pool) = storage::get_insurance_pool(env, group.id, &group.token_address) else {
+        return 0;
+    };
+    let covered = shortfall.min(pool.balance);
+    if covered <= 0 {
+        return 0;
+    }
+    pool.balance -= covered;
+    pool.total_payouts += covered;
+    storage::store_insurance_pool(env, group.id, &group.token_address, &pool);
+
+    let mut liabilities = storage::get_token_liabilities(env, &group.token_address);
+    liabilities.insurance_reserves -= covered;
+    liabilities.pots += covered;
+    storage::store_token_liabilities(env, &group.token_address, &liabilities);
+    covered
+}
+
+fn empty_pool() -> InsurancePool {
+    InsurancePool {
         balance: 0,
         total_payouts: 0,
         pending_claims_count: 0,
-    });
-    pool.balance += amount;
-    storage::store_insurance_pool(env, token, &pool);
+        total_premiums: 0,
+    }
+}
+
+// ── Solvency ──────────────────────────────────────────────────────────────
+
+/// Adjusts the amount held in pots for `token` by `delta`.
+///
+/// Call with a positive delta when contributions or penalties come in and a
+/// negative delta when payouts or refunds go out.
+pub fn adjust_pot_liability(env: &Env, token: &Address, delta: i128) {
+    let mut liabilities = storage::get_token_liabilities(env, token);
+    liabilities.pots += delta;
+    storage::store_token_liabilities(env, token, &liabilities);
+}
+
+/// Adjusts the amount of pot funds lent out to yield adapters for `token`.
+///
+/// Parked funds are still owed to their pots but are not held by the
+/// contract, so they are left out of the solvency check.
+pub fn adjust_parked_liability(env: &Env, token: &Address, delta: i128) {
+    let mut liabilities = storage::get_token_liabilities(env, token);
+    liabilities.parked += delta;
+    storage::store_token_liabilities(env, token, &liabilities);
+}
+
+/// Adjusts the amount set aside to pay keepers for `token` by `delta`.
+pub fn adjust_keeper_liability(env: &Env, token: &Address, delta: i128) {
+    let mut liabilities = storage::get_token_liabilities(env, token);
+    liabilities.keeper_reserves += delta;
+    storage::store_token_liabilities(env, token, &liabilities);
+}
+
+/// Returns what the contract owes for `token` across every group.
+pub fn get_liabilities(env: &Env, token: &Address) -> TokenLiabilities {
+    storage::get_token_liabilities(env, token)
+}
+
+/// Ensures the contract's balance of `token` covers every pot not parked
+/// with a yield adapter, every insurance reserve, all escrowed collateral,
+/// every keeper reserve and all prepaid contributions.
+///
+/// # Errors
+/// * `InsufficientContractBalance` - If the contract holds less than it owes
+pub fn ensure_solvent(env: &Env, token: &Address) -> Result<(), AjoError> {
+    let liabilities = storage::get_token_liabilities(env, token);
+    crate::token::check_contract_balance(
+        env,
+        token,
+        &env.current_contract_address(),
+        liabilities.pots + liabilities.insurance_reserves + liabilities.collateral
+            + liabilities.keeper_reserves
+            + liabilities.prepaid
+            - liabilities.parked,
+    )
 }
 
 /// Records a claim against the insurance pool.
+///
+/// The claim must be for a default recorded by `mark_defaults`, filed by a
+/// member of the group, and for no more than the defaulter still owed after
+/// their collateral was applied.
+///
+/// # Errors
+/// * `GroupNotFound` - If the group does not exist
+/// * `NotMember` - If the claimant is not a member of the group
+/// * `ClaimAlreadyProcessed` - If a claim already exists for this default
+/// * `InvalidClaim` - If no default is recorded or `amount` is out of range
 pub fn file_claim(
     env: &Env,
     group_id: u64,
@@ -30,6 +278,19 @@ pub fn file_claim(
     defaulter: Address,
     amount: i128,
 ) -> Result<u64, AjoError> {
+    let group = storage::get_group(env, group_id).ok_or(AjoError::GroupNotFound)?;
+    if !utils::is_member(&group.members, &claimant) {
+        return Err(AjoError::NotMember);
+    }
+    if storage::get_default_claim(env, group_id, cycle, &defaulter).is_some() {
+        return Err(AjoError::ClaimAlreadyProcessed);
+    }
+    let record = storage::get_default_record(env, group_id, cycle, &defaulter)
+        .ok_or(AjoError::InvalidClaim)?;
+    if amount <= 0 || amount > record.amount_owed - record.collateral_applied {
+        return Err(AjoError::InvalidClaim);
+    }
+
     let claim_id = storage::get_next_claim_id(env);
     let now = env.ledger().timestamp();
 
@@ -45,16 +306,16 @@ pub fn file_claim(
     };
 
     storage::store_insurance_claim(env, claim_id, &claim);
+    storage::store_default_claim(env, group_id, cycle, &claim.defaulter, claim_id);
+    let mut pending = storage::get_pending_claims(env, group_id);
+    pending.push_back(claim_id);
+    storage::store_pending_claims(env, group_id, &pending);
 
     // Update pool stats
-    let group = storage::get_group(env, group_id).ok_or(AjoError::GroupNotFound)?;
-    let mut pool = storage::get_insurance_pool(env, &group.token_address).unwrap_or(InsurancePool {
-        balance: 0,
-        total_payouts: 0,
-        pending_claims_count: 0,
-    });
+    let mut pool = storage::get_insurance_pool(env, group_id, &group.token_address)
+        .unwrap_or_else(empty_pool);
     pool.pending_claims_count += 1;
-    storage::store_insurance_pool(env, &group.token_address, &pool);
+    storage::store_insurance_pool(env, group_id, &group.token_address, &pool);
 
     // Emit event: claim filed
     events::emit_claim_filed(env, claim_id, group_id, cycle);
@@ -62,6 +323,94 @@ pub fn file_claim(
     Ok(claim_id)
 }
 
+/// Covers the current cycle's defaults from the group's insurance reserve.
+///
+/// For each member marked as defaulted this cycle, the amount they still owed
+/// is moved from the reserve into the pot and a claim in `Paid` state is
+/// recorded with `recipient` as the claimant. Coverage is capped by what the
+/// reserve holds. Defaults that already have a paid claim are skipped; a
+/// claim still pending for a covered default is rejected in favour of the
+/// automatic one.
+///
+/// # Returns
+/// The total amount added to the pot
+pub fn cover_cycle_defaults(env: &Env, group: &Group, recipient: &Address) -> i128 {
+    if !group.insurance_config.is_enabled {
+        return 0;
+    }
+    let cycle = group.current_cycle;
+    let defaulters = storage::get_cycle_defaults(env, group.id, cycle);
+    if defaulters.is_empty() {
+        return 0;
+    }
+    let Some(mut pool) = storage::get_insurance_pool(env, group.id, &group.token_address) else {
+        return 0;
+    };
+
+    let now = utils::get_current_timestamp(env);
+    let mut covered = 0i128;
+
+    for defaulter in defaulters.iter() {
+        let existing = storage::get_default_claim(env, group.id, cycle, &defaulter)
+            .and_then(|claim_id| storage::get_insurance_claim(env, claim_id));
+        if existing.as_ref().is_some_and(|claim| claim.status == ClaimStatus::Paid) {
+            continue;
+        }
+        let Some(record) = storage::get_default_record(env, group.id, cycle, &defaulter) else {
+            continue;
+        };
+        let amount = (record.amount_owed - record.collateral_applied).min(pool.balance);
+        if amount <= 0 {
+            continue;
+        }
+
+        let pending_claim = existing.filter(|claim| claim.status == ClaimStatus::Pending);
+        if let Some(mut superseded) = pending_claim {
+            superseded.status = ClaimStatus::Rejected;
+            storage::store_insurance_claim(env, superseded.id, &superseded);
+            pool.pending_claims_count -= 1;
+            let mut pending = storage::get_pending_claims(env, group.id);
+            if let Some(index) = pending.first_index_of(superseded.id) {
+                pending.remove(index);
+                storage::store_pending_claims(env, group.id, &pending);
+            }
+            events::emit_claim_rejected(env, superseded.id, group.id);
+        }
+
+        pool.balance -= amount;
+        pool.total_payouts += amount;
+        covered += amount;
+
+        let claim_id = storage::get_next_claim_id(env);
+        let claim = InsuranceClaim {
+            id: claim_id,
+            group_id: group.id,
+            cycle,
+            defaulter: defaulter.clone(),
+            claimant: recipient.clone(),
+            amount,
+            status: ClaimStatus::Paid,
+            created_at: now,
+        };
+        storage::store_insurance_claim(env, claim_id, &claim);
+        storage::store_default_claim(env, group.id, cycle, &defaulter, claim_id);
+
+        events::emit_default_covered(env, group.id, cycle, claim_id, &defaulter, amount);
+    }
+
+    if covered > 0 {
+        storage::store_insurance_pool(env, group.id, &group.token_address, &pool);
+
+        // The covered amount moves from the reserve into the pot
+        let mut liabilities = storage::get_token_liabilities(env, &group.token_address);
+        liabilities.insurance_reserves -= covered;
+        liabilities.pots += covered;
+        storage::store_token_liabilities(env, &group.token_address, &liabilities);
+    }
+
+    covered
+}
+
 /// Processes a claim and executes payout if approved.
 pub fn process_claim(env: &Env, claim_id: u64, approved: bool) -> Result<(), AjoError> {
     let mut claim = storage::get_insurance_claim(env, claim_id).ok_or(AjoError::InvalidClaim)?;
@@ -71,16 +420,12 @@ pub fn process_claim(env: &Env, claim_id: u64, approved: bool) -> Result<(), Ajo
     }
 
     let group = storage::get_group(env, claim.group_id).ok_or(AjoError::GroupNotFound)?;
-    let mut pool = storage::get_insurance_pool(env, &group.token_address).ok_or(AjoError::PoolNotFound)?;
+    storage::get_insurance_pool(env, claim.group_id, &group.token_address)
+        .ok_or(AjoError::PoolNotFound)?;
 
     if approved {
-        if pool.balance < claim.amount {
-            return Err(AjoError::InsufficientPoolBalance);
-        }
-
-        // Payout from insurance pool
-        pool.balance -= claim.amount;
-        pool.total_payouts += claim.amount;
+        // Payout from the group's insurance reserve
+        withdraw_from_pool(env, claim.group_id, &group.token_address, claim.amount)?;
         claim.status = ClaimStatus::Paid;
 
         // Transfer tokens from contract to claimant
@@ -92,6 +437,8 @@ pub fn process_claim(env: &Env, claim_id: u64, approved: bool) -> Result<(), Ajo
             claim.amount,
         )?;
 
+        ensure_solvent(env, &group.token_address)?;
+
         // Emit approval event
         events::emit_claim_approved(env, claim_id, claim.group_id, &claim.claimant, claim.amount);
     } else {
@@ -101,8 +448,10 @@ pub fn process_claim(env: &Env, claim_id: u64, approved: bool) -> Result<(), Ajo
         events::emit_claim_rejected(env, claim_id, claim.group_id);
     }
 
+    let mut pool = storage::get_insurance_pool(env, claim.group_id, &group.token_address)
+        .ok_or(AjoError::PoolNotFound)?;
     pool.pending_claims_count -= 1;
-    storage::store_insurance_pool(env, &group.token_address, &pool);
+    storage::store_insurance_pool(env, claim.group_id, &group.token_address, &pool);
     storage::store_insurance_claim(env, claim_id, &claim);
 
     Ok(())
@@ -217,31 +566,153 @@ pub fn auto_process_claim(env: &Env, claim_id: u64) -> Result<(), AjoError> {
     process_claim(env, claim_id, is_valid)
 }
 
-/// Returns the current balance and statistics of the insurance pool for a token.
+/// Returns the current balance and statistics of a group's insurance pool for a token.
 ///
 /// # Arguments
-/// * `env`   - The Soroban contract environment
-/// * `token` - Token contract address for the pool to query
+/// * `env`      - The Soroban contract environment
+/// * `group_id` - The group that owns the pool
+/// * `token`    - Token contract address for the pool to query
 ///
 /// # Returns
 /// `Ok(InsurancePool)` with balance and payout stats, or `AjoError::PoolNotFound`.
-pub fn get_pool_info(env: &Env, token: &Address) -> Result<InsurancePool, AjoError> {
-    storage::get_insurance_pool(env, token).ok_or(AjoError::PoolNotFound)
+pub fn get_pool_info(env: &Env, group_id: u64, token: &Address) -> Result<InsurancePool, AjoError> {
+    storage::get_insurance_pool(env, group_id, token).ok_or(AjoError::PoolNotFound)
+}
+
+// ── Risk scoring ──────────────────────────────────────────────────────────
+
+const RISK_BASE_POINTS: u32 = 20;
+const RISK_MAX_ON_TIME_POINTS: u32 = 60;
+const RISK_POINTS_PER_COMPLETION: u32 = 5;
+const RISK_MAX_COMPLETION_POINTS: u32 = 20;
+const RISK_POINTS_PER_LATE: u32 = 3;
+const RISK_MAX_LATE_DEDUCTION: u32 = 15;
+const RISK_POINTS_PER_DEFAULT: u32 = 10;
+const RISK_MAX_DEFAULT_DEDUCTION: u32 = 40;
+const RISK_POINTS_PER_DISPUTE: u32 = 10;
+const RISK_MAX_DISPUTE_DEDUCTION: u32 = 30;
+const RISK_RECENT_WINDOW: u64 = 30 * 86_400;
+const RISK_RECENT_DEDUCTION: u32 = 15;
+const RISK_STALE_WINDOW: u64 = 90 * 86_400;
+const RISK_STALE_DEDUCTION: u32 = 5;
+const RISK_MAX_PENALTY_DEDUCTION: u32 = 10;
+const RISK_POINTS_PER_REFUND: u32 = 5;
+const RISK_MAX_REFUND_DEDUCTION: u32 = 15;
+
+/// Scores a member's payment history from their [`MemberStats`](crate::types::MemberStats),
+/// plus the penalty and refund records of the groups they belong to.
+///
+/// See [`RiskScoreBreakdown`] for the formula. Members with no history score 50.
+pub fn member_risk_breakdown(env: &Env, member: &Address) -> RiskScoreBreakdown {
+    let stats = storage::get_member_stats(env, member)
+        .unwrap_or_else(|| utils::default_member_stats(env, member));
+
+    let mut lowest_reliability = 100u32;
+    let mut refunds = 0u32;
+    for group_id in storage::get_member_groups(env, member).iter() {
+        if let Some(record) = storage::get_member_penalty(env, group_id, member) {
+            lowest_reliability = lowest_reliability.min(record.reliability_score);
+        }
+        if let Some(refund) = storage::get_refund_record(env, group_id, member) {
+            // Only groups that had to be unwound count against the member
+            if matches!(refund.reason, RefundReason::MemberVote | RefundReason::EmergencyRefund) {
+                refunds += 1;
+            }
+        }
+    }
+
+    let payments = stats.on_time_contributions + stats.late_contributions + stats.defaulted_contributions;
+    let on_time_ratio_bps =
+        (stats.on_time_contributions * 10_000).checked_div(payments).unwrap_or(0);
+    let on_time_points = (stats.on_time_contributions * RISK_MAX_ON_TIME_POINTS)
+        .checked_div(payments)
+        .unwrap_or(RISK_MAX_ON_TIME_POINTS / 2);
+
+    let completion_points = (stats.total_groups_completed * RISK_POINTS_PER_COMPLETION)
+        .min(RISK_MAX_COMPLETION_POINTS);
+    let late_deduction = (stats.late_contributions * RISK_POINTS_PER_LATE).min(RISK_MAX_LATE_DEDUCTION);
+    let default_deduction =
+        (stats.defaulted_contributions * RISK_POINTS_PER_DEFAULT).min(RISK_MAX_DEFAULT_DEDUCTION);
+    let dispute_deduction = (stats.disputes_lost * RISK_POINTS_PER_DISPUTE).min(RISK_MAX_DISPUTE_DEDUCTION);
+    let penalty_deduction = (100 - lowest_reliability) * RISK_MAX_PENALTY_DEDUCTION / 100;
+    let refund_deduction = (refunds * RISK_POINTS_PER_REFUND).min(RISK_MAX_REFUND_DEDUCTION);
+
+    let recency_deduction = if stats.last_incident_at == 0 {
+        0
+    } else {
+        let elapsed = utils::get_current_timestamp(env).saturating_sub(stats.last_incident_at);
+        if elapsed <= RISK_RECENT_WINDOW {
+            RISK_RECENT_DEDUCTION
+        } else if elapsed <= RISK_STALE_WINDOW {
+            RISK_STALE_DEDUCTION
+        } else {
+            0
+        }
+    };
+
+    let earned = RISK_BASE_POINTS + on_time_points + completion_points;
+    let deducted = late_deduction
+        + default_deduction
+        + dispute_deduction
+        + penalty_deduction
+        + refund_deduction
+        + recency_deduction;
+    let score = earned.saturating_sub(deducted).min(100);
+
+    RiskScoreBreakdown {
+        member: member.clone(),
+        on_time_ratio_bps,
+        base: RISK_BASE_POINTS,
+        on_time_points,
+        completion_points,
+        late_deduction,
+        default_deduction,
+        dispute_deduction,
+        penalty_deduction,
+        refund_deduction,
+        recency_deduction,
+        score,
+    }
 }
 
-/// Calculates risk score for a member based on history.
-pub fn get_member_risk_score(_env: &Env, _member: &Address) -> u32 {
-    // Simplified: starts at 100 (best) and would be adjusted
-    // by late/missed payment records in a full implementation.
-    100
+/// Calculates risk score for a member based on history (0 riskiest, 100 safest).
+pub fn get_member_risk_score(env: &Env, member: &Address) -> u32 {
+    member_risk_breakdown(env, member).score
 }
 
-/// Calculates group risk rating.
-pub fn get_group_risk_rating(_env: &Env, group: &Group) -> u32 {
+/// Calculates group risk rating (0 riskiest, 100 safest).
+///
+/// Starts from the members' scores, weighting the riskiest member as much as
+/// the average of the rest: `(3 * average + lowest) / 4`. The group's
+/// parameters then adjust the rating:
+/// * −5 for more than 10 members, since one default is harder to spot
+/// * −5 for a grace period longer than 3 days
+/// * −5 when there is no late penalty to deter late payment
+pub fn get_group_risk_rating(env: &Env, group: &Group) -> u32 {
     let total_members = group.members.len();
     if total_members == 0 {
         return 0;
     }
-    // Simplified default medium-risk score.
-    75
+
+    let mut total = 0u32;
+    let mut lowest = 100u32;
+    for member in group.members.iter() {
+        let score = get_member_risk_score(env, &member);
+        total += score;
+        lowest = lowest.min(score);
+    }
+    let average = total / total_members;
+    let mut rating = (average * 3 + lowest) / 4;
+
+    if total_members > 10 {
+        rating = rating.saturating_sub(5);
+    }
+    if group.grace_period > 3 * 86_400 {
+        rating = rating.saturating_sub(5);
+    }
+    if group.penalty_rate == 0 {
+        rating = rating.saturating_sub(5);
+    }
+
+    rating
 }
\ No newline at end of file
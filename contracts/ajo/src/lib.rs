@@ -22,14 +22,31 @@ mod token;
 mod types;
 mod utils;
 mod insurance;
+mod yield_adapter;
 
 pub use contract::AjoContract;
 pub use contract::AjoContractClient;
 pub use errors::AjoError;
+pub use yield_adapter::{YieldAdapter, YieldAdapterClient};
 pub use types::{GroupState, RefundReason, RefundRequest, RefundRecord, RefundVote};
+pub use types::AutoDebitReport;
+pub use types::CrankReport;
+pub use types::Prepayment;
+pub use types::{GroupFilter, GroupSummary};
+pub use types::PortfolioEntry;
 pub use types::{PayoutOrderingStrategy, PayoutVote, PayoutOrder};
 pub use types::{ReminderType, MemberNotificationPreferences, ReminderRecord};
 pub use types::{GroupMilestone, MemberAchievement, MilestoneRecord, AchievementRecord, MemberStats};
 pub use types::{TokenConfig, MultiTokenConfig, TokenContribution};
 pub use types::{Dispute, DisputeType, DisputeStatus, DisputeResolution, DisputeVote};
 pub use types::{GroupTemplate, TemplateConfig};
+pub use types::{GroupAccessType, GroupInvitation, JoinRequest};
+pub use types::{ClaimStatus, InsuranceClaim, InsuranceConfig, InsurancePool, PremiumMode, TokenLiabilities};
+pub use types::{MemberPremium, PremiumPricing, RiskScoreBreakdown};
+pub use types::{CollateralBalance, DefaultRecord};
+pub use types::{PositionSwap, SwapStatus};
+pub use types::{DiscountSettlement, PayoutBid};
+pub use types::{NeedCategory, NeedDeclaration};
+pub use types::RandomCommitment;
+pub use types::{PayoutSchedule, ScheduleRule, ScheduledPayout};
+pub use types::{YieldConfig, YieldDistribution, YieldPosition};
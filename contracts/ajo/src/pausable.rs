@@ -86,7 +86,7 @@ pub fn ensure_not_paused(env: &Env) -> Result<(), AjoError> {
 ///
 /// # Returns
 /// * `Ok(())` if the pause was successful
-/// * `Err(AjoError::UnauthorizedPause)` if the caller is not the admin
+/// * `Err(AjoError::Unauthorized)` if the caller is not the admin
 ///
 /// # Authorization
 /// This function requires admin authentication via `require_auth()`. The admin
@@ -96,7 +96,7 @@ pub fn ensure_not_paused(env: &Env) -> Result<(), AjoError> {
 /// Calling pause when already paused is safe and will succeed without error.
 pub fn pause(env: &Env) -> Result<(), AjoError> {
     // Get admin and verify authorization
-    let admin = storage::get_admin(env).ok_or(AjoError::UnauthorizedPause)?;
+    let admin = storage::get_admin(env).ok_or(AjoError::Unauthorized)?;
     admin.require_auth();
     
     // Set paused state
@@ -122,7 +122,7 @@ pub fn pause(env: &Env) -> Result<(), AjoError> {
 ///
 /// # Returns
 /// * `Ok(())` if the unpause was successful
-/// * `Err(AjoError::UnauthorizedUnpause)` if the caller is not the admin
+/// * `Err(AjoError::Unauthorized)` if the caller is not the admin
 ///
 /// # Authorization
 /// This function requires admin authentication via `require_auth()`. The admin
@@ -136,7 +136,7 @@ pub fn pause(env: &Env) -> Result<(), AjoError> {
 /// payouts remain exactly as they were before the pause.
 pub fn unpause(env: &Env) -> Result<(), AjoError> {
     // Get admin and verify authorization
-    let admin = storage::get_admin(env).ok_or(AjoError::UnauthorizedUnpause)?;
+    let admin = storage::get_admin(env).ok_or(AjoError::Unauthorized)?;
     admin.require_auth();
     
     // Clear paused state
@@ -45,9 +45,9 @@ pub enum StorageKey {
     /// Stored in persistent storage under `("PENPOOL", group_id, cycle)`.
     CyclePenaltyPool(u64, u32),
 
-    /// Insurance pool for a specific token.
-    /// Stored in instance storage under `("INSPOOL", token_address)`.
-    InsurancePool(Address),
+    /// Insurance pool for a group and token.
+    /// Stored in persistent storage under `("INSPOOL", group_id, token_address)`.
+    InsurancePool(u64, Address),
 
     /// Insurance claim keyed by ID.
     /// Stored in persistent storage under `("INSCLAIM", claim_id)`.
@@ -68,6 +68,159 @@ pub enum StorageKey {
     /// Aggregated member statistics.
     /// Stored in persistent storage under `("MSTATS", member)`.
     MemberStatsData(Address),
+
+    /// Running total of a member's installments for a cycle.
+    /// Stored in persistent storage under `("PARTIAL", group_id, cycle, member)`.
+    PartialContribution(u64, u32, Address),
+
+    /// Invitation for an address to join an invite-only group.
+    /// Stored in persistent storage under `("INVITE", group_id, invitee)`.
+    Invitation(u64, Address),
+
+    /// Addresses with an outstanding or accepted invitation to a group.
+    /// Stored in persistent storage under `("INVLIST", group_id)`.
+    InvitationList(u64),
+
+    /// Request from an address to join an approval-required group.
+    /// Stored in persistent storage under `("JOINREQ", group_id, requester)`.
+    JoinRequest(u64, Address),
+
+    /// Addresses with a pending join request for a group.
+    /// Stored in persistent storage under `("JREQLIST", group_id)`.
+    JoinRequestList(u64),
+
+    /// Default record for a member who missed a cycle.
+    /// Stored in persistent storage under `("DEFAULT", group_id, cycle, member)`.
+    Default(u64, u32, Address),
+
+    /// Members recorded as defaulted in a cycle.
+    /// Stored in persistent storage under `("DEFLIST", group_id, cycle)`.
+    CycleDefaults(u64, u32),
+
+    /// Members who forfeited their payout slot by defaulting.
+    /// Stored in persistent storage under `("FORFEIT", group_id)`.
+    ForfeitedMembers(u64),
+
+    /// Premiums deducted from a cycle's pot into the insurance reserve.
+    /// Stored in persistent storage under `("PREMDED", group_id, cycle)`.
+    CyclePremiums(u64, u32),
+
+    /// Collateral a member has escrowed with a group.
+    /// Stored in persistent storage under `("COLLAT", group_id, member)`.
+    Collateral(u64, Address),
+
+    /// Per-member premiums paid in a cycle.
+    /// Stored in persistent storage under `("PREMLIST", group_id, cycle)`.
+    MemberPremiums(u64, u32),
+
+    /// Pot and reserve totals owed for a token across all groups.
+    /// Stored in persistent storage under `("LIABS", token_address)`.
+    TokenLiabilities(Address),
+
+    /// Insurance claim filed for a member's default in a cycle.
+    /// Stored in persistent storage under `("DEFCLAIM", group_id, cycle, member)`.
+    DefaultClaim(u64, u32, Address),
+
+    /// Payout position swap by ID.
+    /// Stored in persistent storage under `("SWAP", swap_id)`.
+    PositionSwap(u64),
+
+    /// IDs of the position swaps proposed in a group.
+    /// Stored in persistent storage under `("SWAPLIST", group_id)`.
+    GroupSwaps(u64),
+
+    /// Payout bids placed in an auction cycle.
+    /// Stored in persistent storage under `("BIDS", group_id, cycle)`.
+    CycleBids(u64, u32),
+
+    /// Auction discount shares credited to a member's next contribution.
+    /// Stored in persistent storage under `("BIDCRED", group_id, member)`.
+    AuctionCredit(u64, Address),
+
+    /// A member's need declaration in a need-based group.
+    /// Stored in persistent storage under `("NEED", group_id, member)`.
+    NeedDeclaration(u64, Address),
+
+    /// Commitments made towards a `Random` group's draw in a cycle.
+    /// Stored in persistent storage under `("RCOMMITS", group_id, cycle)`.
+    RandomCommitments(u64, u32),
+
+    /// Payout rotation fixed when the group filled.
+    /// Stored in persistent storage under `("SCHED", group_id)`.
+    PayoutSchedule(u64),
+
+    /// Number of shares ("hands") a member holds in a group.
+    /// Stored in persistent storage under `("SHARES", group_id, member)`.
+    MemberShares(u64, Address),
+
+    /// Number of payouts a member has received from a group.
+    /// Stored in persistent storage under `("PAYCOUNT", group_id, member)`.
+    PayoutCount(u64, Address),
+
+    /// A member's own contribution per share when it differs from the group's.
+    /// Stored in persistent storage under `("TIER", group_id, member)`.
+    ContributionTier(u64, Address),
+
+    /// Pot held back from lower-tier payouts to fund higher-tier ones.
+    /// Stored in persistent storage under `("TIERHELD", group_id)`.
+    TierHeld(u64),
+
+    /// Payout still owed to a higher-tier recipient.
+    /// Stored in persistent storage under `("TIEROWED", group_id, member)`.
+    TierOwed(u64, Address),
+
+    /// Members who want to carry on into the group's next round.
+    /// Stored in persistent storage under `("ROLLIN", group_id)`.
+    RolloverOptIns(u64),
+
+    /// Token an admin-allowlisted yield adapter lends out.
+    /// Stored in persistent storage under `("YLDADPT", adapter)`.
+    YieldAdapter(Address),
+
+    /// A group's yield adapter and how it shares out yield.
+    /// Stored in persistent storage under `("YLDCFG", group_id)`.
+    YieldConfig(u64),
+
+    /// A group's funds currently parked with its yield adapter.
+    /// Stored in persistent storage under `("YLDPOS", group_id)`.
+    YieldPosition(u64),
+
+    /// Units issued across every group's position with a yield adapter.
+    /// Stored in persistent storage under `("YLDUNITS", adapter)`.
+    YieldUnits(Address),
+
+    /// Members whose contributions are pulled from their token allowance.
+    /// Stored in persistent storage under `("AUTODEBT", group_id)`.
+    AutoDebitMembers(u64),
+
+    /// Claims against a group's insurance pool awaiting settlement.
+    /// Stored in persistent storage under `("GCLAIMS", group_id)`.
+    PendingClaims(u64),
+
+    /// Default keeper bounty in basis points of a group's contribution.
+    /// Stored in instance storage under `"KEEPDFLT"`.
+    DefaultKeeperFee,
+
+    /// A group's own keeper bounty in basis points of its contribution.
+    /// Stored in persistent storage under `("KEEPFEE", group_id)`.
+    KeeperFee(u64),
+
+    /// Funds set aside to pay keepers who crank a group.
+    /// Stored in persistent storage under `("KEEPRSV", group_id)`.
+    KeeperReserve(u64),
+
+    /// Contributions a member has paid ahead for future cycles.
+    /// Stored in persistent storage under `("PREPAID", group_id, member)`.
+    Prepayment(u64, Address),
+
+    /// Ascending ids of the groups matching a [`GroupFilter`](crate::types::GroupFilter)
+    /// whose ids fall on one page of `GROUP_INDEX_PAGE_SIZE` ids.
+    /// Stored in persistent storage under `("GIDX", filter, page)`.
+    GroupIndex(crate::types::GroupFilter, u64),
+
+    /// Ascending ids of the groups an address is a member of.
+    /// Stored in persistent storage under `("MGROUPS", member)`.
+    MemberGroups(Address),
 }
 
 impl StorageKey {
@@ -93,12 +246,50 @@ impl StorageKey {
             StorageKey::ContributionDetail(_, _, _) => symbol_short!("CONTREC"),
             StorageKey::MemberPenalty(_, _) => symbol_short!("PENALTY"),
             StorageKey::CyclePenaltyPool(_, _) => symbol_short!("PENPOOL"),
-            StorageKey::InsurancePool(_) => symbol_short!("INSPOOL"),
+            StorageKey::InsurancePool(_, _) => symbol_short!("INSPOOL"),
             StorageKey::InsuranceClaim(_) => symbol_short!("INSCLAIM"),
             StorageKey::ClaimCounter => symbol_short!("ICONT"),
             StorageKey::GroupMilestones(_) => symbol_short!("GMILE"),
             StorageKey::MemberAchievements(_) => symbol_short!("MACHIEV"),
             StorageKey::MemberStatsData(_) => symbol_short!("MSTATS"),
+            StorageKey::PartialContribution(_, _, _) => symbol_short!("PARTIAL"),
+            StorageKey::Invitation(_, _) => symbol_short!("INVITE"),
+            StorageKey::InvitationList(_) => symbol_short!("INVLIST"),
+            StorageKey::JoinRequest(_, _) => symbol_short!("JOINREQ"),
+            StorageKey::JoinRequestList(_) => symbol_short!("JREQLIST"),
+            StorageKey::Default(_, _, _) => symbol_short!("DEFAULT"),
+            StorageKey::CycleDefaults(_, _) => symbol_short!("DEFLIST"),
+            StorageKey::ForfeitedMembers(_) => symbol_short!("FORFEIT"),
+            StorageKey::CyclePremiums(_, _) => symbol_short!("PREMDED"),
+            StorageKey::MemberPremiums(_, _) => symbol_short!("PREMLIST"),
+            StorageKey::Collateral(_, _) => symbol_short!("COLLAT"),
+            StorageKey::TokenLiabilities(_) => symbol_short!("LIABS"),
+            StorageKey::DefaultClaim(_, _, _) => symbol_short!("DEFCLAIM"),
+            StorageKey::PositionSwap(_) => symbol_short!("SWAP"),
+            StorageKey::GroupSwaps(_) => symbol_short!("SWAPLIST"),
+            StorageKey::CycleBids(_, _) => symbol_short!("BIDS"),
+            StorageKey::AuctionCredit(_, _) => symbol_short!("BIDCRED"),
+            StorageKey::NeedDeclaration(_, _) => symbol_short!("NEED"),
+            StorageKey::RandomCommitments(_, _) => symbol_short!("RCOMMITS"),
+            StorageKey::PayoutSchedule(_) => symbol_short!("SCHED"),
+            StorageKey::MemberShares(_, _) => symbol_short!("SHARES"),
+            StorageKey::PayoutCount(_, _) => symbol_short!("PAYCOUNT"),
+            StorageKey::ContributionTier(_, _) => symbol_short!("TIER"),
+            StorageKey::TierHeld(_) => symbol_short!("TIERHELD"),
+            StorageKey::TierOwed(_, _) => symbol_short!("TIEROWED"),
+            StorageKey::RolloverOptIns(_) => symbol_short!("ROLLIN"),
+            StorageKey::YieldAdapter(_) => symbol_short!("YLDADPT"),
+            StorageKey::YieldConfig(_) => symbol_short!("YLDCFG"),
+            StorageKey::YieldPosition(_) => symbol_short!("YLDPOS"),
+            StorageKey::YieldUnits(_) => symbol_short!("YLDUNITS"),
+            StorageKey::AutoDebitMembers(_) => symbol_short!("AUTODEBT"),
+            StorageKey::PendingClaims(_) => symbol_short!("GCLAIMS"),
+            StorageKey::DefaultKeeperFee => symbol_short!("KEEPDFLT"),
+            StorageKey::KeeperFee(_) => symbol_short!("KEEPFEE"),
+            StorageKey::KeeperReserve(_) => symbol_short!("KEEPRSV"),
+            StorageKey::Prepayment(_, _) => symbol_short!("PREPAID"),
+            StorageKey::GroupIndex(_, _) => symbol_short!("GIDX"),
+            StorageKey::MemberGroups(_) => symbol_short!("MGROUPS"),
         }
     }
 }
@@ -128,6 +319,7 @@ pub fn get_next_group_id(env: &Env) -> u64 {
 ///
 /// This function writes or overwrites the group data for the given `group_id`.
 /// Call this any time the group's mutable fields (members, cycle, payout index, etc.) change.
+/// The group discovery indexes and members' group lists are updated to match.
 ///
 /// # Arguments
 /// * `env` - The contract environment used to access persistent storage
@@ -135,7 +327,211 @@ pub fn get_next_group_id(env: &Env) -> u64 {
 /// * `group` - The group data to store
 pub fn store_group(env: &Env, group_id: u64, group: &crate::types::Group) {
     let key = (symbol_short!("GROUP"), group_id);
+    let previous: Option<crate::types::Group> = env.storage().persistent().get(&key);
     env.storage().persistent().set(&key, group);
+    update_group_indexes(env, previous.as_ref(), group);
+    update_member_groups(env, previous.as_ref(), group);
+}
+
+/// The state a group is listed under: completed groups count as
+/// [`GroupState::Complete`](crate::types::GroupState::Complete) whatever
+/// their `state` says.
+pub fn listed_state(group: &crate::types::Group) -> crate::types::GroupState {
+    if group.is_complete {
+        crate::types::GroupState::Complete
+    } else {
+        group.state
+    }
+}
+
+/// Whether a group still takes new members and has shares left to fill.
+fn has_open_seats(env: &Env, group: &crate::types::Group) -> bool {
+    accepts_members(group) && seats_taken(env, group) < group.max_members
+}
+
+/// Whether a group takes new members at all, however many seats it has left.
+fn accepts_members(group: &crate::types::Group) -> bool {
+    if group.is_complete || group.state == crate::types::GroupState::Cancelled {
+        return false;
+    }
+    group.state == crate::types::GroupState::Forming
+        || group.min_members == 0
+        || group.allow_late_join
+}
+
+/// Seats taken in a group, counting each share held as a seat.
+fn seats_taken(env: &Env, group: &crate::types::Group) -> u32 {
+    group
+        .members
+        .iter()
+        .map(|member| get_member_shares(env, group.id, &member))
+        .sum()
+}
+
+/// Moves a group between discovery indexes after it changes.
+///
+/// An index is only read or written when what it lists the group by may have
+/// changed: its listed state, its token, or whether it has open seats.
+/// Changes to a member's shares are not visible here; callers changing them
+/// use [`update_open_seat_index`]. Groups created before the indexes existed
+/// are added by [`index_group`].
+fn update_group_indexes(
+    env: &Env,
+    previous: Option<&crate::types::Group>,
+    group: &crate::types::Group,
+) {
+    use crate::types::GroupFilter;
+
+    let state = listed_state(group);
+    let previous_state = previous.map(listed_state);
+    if previous_state != Some(state) {
+        if let Some(previous_state) = previous_state {
+            remove_from_group_index(env, &GroupFilter::State(previous_state), group.id);
+        }
+        add_to_group_index(env, &GroupFilter::State(state), group.id);
+    }
+
+    let previous_token = previous.map(|previous| previous.token_address.clone());
+    if previous_token.as_ref() != Some(&group.token_address) {
+        if let Some(previous_token) = previous_token {
+            remove_from_group_index(env, &GroupFilter::Token(previous_token), group.id);
+        }
+        add_to_group_index(env, &GroupFilter::Token(group.token_address.clone()), group.id);
+    }
+
+    let previous = match previous {
+        Some(previous) => previous,
+        None => {
+            update_open_seat_index(env, group);
+            return;
+        }
+    };
+    let accepting = accepts_members(group);
+    let joined = group.members.len() > previous.members.len();
+    let left = group.members.len() < previous.members.len();
+    let may_have_changed =
+        if accepting != accepts_members(previous) || group.max_members != previous.max_members {
+            true
+        } else if !accepting || !(joined || left) {
+            // Slots handed over or swapped leave the seats taken as they were
+            false
+        } else {
+            // Seats taken only grow as members join and only shrink as they
+            // leave, so a join can only fill the group and a leave only open it
+            has_open_seats(env, group) == left
+        };
+    if may_have_changed {
+        update_open_seat_index(env, group);
+    }
+}
+
+/// Adds `group` to or removes it from the open-seat index to match its
+/// current members and shares.
+pub fn update_open_seat_index(env: &Env, group: &crate::types::Group) {
+    if has_open_seats(env, group) {
+        add_to_group_index(env, &crate::types::GroupFilter::OpenSeats, group.id);
+    } else {
+        remove_from_group_index(env, &crate::types::GroupFilter::OpenSeats, group.id);
+    }
+}
+
+/// Adds `group` to every discovery index it belongs in and to its members'
+/// group lists, for groups stored before those existed. Indexes and lists
+/// already listing it are unchanged.
+pub fn index_group(env: &Env, group: &crate::types::Group) {
+    use crate::types::GroupFilter;
+
+    add_to_group_index(env, &GroupFilter::State(listed_state(group)), group.id);
+    add_to_group_index(env, &GroupFilter::Token(group.token_address.clone()), group.id);
+    update_open_seat_index(env, group);
+    for member in group.members.iter() {
+        add_member_group(env, &member, group.id);
+    }
+}
+
+fn group_index_page(group_id: u64) -> u64 {
+    group_id / crate::types::GROUP_INDEX_PAGE_SIZE
+}
+
+fn add_to_group_index(env: &Env, filter: &crate::types::GroupFilter, group_id: u64) {
+    let page = group_index_page(group_id);
+    let mut ids = get_group_index_page(env, filter, page);
+    if let Err(position) = ids.binary_search(group_id) {
+        ids.insert(position, group_id);
+        let key = (symbol_short!("GIDX"), filter.clone(), page);
+        env.storage().persistent().set(&key, &ids);
+    }
+}
+
+fn remove_from_group_index(env: &Env, filter: &crate::types::GroupFilter, group_id: u64) {
+    let page = group_index_page(group_id);
+    let mut ids = get_group_index_page(env, filter, page);
+    if let Ok(position) = ids.binary_search(group_id) {
+        ids.remove(position);
+        let key = (symbol_short!("GIDX"), filter.clone(), page);
+        env.storage().persistent().set(&key, &ids);
+    }
+}
+
+/// Adds the group to the lists of members who joined it and drops it from
+/// the lists of those who left or were removed.
+///
+/// Groups stay listed once they complete or are cancelled.
+fn update_member_groups(
+    env: &Env,
+    previous: Option<&crate::types::Group>,
+    group: &crate::types::Group,
+) {
+    let previous_members = previous
+        .map(|previous| previous.members.clone())
+        .unwrap_or_else(|| Vec::new(env));
+    if previous_members == group.members {
+        return;
+    }
+
+    for member in group.members.iter() {
+        if !previous_members.contains(&member) {
+            add_member_group(env, &member, group.id);
+        }
+    }
+    for member in previous_members.iter() {
+        if !group.members.contains(&member) {
+            let mut ids = get_member_groups(env, &member);
+            if let Ok(position) = ids.binary_search(group.id) {
+                ids.remove(position);
+                env.storage().persistent().set(&(symbol_short!("MGROUPS"), &member), &ids);
+            }
+        }
+    }
+}
+
+fn add_member_group(env: &Env, member: &Address, group_id: u64) {
+    let mut ids = get_member_groups(env, member);
+    if let Err(position) = ids.binary_search(group_id) {
+        ids.insert(position, group_id);
+        env.storage().persistent().set(&(symbol_short!("MGROUPS"), member), &ids);
+    }
+}
+
+/// Retrieves the ids of the groups `member` belongs to, in ascending order.
+pub fn get_member_groups(env: &Env, member: &Address) -> Vec<u64> {
+    let key = (symbol_short!("MGROUPS"), member);
+    env.storage().persistent().get(&key).unwrap_or_else(|| Vec::new(env))
+}
+
+/// Retrieves the ids of the groups matching `filter` on index page `page`,
+/// in ascending order.
+///
+/// Page `n` holds ids from `n * GROUP_INDEX_PAGE_SIZE` up to, but not
+/// including, `(n + 1) * GROUP_INDEX_PAGE_SIZE`.
+pub fn get_group_index_page(env: &Env, filter: &crate::types::GroupFilter, page: u64) -> Vec<u64> {
+    let key = (symbol_short!("GIDX"), filter.clone(), page);
+    env.storage().persistent().get(&key).unwrap_or_else(|| Vec::new(env))
+}
+
+/// Returns the highest group id issued so far (`0` if no group exists).
+pub fn get_group_count(env: &Env) -> u64 {
+    env.storage().instance().get(&symbol_short!("GCOUNTER")).unwrap_or(0)
 }
 
 /// Retrieves a [`Group`](crate::types::Group) from persistent ledger storage.
@@ -203,18 +599,324 @@ pub fn has_contributed(env: &Env, group_id: u64, cycle: u32, member: &Address) -
     env.storage().persistent().get(&key).unwrap_or(false)
 }
 
-/// Records that the given member has received their payout for a group.
+/// Removes a member's contribution flag for a cycle.
+pub fn remove_contribution(env: &Env, group_id: u64, cycle: u32, member: &Address) {
+    let key = (symbol_short!("CONTRIB"), group_id, cycle, member);
+    env.storage().persistent().remove(&key);
+}
+
+/// Stores the running installment total for a member in a cycle.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `group_id` - The group the payment belongs to
+/// * `cycle` - The cycle number
+/// * `member` - The paying member's address
+/// * `record` - The updated running total
+pub fn store_partial_contribution(
+    env: &Env,
+    group_id: u64,
+    cycle: u32,
+    member: &Address,
+    record: &crate::types::PartialContribution,
+) {
+    let key = (symbol_short!("PARTIAL"), group_id, cycle, member);
+    env.storage().persistent().set(&key, record);
+}
+
+/// Retrieves the running installment total for a member in a cycle.
+///
+/// # Returns
+/// `Some(PartialContribution)` if the member has paid anything this cycle, `None` otherwise
+pub fn get_partial_contribution(
+    env: &Env,
+    group_id: u64,
+    cycle: u32,
+    member: &Address,
+) -> Option<crate::types::PartialContribution> {
+    let key = (symbol_short!("PARTIAL"), group_id, cycle, member);
+    env.storage().persistent().get(&key)
+}
+
+/// Removes the running installment total for a member in a cycle.
+pub fn remove_partial_contribution(env: &Env, group_id: u64, cycle: u32, member: &Address) {
+    let key = (symbol_short!("PARTIAL"), group_id, cycle, member);
+    env.storage().persistent().remove(&key);
+}
+
+/// Records that the given member has received a payout for a group.
 ///
 /// This flag is set after `execute_payout` successfully distributes funds.
 /// It can be used for audit purposes and to prevent any future duplicate payouts.
+/// The member's payout count is incremented alongside it, so members holding
+/// several shares can be paid once per share.
 ///
 /// # Arguments
 /// * `env` - The contract environment used to access persistent storage
 /// * `group_id` - The group the payout belongs to
 /// * `member` - The address that received the payout
 pub fn mark_payout_received(env: &Env, group_id: u64, member: &Address) {
+    let count = get_payouts_received(env, group_id, member);
     let key = (symbol_short!("PAYOUT"), group_id, member);
     env.storage().persistent().set(&key, &true);
+    store_payouts_received(env, group_id, member, count + 1);
+}
+
+/// Clears the payout-received flag and count for a member, e.g. when their
+/// slot is handed to someone else.
+pub fn clear_payout_received(env: &Env, group_id: u64, member: &Address) {
+    let key = (symbol_short!("PAYOUT"), group_id, member);
+    env.storage().persistent().remove(&key);
+    let key = (symbol_short!("PAYCOUNT"), group_id, member);
+    env.storage().persistent().remove(&key);
+}
+
+/// Stores the number of payouts a member has received from a group.
+pub fn store_payouts_received(env: &Env, group_id: u64, member: &Address, count: u32) {
+    let key = (symbol_short!("PAYCOUNT"), group_id, member);
+    env.storage().persistent().set(&key, &count);
+}
+
+/// Returns the number of payouts a member has received from a group.
+///
+/// Payouts recorded before counts were kept only set the flag and count as one.
+pub fn get_payouts_received(env: &Env, group_id: u64, member: &Address) -> u32 {
+    let key = (symbol_short!("PAYCOUNT"), group_id, member);
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| has_received_payout(env, group_id, member) as u32)
+}
+
+/// Stores the number of shares a member holds in a group.
+pub fn store_member_shares(env: &Env, group_id: u64, member: &Address, shares: u32) {
+    let key = (symbol_short!("SHARES"), group_id, member);
+    env.storage().persistent().set(&key, &shares);
+}
+
+/// Returns the number of shares a member holds in a group (1 if never set).
+pub fn get_member_shares(env: &Env, group_id: u64, member: &Address) -> u32 {
+    let key = (symbol_short!("SHARES"), group_id, member);
+    env.storage().persistent().get(&key).unwrap_or(1)
+}
+
+/// Removes a member's share count for a group.
+pub fn remove_member_shares(env: &Env, group_id: u64, member: &Address) {
+    let key = (symbol_short!("SHARES"), group_id, member);
+    env.storage().persistent().remove(&key);
+}
+
+/// Stores a member's own contribution per share for a group.
+pub fn store_contribution_tier(env: &Env, group_id: u64, member: &Address, amount: i128) {
+    let key = (symbol_short!("TIER"), group_id, member);
+    env.storage().persistent().set(&key, &amount);
+}
+
+/// Retrieves a member's own contribution per share, if it differs from the group's.
+pub fn get_contribution_tier(env: &Env, group_id: u64, member: &Address) -> Option<i128> {
+    let key = (symbol_short!("TIER"), group_id, member);
+    env.storage().persistent().get(&key)
+}
+
+/// Removes a member's contribution tier for a group.
+pub fn remove_contribution_tier(env: &Env, group_id: u64, member: &Address) {
+    let key = (symbol_short!("TIER"), group_id, member);
+    env.storage().persistent().remove(&key);
+}
+
+/// Stores the pot a group holds back to fund higher-tier payouts.
+pub fn store_tier_held(env: &Env, group_id: u64, amount: i128) {
+    let key = (symbol_short!("TIERHELD"), group_id);
+    env.storage().persistent().set(&key, &amount);
+}
+
+/// Retrieves the pot a group holds back to fund higher-tier payouts (0 if none).
+pub fn get_tier_held(env: &Env, group_id: u64) -> i128 {
+    let key = (symbol_short!("TIERHELD"), group_id);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Stores the payout still owed to a member, removing the entry once settled.
+pub fn store_tier_owed(env: &Env, group_id: u64, member: &Address, amount: i128) {
+    let key = (symbol_short!("TIEROWED"), group_id, member);
+    if amount > 0 {
+        env.storage().persistent().set(&key, &amount);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Retrieves the payout still owed to a member (0 if none).
+pub fn get_tier_owed(env: &Env, group_id: u64, member: &Address) -> i128 {
+    let key = (symbol_short!("TIEROWED"), group_id, member);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Stores the members who opted into a group's next round.
+pub fn store_rollover_opt_ins(env: &Env, group_id: u64, members: &Vec<Address>) {
+    let key = (symbol_short!("ROLLIN"), group_id);
+    env.storage().persistent().set(&key, members);
+}
+
+/// Retrieves the members who opted into a group's next round (empty if none).
+pub fn get_rollover_opt_ins(env: &Env, group_id: u64) -> Vec<Address> {
+    let key = (symbol_short!("ROLLIN"), group_id);
+    env.storage().persistent().get(&key).unwrap_or_else(|| Vec::new(env))
+}
+
+/// Stores the members who opted into auto-debit for a group.
+pub fn store_auto_debit_members(env: &Env, group_id: u64, members: &Vec<Address>) {
+    let key = (symbol_short!("AUTODEBT"), group_id);
+    env.storage().persistent().set(&key, members);
+}
+
+/// Retrieves the members who opted into auto-debit for a group (empty if none).
+pub fn get_auto_debit_members(env: &Env, group_id: u64) -> Vec<Address> {
+    let key = (symbol_short!("AUTODEBT"), group_id);
+    env.storage().persistent().get(&key).unwrap_or_else(|| Vec::new(env))
+}
+
+/// Stores the claims against a group's insurance pool awaiting settlement.
+pub fn store_pending_claims(env: &Env, group_id: u64, claim_ids: &Vec<u64>) {
+    let key = (symbol_short!("GCLAIMS"), group_id);
+    env.storage().persistent().set(&key, claim_ids);
+}
+
+/// Retrieves the claims against a group's insurance pool awaiting settlement (empty if none).
+pub fn get_pending_claims(env: &Env, group_id: u64) -> Vec<u64> {
+    let key = (symbol_short!("GCLAIMS"), group_id);
+    env.storage().persistent().get(&key).unwrap_or_else(|| Vec::new(env))
+}
+
+/// Stores the default keeper bounty in basis points.
+pub fn store_default_keeper_fee(env: &Env, fee_bps: u32) {
+    let key = symbol_short!("KEEPDFLT");
+    env.storage().instance().set(&key, &fee_bps);
+}
+
+/// Retrieves the default keeper bounty in basis points (0 if unset).
+pub fn get_default_keeper_fee(env: &Env) -> u32 {
+    let key = symbol_short!("KEEPDFLT");
+    env.storage().instance().get(&key).unwrap_or(0)
+}
+
+/// Stores a group's own keeper bounty in basis points.
+pub fn store_keeper_fee(env: &Env, group_id: u64, fee_bps: u32) {
+    let key = (symbol_short!("KEEPFEE"), group_id);
+    env.storage().persistent().set(&key, &fee_bps);
+}
+
+/// Retrieves a group's own keeper bounty in basis points, if it set one.
+pub fn get_keeper_fee(env: &Env, group_id: u64) -> Option<u32> {
+    let key = (symbol_short!("KEEPFEE"), group_id);
+    env.storage().persistent().get(&key)
+}
+
+/// Stores a group's keeper reserve, removing the entry once empty.
+pub fn store_keeper_reserve(env: &Env, group_id: u64, amount: i128) {
+    let key = (symbol_short!("KEEPRSV"), group_id);
+    if amount > 0 {
+        env.storage().persistent().set(&key, &amount);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Retrieves a group's keeper reserve (0 if none).
+pub fn get_keeper_reserve(env: &Env, group_id: u64) -> i128 {
+    let key = (symbol_short!("KEEPRSV"), group_id);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Stores a member's prepayment for a group.
+pub fn store_prepayment(
+    env: &Env,
+    group_id: u64,
+    member: &Address,
+    prepayment: &crate::types::Prepayment,
+) {
+    let key = (symbol_short!("PREPAID"), group_id, member);
+    env.storage().persistent().set(&key, prepayment);
+}
+
+/// Retrieves a member's prepayment for a group, if they have one.
+pub fn get_prepayment(
+    env: &Env,
+    group_id: u64,
+    member: &Address,
+) -> Option<crate::types::Prepayment> {
+    let key = (symbol_short!("PREPAID"), group_id, member);
+    env.storage().persistent().get(&key)
+}
+
+/// Removes a member's prepayment for a group.
+pub fn remove_prepayment(env: &Env, group_id: u64, member: &Address) {
+    let key = (symbol_short!("PREPAID"), group_id, member);
+    env.storage().persistent().remove(&key);
+}
+
+/// Allowlists a yield adapter for a token.
+pub fn store_yield_adapter(env: &Env, adapter: &Address, token: &Address) {
+    let key = (symbol_short!("YLDADPT"), adapter);
+    env.storage().persistent().set(&key, token);
+}
+
+/// Retrieves the token an allowlisted yield adapter lends out.
+pub fn get_yield_adapter(env: &Env, adapter: &Address) -> Option<Address> {
+    let key = (symbol_short!("YLDADPT"), adapter);
+    env.storage().persistent().get(&key)
+}
+
+/// Removes a yield adapter from the allowlist.
+pub fn remove_yield_adapter(env: &Env, adapter: &Address) {
+    let key = (symbol_short!("YLDADPT"), adapter);
+    env.storage().persistent().remove(&key);
+}
+
+/// Stores a group's yield configuration.
+pub fn store_yield_config(env: &Env, group_id: u64, config: &crate::types::YieldConfig) {
+    let key = (symbol_short!("YLDCFG"), group_id);
+    env.storage().persistent().set(&key, config);
+}
+
+/// Retrieves a group's yield configuration.
+pub fn get_yield_config(env: &Env, group_id: u64) -> Option<crate::types::YieldConfig> {
+    let key = (symbol_short!("YLDCFG"), group_id);
+    env.storage().persistent().get(&key)
+}
+
+/// Removes a group's yield configuration.
+pub fn remove_yield_config(env: &Env, group_id: u64) {
+    let key = (symbol_short!("YLDCFG"), group_id);
+    env.storage().persistent().remove(&key);
+}
+
+/// Stores a group's parked funds, removing the entry once nothing is parked.
+pub fn store_yield_position(env: &Env, group_id: u64, position: &crate::types::YieldPosition) {
+    let key = (symbol_short!("YLDPOS"), group_id);
+    if position.units > 0 {
+        env.storage().persistent().set(&key, position);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Retrieves a group's parked funds.
+pub fn get_yield_position(env: &Env, group_id: u64) -> Option<crate::types::YieldPosition> {
+    let key = (symbol_short!("YLDPOS"), group_id);
+    env.storage().persistent().get(&key)
+}
+
+/// Stores the units issued across every group's position with an adapter.
+pub fn store_yield_units(env: &Env, adapter: &Address, units: i128) {
+    let key = (symbol_short!("YLDUNITS"), adapter);
+    env.storage().persistent().set(&key, &units);
+}
+
+/// Retrieves the units issued across every group's position with an adapter (0 if none).
+pub fn get_yield_units(env: &Env, adapter: &Address) -> i128 {
+    let key = (symbol_short!("YLDUNITS"), adapter);
+    env.storage().persistent().get(&key).unwrap_or(0)
 }
 
 /// Returns contribution status for every member in a cycle as an ordered vector.
@@ -425,6 +1127,59 @@ pub fn add_to_penalty_pool(env: &Env, group_id: u64, cycle: u32, penalty: i128)
     store_cycle_penalty_pool(env, group_id, cycle, current + penalty);
 }
 
+/// Removes a member's default record for a cycle.
+pub fn remove_default_record(env: &Env, group_id: u64, cycle: u32, member: &Address) {
+    let key = (symbol_short!("DEFAULT"), group_id, cycle, member);
+    env.storage().persistent().remove(&key);
+}
+
+/// Stores a member's default record for a cycle.
+pub fn store_default_record(
+    env: &Env,
+    group_id: u64,
+    cycle: u32,
+    member: &Address,
+    record: &crate::types::DefaultRecord,
+) {
+    let key = (symbol_short!("DEFAULT"), group_id, cycle, member);
+    env.storage().persistent().set(&key, record);
+}
+
+/// Retrieves a member's default record for a cycle.
+pub fn get_default_record(
+    env: &Env,
+    group_id: u64,
+    cycle: u32,
+    member: &Address,
+) -> Option<crate::types::DefaultRecord> {
+    let key = (symbol_short!("DEFAULT"), group_id, cycle, member);
+    env.storage().persistent().get(&key)
+}
+
+/// Stores the list of members who defaulted in a cycle.
+pub fn store_cycle_defaults(env: &Env, group_id: u64, cycle: u32, members: &Vec<Address>) {
+    let key = (symbol_short!("DEFLIST"), group_id, cycle);
+    env.storage().persistent().set(&key, members);
+}
+
+/// Retrieves the list of members who defaulted in a cycle (empty if none).
+pub fn get_cycle_defaults(env: &Env, group_id: u64, cycle: u32) -> Vec<Address> {
+    let key = (symbol_short!("DEFLIST"), group_id, cycle);
+    env.storage().persistent().get(&key).unwrap_or_else(|| Vec::new(env))
+}
+
+/// Stores the list of members who forfeited their payout slot.
+pub fn store_forfeited_members(env: &Env, group_id: u64, members: &Vec<Address>) {
+    let key = (symbol_short!("FORFEIT"), group_id);
+    env.storage().persistent().set(&key, members);
+}
+
+/// Retrieves the list of members who forfeited their payout slot (empty if none).
+pub fn get_forfeited_members(env: &Env, group_id: u64) -> Vec<Address> {
+    let key = (symbol_short!("FORFEIT"), group_id);
+    env.storage().persistent().get(&key).unwrap_or_else(|| Vec::new(env))
+}
+
 /// Stores a refund request for a group.
 ///
 /// # Arguments
@@ -556,16 +1311,95 @@ pub fn get_refund_record(
     env.storage().persistent().get(&key)
 }
 
-/// Stores the insurance pool for a token.
-pub fn store_insurance_pool(env: &Env, token: &Address, pool: &crate::types::InsurancePool) {
-    let key = (symbol_short!("INSPOOL"), token);
-    env.storage().instance().set(&key, pool);
+/// Stores the insurance pool for a group and token.
+pub fn store_insurance_pool(
+    env: &Env,
+    group_id: u64,
+    token: &Address,
+    pool: &crate::types::InsurancePool,
+) {
+    let key = (symbol_short!("INSPOOL"), group_id, token);
+    env.storage().persistent().set(&key, pool);
+}
+
+/// Retrieves the insurance pool for a group and token.
+pub fn get_insurance_pool(
+    env: &Env,
+    group_id: u64,
+    token: &Address,
+) -> Option<crate::types::InsurancePool> {
+    let key = (symbol_short!("INSPOOL"), group_id, token);
+    env.storage().persistent().get(&key)
+}
+
+/// Stores the premiums deducted from a cycle's pot.
+pub fn store_cycle_premiums(env: &Env, group_id: u64, cycle: u32, amount: i128) {
+    let key = (symbol_short!("PREMDED"), group_id, cycle);
+    env.storage().persistent().set(&key, &amount);
 }
 
-/// Retrieves the insurance pool for a token.
-pub fn get_insurance_pool(env: &Env, token: &Address) -> Option<crate::types::InsurancePool> {
-    let key = (symbol_short!("INSPOOL"), token);
-    env.storage().instance().get(&key)
+/// Retrieves the premiums deducted from a cycle's pot (0 if none).
+pub fn get_cycle_premiums(env: &Env, group_id: u64, cycle: u32) -> i128 {
+    let key = (symbol_short!("PREMDED"), group_id, cycle);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Stores the collateral a member has escrowed with a group.
+pub fn store_collateral(env: &Env, group_id: u64, member: &Address, amount: i128) {
+    let key = (symbol_short!("COLLAT"), group_id, member);
+    env.storage().persistent().set(&key, &amount);
+}
+
+/// Retrieves the collateral a member has escrowed with a group (0 if none).
+pub fn get_collateral(env: &Env, group_id: u64, member: &Address) -> i128 {
+    let key = (symbol_short!("COLLAT"), group_id, member);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Removes a member's collateral record for a group.
+pub fn remove_collateral(env: &Env, group_id: u64, member: &Address) {
+    let key = (symbol_short!("COLLAT"), group_id, member);
+    env.storage().persistent().remove(&key);
+}
+
+/// Appends a member's premium to the cycle's premium list.
+pub fn add_member_premium(env: &Env, group_id: u64, cycle: u32, premium: &crate::types::MemberPremium) {
+    let key = (symbol_short!("PREMLIST"), group_id, cycle);
+    let mut premiums = get_member_premiums(env, group_id, cycle);
+    premiums.push_back(premium.clone());
+    env.storage().persistent().set(&key, &premiums);
+}
+
+/// Retrieves the premiums each member paid in a cycle (empty if none).
+pub fn get_member_premiums(env: &Env, group_id: u64, cycle: u32) -> Vec<crate::types::MemberPremium> {
+    let key = (symbol_short!("PREMLIST"), group_id, cycle);
+    env.storage().persistent().get(&key).unwrap_or_else(|| Vec::new(env))
+}
+
+/// Stores the pot and reserve totals owed for a token.
+pub fn store_token_liabilities(
+    env: &Env,
+    token: &Address,
+    liabilities: &crate::types::TokenLiabilities,
+) {
+    let key = (symbol_short!("LIABS"), token);
+    env.storage().persistent().set(&key, liabilities);
+}
+
+/// Retrieves the pot and reserve totals owed for a token (zero if none).
+pub fn get_token_liabilities(env: &Env, token: &Address) -> crate::types::TokenLiabilities {
+    let key = (symbol_short!("LIABS"), token);
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(crate::types::TokenLiabilities {
+            pots: 0,
+            insurance_reserves: 0,
+            collateral: 0,
+            parked: 0,
+            keeper_reserves: 0,
+            prepaid: 0,
+        })
 }
 
 /// Returns next available claim ID.
@@ -589,6 +1423,18 @@ pub fn get_insurance_claim(env: &Env, claim_id: u64) -> Option<crate::types::Ins
     env.storage().persistent().get(&key)
 }
 
+/// Records the claim filed for a member's default in a cycle.
+pub fn store_default_claim(env: &Env, group_id: u64, cycle: u32, defaulter: &Address, claim_id: u64) {
+    let key = (symbol_short!("DEFCLAIM"), group_id, cycle, defaulter);
+    env.storage().persistent().set(&key, &claim_id);
+}
+
+/// Retrieves the ID of the claim filed for a member's default in a cycle.
+pub fn get_default_claim(env: &Env, group_id: u64, cycle: u32, defaulter: &Address) -> Option<u64> {
+    let key = (symbol_short!("DEFCLAIM"), group_id, cycle, defaulter);
+    env.storage().persistent().get(&key)
+}
+
 // ── Payout-ordering helpers ───────────────────────────────────────────────────
 
 /// Returns `true` if the given member has already received their payout for a group.
@@ -642,6 +1488,118 @@ pub fn get_payout_order(env: &Env, group_id: u64, cycle: u32) -> Option<crate::t
     env.storage().persistent().get(&key)
 }
 
+/// Stores a group's fixed payout schedule.
+pub fn store_payout_schedule(env: &Env, schedule: &crate::types::PayoutSchedule) {
+    let key = (symbol_short!("SCHED"), schedule.group_id);
+    env.storage().persistent().set(&key, schedule);
+}
+
+/// Retrieves a group's fixed payout schedule, if one has been drawn.
+pub fn get_payout_schedule(env: &Env, group_id: u64) -> Option<crate::types::PayoutSchedule> {
+    let key = (symbol_short!("SCHED"), group_id);
+    env.storage().persistent().get(&key)
+}
+
+/// Stores the commitments made towards a cycle's draw, in commit order.
+pub fn store_random_commitments(
+    env: &Env,
+    group_id: u64,
+    cycle: u32,
+    commitments: &Vec<crate::types::RandomCommitment>,
+) {
+    let key = (symbol_short!("RCOMMITS"), group_id, cycle);
+    env.storage().persistent().set(&key, commitments);
+}
+
+/// Retrieves the commitments made towards a cycle's draw.
+pub fn get_random_commitments(
+    env: &Env,
+    group_id: u64,
+    cycle: u32,
+) -> Vec<crate::types::RandomCommitment> {
+    let key = (symbol_short!("RCOMMITS"), group_id, cycle);
+    env.storage().persistent().get(&key).unwrap_or_else(|| Vec::new(env))
+}
+
+/// Stores a member's need declaration.
+pub fn store_need_declaration(env: &Env, declaration: &crate::types::NeedDeclaration) {
+    let key = (symbol_short!("NEED"), declaration.group_id, &declaration.member);
+    env.storage().persistent().set(&key, declaration);
+}
+
+/// Retrieves a member's need declaration, if they made one.
+pub fn get_need_declaration(
+    env: &Env,
+    group_id: u64,
+    member: &Address,
+) -> Option<crate::types::NeedDeclaration> {
+    let key = (symbol_short!("NEED"), group_id, member);
+    env.storage().persistent().get(&key)
+}
+
+/// Stores the payout bids placed in a cycle, in the order they were placed.
+pub fn store_cycle_bids(env: &Env, group_id: u64, cycle: u32, bids: &Vec<crate::types::PayoutBid>) {
+    let key = (symbol_short!("BIDS"), group_id, cycle);
+    env.storage().persistent().set(&key, bids);
+}
+
+/// Retrieves the payout bids placed in a cycle.
+pub fn get_cycle_bids(env: &Env, group_id: u64, cycle: u32) -> Vec<crate::types::PayoutBid> {
+    let key = (symbol_short!("BIDS"), group_id, cycle);
+    env.storage().persistent().get(&key).unwrap_or_else(|| Vec::new(env))
+}
+
+/// Stores a member's auction credit, removing the entry once it is used up.
+pub fn store_auction_credit(env: &Env, group_id: u64, member: &Address, amount: i128) {
+    let key = (symbol_short!("BIDCRED"), group_id, member);
+    if amount > 0 {
+        env.storage().persistent().set(&key, &amount);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Retrieves a member's unused auction credit.
+pub fn get_auction_credit(env: &Env, group_id: u64, member: &Address) -> i128 {
+    let key = (symbol_short!("BIDCRED"), group_id, member);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Returns the next position swap ID and increments the counter.
+pub fn get_next_swap_id(env: &Env) -> u64 {
+    let key = symbol_short!("SWAPCNT");
+    let current: u64 = env.storage().instance().get(&key).unwrap_or(0);
+    let next = current + 1;
+    env.storage().instance().set(&key, &next);
+    next
+}
+
+/// Stores a position swap and records its ID against the group the first
+/// time it is stored.
+pub fn store_position_swap(env: &Env, swap: &crate::types::PositionSwap) {
+    let key = (symbol_short!("SWAP"), swap.id);
+    if !env.storage().persistent().has(&key) {
+        let mut ids = get_group_swap_ids(env, swap.group_id);
+        ids.push_back(swap.id);
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("SWAPLIST"), swap.group_id), &ids);
+    }
+    env.storage().persistent().set(&key, swap);
+}
+
+/// Retrieves a position swap by ID.
+pub fn get_position_swap(env: &Env, swap_id: u64) -> Option<crate::types::PositionSwap> {
+    let key = (symbol_short!("SWAP"), swap_id);
+    env.storage().persistent().get(&key)
+}
+
+/// Retrieves the IDs of every position swap proposed in a group, oldest first.
+pub fn get_group_swap_ids(env: &Env, group_id: u64) -> Vec<u64> {
+    let key = (symbol_short!("SWAPLIST"), group_id);
+    env.storage().persistent().get(&key).unwrap_or_else(|| Vec::new(env))
+}
+
 // ── Contribution reminder helpers ─────────────────────────────────────────────
 
 /// Stores a member's notification preferences in persistent storage.
@@ -783,6 +1741,63 @@ pub fn get_invitation(
     env.storage().persistent().get(&key)
 }
 
+/// Removes an invitation.
+pub fn remove_invitation(env: &Env, group_id: u64, invitee: &Address) {
+    let key = (symbol_short!("INVITE"), group_id, invitee);
+    env.storage().persistent().remove(&key);
+}
+
+/// Stores the list of invited addresses for a group.
+pub fn store_invitation_list(env: &Env, group_id: u64, invitees: &Vec<Address>) {
+    let key = (symbol_short!("INVLIST"), group_id);
+    env.storage().persistent().set(&key, invitees);
+}
+
+/// Retrieves the list of invited addresses for a group (empty if none).
+pub fn get_invitation_list(env: &Env, group_id: u64) -> Vec<Address> {
+    let key = (symbol_short!("INVLIST"), group_id);
+    env.storage().persistent().get(&key).unwrap_or_else(|| Vec::new(env))
+}
+
+/// Stores a request to join an approval-required group.
+pub fn store_join_request(
+    env: &Env,
+    group_id: u64,
+    requester: &Address,
+    request: &crate::types::JoinRequest,
+) {
+    let key = (symbol_short!("JOINREQ"), group_id, requester);
+    env.storage().persistent().set(&key, request);
+}
+
+/// Retrieves a request to join a group.
+pub fn get_join_request(
+    env: &Env,
+    group_id: u64,
+    requester: &Address,
+) -> Option<crate::types::JoinRequest> {
+    let key = (symbol_short!("JOINREQ"), group_id, requester);
+    env.storage().persistent().get(&key)
+}
+
+/// Removes a request to join a group.
+pub fn remove_join_request(env: &Env, group_id: u64, requester: &Address) {
+    let key = (symbol_short!("JOINREQ"), group_id, requester);
+    env.storage().persistent().remove(&key);
+}
+
+/// Stores the list of addresses with pending join requests for a group.
+pub fn store_join_request_list(env: &Env, group_id: u64, requesters: &Vec<Address>) {
+    let key = (symbol_short!("JREQLIST"), group_id);
+    env.storage().persistent().set(&key, requesters);
+}
+
+/// Retrieves the list of addresses with pending join requests (empty if none).
+pub fn get_join_request_list(env: &Env, group_id: u64) -> Vec<Address> {
+    let key = (symbol_short!("JREQLIST"), group_id);
+    env.storage().persistent().get(&key).unwrap_or_else(|| Vec::new(env))
+}
+
 // ── Multi-token storage ───────────────────────────────────────────────────
 
 /// Stores the multi-token configuration for a group.
@@ -835,6 +1850,12 @@ pub fn get_token_contribution(
     env.storage().persistent().get(&key)
 }
 
+/// Removes the token-specific contribution record for a member in a cycle.
+pub fn remove_token_contribution(env: &Env, group_id: u64, cycle: u32, member: &Address) {
+    let key = (symbol_short!("TKCONT"), group_id, cycle, member);
+    env.storage().persistent().remove(&key);
+}
+
 /// Tracks per-token balance accumulated in a group for a given cycle.
 ///
 /// This is used during payout to know how much of each token is available.
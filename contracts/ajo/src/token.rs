@@ -31,6 +31,65 @@ pub fn transfer_token(
     Ok(())
 }
 
+/// Transfer tokens out of `from`'s allowance to the contract.
+///
+/// `spender` must have been approved by `from` with the Stellar Asset
+/// Contract `approve` function.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `token_address` - Address of the token contract
+/// * `spender` - Address spending the allowance (this contract)
+/// * `from` - Address to transfer from
+/// * `to` - Address to transfer to
+/// * `amount` - Amount to transfer
+///
+/// # Returns
+/// `Ok(())` on successful transfer
+///
+/// # Errors
+/// * `TransferFailed` - If the token transfer fails
+pub fn transfer_token_from(
+    env: &Env,
+    token_address: &Address,
+    spender: &Address,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+) -> Result<(), AjoError> {
+    let client = token::Client::new(env, token_address);
+    client.transfer_from(spender, from, to, &amount);
+    Ok(())
+}
+
+/// Check if `spender` may transfer at least `amount` on behalf of `owner`.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `token_address` - Address of the token contract
+/// * `owner` - Address that granted the allowance
+/// * `spender` - Address spending the allowance
+/// * `amount` - Required amount
+///
+/// # Returns
+/// `Ok(())` if the allowance is sufficient
+///
+/// # Errors
+/// * `InsufficientAllowance` - If the allowance is less than required amount
+pub fn check_allowance(
+    env: &Env,
+    token_address: &Address,
+    owner: &Address,
+    spender: &Address,
+    amount: i128,
+) -> Result<(), AjoError> {
+    let client = token::Client::new(env, token_address);
+    if client.allowance(owner, spender) < amount {
+        return Err(AjoError::InsufficientAllowance);
+    }
+    Ok(())
+}
+
 /// Get the token balance of an address.
 ///
 /// # Arguments
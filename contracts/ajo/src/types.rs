@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address, Vec};
+use soroban_sdk::{contracttype, Address, BytesN, Vec};
 
 /// Strategy for determining payout order in a group.
 #[contracttype]
@@ -13,8 +13,80 @@ pub enum PayoutOrderingStrategy {
     VotingBased = 2,
     /// Member with the best on-time contribution history is selected.
     ContributionBased = 3,
-    /// Members vote on declared need; most-voted member receives payout.
+    /// Members declare a need and endorse each other's declarations; the
+    /// most pressing need receives payout.
     NeedBased = 4,
+    /// Members bid a discount on the pot; the largest discount wins and is
+    /// shared among the other members.
+    Auction = 5,
+}
+
+/// A member's bid to receive the current cycle's pot at a discount.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutBid {
+    /// The group this bid belongs to.
+    pub group_id: u64,
+    /// The cycle number this bid applies to.
+    pub cycle: u32,
+    /// Address of the bidding member.
+    pub bidder: Address,
+    /// Amount the bidder gives up from the pot, in token units.
+    pub discount: i128,
+    /// Unix timestamp when the bid was placed.
+    pub timestamp: u64,
+}
+
+/// What a member needs their payout for.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum NeedCategory {
+    Medical = 0,
+    Education = 1,
+    Housing = 2,
+    Business = 3,
+    Emergency = 4,
+    Other = 5,
+}
+
+/// Lowest and highest urgency a need declaration can carry.
+pub const MIN_NEED_URGENCY: u32 = 1;
+pub const MAX_NEED_URGENCY: u32 = 5;
+
+/// A member's declared need for an early payout in a need-based group.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NeedDeclaration {
+    /// The group the declaration belongs to.
+    pub group_id: u64,
+    /// The declaring member.
+    pub member: Address,
+    /// What the payout is needed for.
+    pub category: NeedCategory,
+    /// Urgency from 1 (can wait) to 5 (critical).
+    pub urgency: u32,
+    /// Unix timestamp by which the funds are needed.
+    pub needed_by: u64,
+    /// Hash of supporting evidence kept off-chain.
+    pub evidence_hash: BytesN<32>,
+    /// Members who have endorsed this declaration.
+    pub endorsers: Vec<Address>,
+    /// Cycle in which the declaration was made.
+    pub declared_cycle: u32,
+    /// Unix timestamp when the declaration was made.
+    pub declared_at: u64,
+}
+
+/// How an auction winner's discount reaches the other members.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum DiscountSettlement {
+    /// Each share is transferred to the member at payout time (default).
+    PayOut = 0,
+    /// Each share is credited against the member's next contribution.
+    CreditNextContribution = 1,
 }
 
 /// A single vote cast for the next payout recipient.
@@ -33,6 +105,41 @@ pub struct PayoutVote {
     pub timestamp: u64,
 }
 
+/// Lifecycle of a payout position swap.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum SwapStatus {
+    /// Waiting for the counterparty to accept.
+    Proposed = 0,
+    /// Accepted by both members, waiting for the creator's approval.
+    AwaitingApproval = 1,
+    /// Positions have been exchanged.
+    Completed = 2,
+    /// Withdrawn before it completed.
+    Cancelled = 3,
+}
+
+/// A request between two members to exchange their payout positions.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PositionSwap {
+    /// Unique swap identifier.
+    pub id: u64,
+    /// The group whose rotation is reordered.
+    pub group_id: u64,
+    /// Member who proposed the swap.
+    pub proposer: Address,
+    /// Member asked to take the proposer's position.
+    pub counterparty: Address,
+    /// Current status of the swap.
+    pub status: SwapStatus,
+    /// Unix timestamp when the swap was proposed.
+    pub proposed_at: u64,
+    /// Unix timestamp when the swap completed or was cancelled (`0` while open).
+    pub closed_at: u64,
+}
+
 /// Records the determined payout order for a specific cycle.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -47,6 +154,76 @@ pub struct PayoutOrder {
     pub selection_method: PayoutOrderingStrategy,
     /// Unix timestamp when the order was determined.
     pub determined_at: u64,
+    /// Seed a `Random` recipient was drawn from, so the draw can be
+    /// re-computed by anyone. All zeros for other strategies.
+    pub seed: BytesN<32>,
+}
+
+/// Rule used to fix a group's whole payout rotation when it fills.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum ScheduleRule {
+    /// No schedule; the payout strategy picks each cycle's recipient.
+    Unlocked = 0,
+    /// Members are paid in the order they joined.
+    JoinOrder = 1,
+    /// Members are shuffled from a seed recorded with the schedule. The seed
+    /// comes from the host PRNG, so this trusts the validators.
+    Shuffled = 2,
+    /// Members with the highest risk score (most reliable) are paid first.
+    MostReliableFirst = 3,
+}
+
+/// A group's payout rotation, fixed when the group filled.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutSchedule {
+    /// The group this schedule belongs to.
+    pub group_id: u64,
+    /// Rule the rotation was drawn with.
+    pub rule: ScheduleRule,
+    /// Recipients in payout order.
+    pub recipients: Vec<Address>,
+    /// Seed a `Shuffled` rotation was drawn from; all zeros otherwise.
+    pub seed: BytesN<32>,
+    /// Unix timestamp when the schedule was fixed.
+    pub locked_at: u64,
+}
+
+/// One cycle of a group's payout schedule.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScheduledPayout {
+    /// The cycle number.
+    pub cycle: u32,
+    /// The member paid in this cycle.
+    pub recipient: Address,
+    /// When the payout happened, or is projected to happen if every cycle
+    /// pays out as soon as its grace period ends.
+    pub projected_date: u64,
+    /// Whether the payout has been made.
+    pub paid: bool,
+}
+
+/// A member's commitment to a secret for a `Random` group's draw.
+///
+/// Members commit `sha256(secret)` before the cycle's contribution deadline
+/// and reveal `secret` after it. Revealed secrets are hashed together to
+/// seed the draw.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RandomCommitment {
+    /// The committing member.
+    pub member: Address,
+    /// `sha256(secret)`.
+    pub commitment: BytesN<32>,
+    /// Whether the secret has been revealed.
+    pub revealed: bool,
+    /// The revealed secret; all zeros until revealed.
+    pub secret: BytesN<32>,
+    /// Unix timestamp when the commitment was made.
+    pub committed_at: u64,
 }
 
 /// State of a group in its lifecycle.
@@ -60,6 +237,8 @@ pub enum GroupState {
     Cancelled = 1,
     /// Group has completed all cycles successfully.
     Complete = 2,
+    /// Group is waiting for members; cycles have not started yet.
+    Forming = 3,
 }
 
 /// Represents an Ajo group configuration and state.
@@ -133,7 +312,7 @@ pub struct Group {
     /// Penalties are added to the group pool for the next recipient.
     pub penalty_rate: u32,
 
-    /// Current state of the group (Active, Cancelled, or Complete).
+    /// Current state of the group (Forming, Active, Cancelled, or Complete).
     pub state: GroupState,
 
     /// Insurance configuration for the group.
@@ -146,6 +325,52 @@ pub struct Group {
     /// Access control type for the group.
     /// Defaults to `Open` when created via `create_group`.
     pub access_type: GroupAccessType,
+
+    /// Whether a member who defaults on a cycle loses their future payout slot.
+    /// Defaults to `false` (defaulters keep their slot).
+    pub forfeit_payout_on_default: bool,
+
+    /// Security deposit each member escrows on joining, in token units.
+    /// Slashed to cover missed contributions and returned when the group
+    /// completes or is cancelled. `0` means no collateral is required.
+    pub collateral_amount: i128,
+
+    /// Whether position swaps need the creator's approval once both members
+    /// have agreed. Defaults to `false`.
+    pub swap_requires_approval: bool,
+
+    /// How auction discounts are shared with the other members.
+    /// Defaults to `PayOut`; only used by `Auction` groups.
+    pub discount_settlement: DiscountSettlement,
+
+    /// How the full payout rotation is fixed once the group fills.
+    /// Defaults to `Unlocked` (recipients are chosen cycle by cycle).
+    pub schedule_rule: ScheduleRule,
+
+    /// Members needed before the creator can start a forming group.
+    /// `0` means the group had no forming phase and started on creation.
+    pub min_members: u32,
+
+    /// When a forming group that has not started can be expired, as a Unix
+    /// timestamp. `0` if the group had no forming phase.
+    pub formation_deadline: u64,
+
+    /// Whether members can still join after a forming group has started.
+    /// Defaults to `false`; ignored for groups without a forming phase.
+    pub allow_late_join: bool,
+
+    /// Whether a successor round opens automatically when this one completes.
+    /// Defaults to `false`.
+    pub rollover_enabled: bool,
+
+    /// Which round of a recurring group this is, starting at 1.
+    pub round: u32,
+
+    /// The group whose completion opened this round, or `0` for a first round.
+    pub predecessor_id: u64,
+
+    /// The round opened when this one completed, or `0` if none was.
+    pub successor_id: u64,
 }
 
 /// Comprehensive snapshot of a group's current state.
@@ -202,6 +427,24 @@ pub struct GroupStatus {
 
     /// Unix timestamp when grace period ends.
     pub grace_period_end_time: u64,
+
+    /// Total amount received towards the current cycle, including partial
+    /// installments from members who have not yet paid in full.
+    pub total_collected: i128,
+
+    /// Collateral each member must escrow on joining (0 if none).
+    pub collateral_amount: i128,
+
+    /// Collateral currently escrowed by each member, in member order.
+    pub collateral_balances: Vec<CollateralBalance>,
+}
+
+/// Collateral a member currently has escrowed with a group.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CollateralBalance {
+    pub member: Address,
+    pub amount: i128,
 }
 
 /// Optional metadata for a group.
@@ -266,7 +509,7 @@ pub struct Dispute {
     pub votes_for_action: u32,
     pub votes_against_action: u32,
     pub proposed_resolution: DisputeResolution,
-    pub final_resolution: Option<DisputeResolution>,
+    pub final_resolution: DisputeResolution, // NoAction until resolved
 }
 
 #[contracttype]
@@ -285,6 +528,10 @@ pub const VOTING_PERIOD: u64 = 604_800;
 pub const DISPUTE_VOTING_PERIOD: u64 = 604_800; // 7 days for disputes
 pub const REFUND_APPROVAL_THRESHOLD: u32 = 51;
 pub const DISPUTE_APPROVAL_THRESHOLD: u32 = 66;
+pub const MAX_KEEPER_FEE_BPS: u32 = 1_000; // keeper bounty cap: 10% of a contribution
+pub const MAX_GROUP_PAGE_SIZE: u32 = 50;
+pub const GROUP_INDEX_PAGE_SIZE: u64 = 100; // group ids per discovery index page
+pub const MAX_INDEX_PAGES_SCANNED: u64 = 10; // discovery index pages read per listing call
 
 /// Tracks a refund request initiated by a member.
 #[contracttype]
@@ -342,6 +589,96 @@ pub struct MemberPenaltyRecord {
     pub on_time_count: u32,
     pub total_penalties: i128,
     pub reliability_score: u32,
+    pub default_count: u32,
+}
+
+/// Outcome of pulling auto-debit contributions for a cycle.
+///
+/// Members who already contributed are left out.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AutoDebitReport {
+    /// The cycle collected for.
+    pub cycle: u32,
+    /// Members whose contribution was pulled.
+    pub collected: Vec<Address>,
+    /// Members whose allowance did not cover the amount due.
+    pub insufficient_allowance: Vec<Address>,
+    /// Members whose balance did not cover the amount due.
+    pub insufficient_balance: Vec<Address>,
+}
+
+/// Contributions a member has paid ahead for future cycles.
+///
+/// One cycle is applied each time the group advances to a new cycle; whatever
+/// is left is refunded when the group completes or is cancelled.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Prepayment {
+    /// Future cycles still covered.
+    pub cycles: u32,
+    /// Funds held for those cycles.
+    pub amount: i128,
+}
+
+/// Which groups [`list_groups`](crate::contract::AjoContract::list_groups)
+/// returns.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum GroupFilter {
+    /// Groups in the given state; completed groups are listed as `Complete`.
+    State(GroupState),
+    /// Groups contributing in the given token.
+    Token(Address),
+    /// Groups still taking members that have seats left.
+    OpenSeats,
+}
+
+/// Compact view of a group for browsing.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupSummary {
+    pub id: u64,
+    pub token_address: Address,
+    pub contribution_amount: i128,
+    /// Seats taken, counting each share held as a seat.
+    pub seats_taken: u32,
+    pub max_members: u32,
+    pub cycle_duration: u64,
+    pub state: GroupState,
+    /// The group's metadata name, empty if it has none.
+    pub name: soroban_sdk::String,
+}
+
+/// One call's worth of [`list_groups`](crate::contract::AjoContract::list_groups) results.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupPage {
+    pub groups: Vec<GroupSummary>,
+    /// Pass as `cursor` to carry on listing; `0` once every group has been checked.
+    pub next_cursor: u64,
+}
+
+/// One of a member's groups, from that member's point of view.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PortfolioEntry {
+    pub group_id: u64,
+    /// Completed groups are reported as `Complete`.
+    pub state: GroupState,
+    pub current_cycle: u32,
+    /// Still owed for the current cycle; `0` once paid or while the group
+    /// is not running.
+    pub amount_due: i128,
+    /// Deadline for the current cycle's contribution; `0` while the group is
+    /// not running.
+    pub due_at: u64,
+    /// Whether the member has paid the current cycle in full.
+    pub paid_this_cycle: bool,
+    /// 1-based place in the payout rotation, or `0` if the group picks each
+    /// recipient when the cycle pays out or the member forfeited their slot.
+    pub payout_position: u32,
+    pub received_payout: bool,
 }
 
 /// Records a refund transaction.
@@ -377,14 +714,10 @@ pub enum RefundReason {
     EmergencyRefund = 2,
     /// Dispute resolution refund.
     DisputeRefund = 3,
+    /// Member left the group before the first payout.
+    MemberLeft = 4,
 }
 
-/// Voting period duration in seconds (7 days).
-pub const VOTING_PERIOD: u64 = 604_800;
-
-/// Minimum approval percentage required for refund (51%).
-pub const REFUND_APPROVAL_THRESHOLD: u32 = 51;
-
 /// Detailed record of a member's contribution for a specific cycle.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -398,6 +731,44 @@ pub struct ContributionRecord {
     pub penalty_amount: i128,
 }
 
+/// Records a member who failed to pay a cycle's contribution in full before
+/// the grace period ended.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DefaultRecord {
+    pub group_id: u64,
+    pub cycle: u32,
+    pub member: Address,
+    /// Amount that was still owed when the default was recorded.
+    pub amount_owed: i128,
+    /// Partial installments paid before defaulting (kept in the pot).
+    pub amount_paid: i128,
+    /// Unix timestamp when the default was recorded.
+    pub marked_at: u64,
+    /// Whether the member lost their future payout slot.
+    pub payout_forfeited: bool,
+    /// Collateral slashed into the pot towards `amount_owed`.
+    pub collateral_applied: i128,
+}
+
+/// Running total of the installments a member has paid towards one cycle.
+///
+/// Written on every payment (full or partial). The member's contribution
+/// flag for the cycle only flips once `amount_paid` reaches the required
+/// contribution amount.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PartialContribution {
+    pub group_id: u64,
+    pub cycle: u32,
+    pub member: Address,
+    /// Total paid so far this cycle, in stroops.
+    pub amount_paid: i128,
+    /// Number of separate payments received this cycle.
+    pub installments: u32,
+    /// Unix timestamp of the most recent payment.
+    pub last_paid_at: u64,
+}
 
 /// Records that a member has received their payout for a given cycle.
 #[contracttype]
@@ -418,6 +789,48 @@ pub struct InsuranceConfig {
     pub rate_bps: u32,
     /// Whether insurance is enabled for this group.
     pub is_enabled: bool,
+    /// How premiums are funded. Defaults to `OnTop`.
+    pub premium_mode: PremiumMode,
+    /// How each member's premium rate is set. Defaults to `Flat`.
+    pub pricing: PremiumPricing,
+    /// Lowest rate a risk-priced premium can fall to, in basis points.
+    pub floor_bps: u32,
+    /// Highest rate a risk-priced premium can rise to, in basis points.
+    pub cap_bps: u32,
+}
+
+/// How a member's insurance premium rate is set.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum PremiumPricing {
+    /// Every member pays `rate_bps`.
+    Flat = 0,
+    /// `rate_bps` is scaled by the member's risk factor, bounded by
+    /// `floor_bps` and `cap_bps`.
+    RiskBased = 1,
+}
+
+/// The premium a member paid for one cycle.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MemberPremium {
+    pub member: Address,
+    /// Rate applied to the member's contribution, in basis points.
+    pub rate_bps: u32,
+    /// Premium moved into the insurance reserve.
+    pub amount: i128,
+}
+
+/// How a group's insurance premiums are funded.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum PremiumMode {
+    /// Members pay the premium in addition to their contribution.
+    OnTop = 0,
+    /// The premium is taken out of the contribution, shrinking the pot.
+    DeductFromPot = 1,
 }
 
 /// Status of an insurance claim.
@@ -453,16 +866,97 @@ pub struct InsuranceClaim {
     pub created_at: u64,
 }
 
-/// Insurance fund balance tracking.
+/// Insurance fund balance tracking for one group and token.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct InsurancePool {
-    /// Total balance available in the insurance pool for a specific token.
+    /// Reserve currently available to cover claims.
     pub balance: i128,
     /// Total amount paid out from the pool.
     pub total_payouts: i128,
     /// Total amount of claims filed.
     pub pending_claims_count: u32,
+    /// Total premiums ever collected into the pool.
+    pub total_premiums: i128,
+}
+
+/// Funds the contract owes for one token across every group.
+///
+/// The contract's token balance must always cover
+/// `pots + insurance_reserves + collateral + keeper_reserves + prepaid - parked`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenLiabilities {
+    /// Contributions and penalties held for upcoming payouts or refunds.
+    pub pots: i128,
+    /// Insurance premiums held in group reserves.
+    pub insurance_reserves: i128,
+    /// Member collateral held in escrow.
+    pub collateral: i128,
+    /// Pot funds lent out to yield adapters rather than held by the contract.
+    pub parked: i128,
+    /// Funds set aside to pay keepers.
+    pub keeper_reserves: i128,
+    /// Contributions members have paid ahead for future cycles.
+    pub prepaid: i128,
+}
+
+/// What a call to `crank` did.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CrankReport {
+    /// Whether a refund vote past its deadline was closed.
+    pub refund_vote_closed: bool,
+    /// Disputes resolved after their voting deadline.
+    pub disputes_resolved: u32,
+    /// Insurance claims settled.
+    pub claims_settled: u32,
+    /// Members newly marked as defaulted.
+    pub defaults_marked: u32,
+    /// Whether the cycle's payout was executed.
+    pub payout_executed: bool,
+    /// Contribution reminders sent.
+    pub reminders_sent: u32,
+    /// Bounty paid to the caller.
+    pub bounty: i128,
+}
+
+/// Who receives the yield earned on a group's parked pot.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum YieldDistribution {
+    /// Added to the payout of the cycle the pot was parked for.
+    Recipient = 0,
+    /// Shared among members in proportion to what they contribute each cycle.
+    Members = 1,
+    /// Paid into the group's insurance reserve.
+    InsurancePool = 2,
+}
+
+/// A group's opt-in to parking its pot with a lending adapter.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct YieldConfig {
+    /// Admin-allowlisted adapter lending out the group's token.
+    pub adapter: Address,
+    /// Who receives the yield.
+    pub distribution: YieldDistribution,
+}
+
+/// A group's funds currently parked with its yield adapter.
+///
+/// The contract holds one position per adapter on behalf of every group;
+/// `units` is the group's claim on it and grows with the adapter's balance.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct YieldPosition {
+    /// Adapter the funds are parked with.
+    pub adapter: Address,
+    /// The group's units of the contract's position with the adapter.
+    pub units: i128,
+    /// Amount parked and not yet withdrawn.
+    pub principal: i128,
 }
 
 /// Classification of contribution reminders sent to members.
@@ -572,8 +1066,53 @@ pub struct MemberStats {
     pub total_contributions: u32,
     pub on_time_contributions: u32,
     pub late_contributions: u32,
+    pub defaulted_contributions: u32,
     pub total_amount_contributed: i128,
     pub achievements: Vec<MemberAchievement>,
+    /// Disputes resolved against the member with an action taken.
+    pub disputes_lost: u32,
+    /// Timestamp of the member's most recent late payment, default or lost
+    /// dispute (0 if none).
+    pub last_incident_at: u64,
+}
+
+/// How a member's insurance risk score was derived.
+///
+/// Scores run from 0 (riskiest) to 100 (safest):
+/// `score = base + on_time_points + completion_points - late_deduction - default_deduction
+///          - dispute_deduction - penalty_deduction - refund_deduction - recency_deduction`,
+/// clamped to 0–100.
+///
+/// Penalty and refund deductions come from the records of the groups the
+/// member currently belongs to.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RiskScoreBreakdown {
+    pub member: Address,
+    /// Share of contributions paid on time, in basis points (0 with no history).
+    pub on_time_ratio_bps: u32,
+    /// Points every member starts with.
+    pub base: u32,
+    /// Up to 60 points scaled by the on-time ratio (30 with no history).
+    pub on_time_points: u32,
+    /// 5 points per completed group, up to 20.
+    pub completion_points: u32,
+    /// 3 points per late contribution, up to 15.
+    pub late_deduction: u32,
+    /// 10 points per default, up to 40.
+    pub default_deduction: u32,
+    /// 10 points per dispute lost, up to 30.
+    pub dispute_deduction: u32,
+    /// Up to 10 points scaled by the member's lowest per-group reliability
+    /// score, so a poor record in one group is not diluted by the others.
+    pub penalty_deduction: u32,
+    /// 5 points per group that refunded the member after a refund vote or an
+    /// emergency refund, up to 15.
+    pub refund_deduction: u32,
+    /// 15 points if the last incident was within 30 days, 5 within 90 days.
+    pub recency_deduction: u32,
+    /// The final score.
+    pub score: u32,
 }
 
 // ── Group access control ──────────────────────────────────────────────────
@@ -601,6 +1140,8 @@ pub struct GroupInvitation {
     pub created_at: u64,
     pub expires_at: u64,
     pub accepted: bool,
+    /// Contribution per share the invitee pays once they join.
+    pub contribution_amount: i128,
 }
 
 /// A request from a member to join an approval-required group.
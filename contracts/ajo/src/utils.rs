@@ -1,4 +1,4 @@
-use soroban_sdk::{Address, Env, Vec};
+use soroban_sdk::{Address, Bytes, BytesN, Env, Vec};
 
 use crate::types::{Group, GroupMilestone, GroupTemplate, MemberAchievement, PayoutOrder, PayoutOrderingStrategy, TemplateConfig};
 use crate::errors::AjoError;
@@ -19,9 +19,120 @@ pub fn is_member(members: &Vec<Address>, address: &Address) -> bool {
     members.iter().any(|m| m == *address)
 }
 
+/// Checks that `member` can be added to `group` right now.
+///
+/// # Errors
+/// * `GroupComplete` - If the group has completed all cycles
+/// * `GroupCancelled` - If the group has been cancelled
+/// * `InvalidState` - If the group is forming and its deadline has passed, or if the
+///   group had a forming phase, has started and does not allow late joining
+/// * `AlreadyMember` - If the address is already a member
+/// * `MaxMembersExceeded` - If the members' shares already fill the group
+pub fn validate_can_join(env: &Env, group: &Group, member: &Address) -> Result<(), AjoError> {
+    if group.is_complete {
+        return Err(AjoError::GroupComplete);
+    }
+    if group.state == crate::types::GroupState::Cancelled {
+        return Err(AjoError::GroupCancelled);
+    }
+    if group.state == crate::types::GroupState::Forming {
+        if get_current_timestamp(env) > group.formation_deadline {
+            return Err(AjoError::InvalidState);
+        }
+    } else if group.min_members > 0 && !group.allow_late_join {
+        return Err(AjoError::InvalidState);
+    }
+    if is_member(&group.members, member) {
+        return Err(AjoError::AlreadyMember);
+    }
+    if total_shares(env, group) >= group.max_members {
+        return Err(AjoError::MaxMembersExceeded);
+    }
+    Ok(())
+}
+
+/// Loads `member`'s invitation to a group and checks it can still be used.
+///
+/// # Errors
+/// * `Unauthorized` - If no invitation exists, or if the invitation has expired
+/// * `AlreadyMember` - If the invitation was already used
+pub fn validate_invitation(
+    env: &Env,
+    group_id: u64,
+    member: &Address,
+) -> Result<crate::types::GroupInvitation, AjoError> {
+    let invitation = crate::storage::get_invitation(env, group_id, member)
+        .ok_or(AjoError::Unauthorized)?;
+    if get_current_timestamp(env) > invitation.expires_at {
+        return Err(AjoError::Unauthorized);
+    }
+    if invitation.accepted {
+        return Err(AjoError::AlreadyMember);
+    }
+    Ok(invitation)
+}
+
+/// Adds `member` to the group, persists it, emits the join event and
+/// updates the member's stats. Once the group is full, a forming group is
+/// started and the payout schedule is fixed if the group has a schedule
+/// rule. A member joining a group whose schedule is already fixed is drawn
+/// into it again before the first payout, or added to the end afterwards.
+/// Callers must run [`validate_can_join`] first.
+pub fn admit_member(env: &Env, group: &mut Group, member: &Address) {
+    group.members.push_back(member.clone());
+    let full = total_shares(env, group) >= group.max_members;
+    let starting = full && group.state == crate::types::GroupState::Forming;
+    if starting {
+        start_cycles(env, group);
+    }
+    crate::storage::store_group(env, group.id, group);
+
+    if !starting && group.schedule_rule != crate::types::ScheduleRule::Unlocked {
+        let locked = crate::storage::get_payout_schedule(env, group.id);
+        if group.payout_index == 0 && (full || locked.is_some()) {
+            lock_payout_schedule(env, group);
+        } else if let Some(mut schedule) = locked {
+            schedule.recipients.push_back(member.clone());
+            crate::storage::store_payout_schedule(env, &schedule);
+        }
+    }
+
+    crate::events::emit_member_joined(env, group.id, member);
+
+    let mut stats = crate::storage::get_member_stats(env, member)
+        .unwrap_or_else(|| default_member_stats(env, member));
+    stats.total_groups_joined += 1;
+    crate::storage::store_member_stats(env, member, &stats);
+}
+
+/// Moves a forming group into its first cycle, starting the cycle clock now,
+/// and fixes the payout schedule if the group has a schedule rule. Callers
+/// are responsible for storing the group.
+pub fn start_cycles(env: &Env, group: &mut Group) {
+    let now = get_current_timestamp(env);
+    group.state = crate::types::GroupState::Active;
+    group.current_cycle = 1;
+    group.cycle_start_time = now;
+    crate::events::emit_group_started(env, group.id, group.members.len(), now);
+
+    if group.schedule_rule != crate::types::ScheduleRule::Unlocked {
+        lock_payout_schedule(env, group);
+    }
+}
+
+/// Drops `requester` from the group's pending join request list.
+pub fn remove_pending_join_request(env: &Env, group_id: u64, requester: &Address) {
+    let mut requesters = crate::storage::get_join_request_list(env, group_id);
+    if let Some(index) = requesters.first_index_of(requester) {
+        requesters.remove(index);
+        crate::storage::store_join_request_list(env, group_id, &requesters);
+    }
+}
+
 /// Returns `true` if every member of the group has contributed in the current cycle.
 ///
 /// Iterates over all members and short-circuits on the first missing contribution.
+/// Partial installments only count once they add up to the full amount.
 /// This is called by [`execute_payout`](crate::contract::AjoContract::execute_payout)
 /// to gate payout execution — a payout cannot proceed until this returns `true`.
 ///
@@ -33,28 +144,406 @@ pub fn is_member(members: &Vec<Address>, address: &Address) -> bool {
 /// `true` if all members have contributed, `false` otherwise
 #[inline]
 pub fn all_members_contributed(env: &Env, group: &Group) -> bool {
-    let group_id = group.id;
     let cycle = group.current_cycle;
-    
+
     group.members.iter().all(|member| {
-        crate::storage::has_contributed(env, group_id, cycle, &member)
+        has_paid_in_full(env, group, cycle, &member)
     })
 }
 
+/// Returns the amount actually collected for the current cycle.
+///
+/// Members who paid in full count for their whole contribution; members
+/// recorded as defaulted count for whatever installments they paid.
+///
+/// # Errors
+/// * `IncompleteContributions` - If a member has neither paid in full nor
+///   been recorded as defaulted
+pub fn cycle_collected_amount(env: &Env, group: &Group) -> Result<i128, AjoError> {
+    let cycle = group.current_cycle;
+    let mut collected = 0i128;
+    for member in group.members.iter() {
+        if crate::storage::has_contributed(env, group.id, cycle, &member) {
+            collected += member_contribution(env, group, &member);
+        } else if let Some(record) = crate::storage::get_default_record(env, group.id, cycle, &member) {
+            collected += record.amount_paid + record.collateral_applied;
+        } else {
+            return Err(AjoError::IncompleteContributions);
+        }
+    }
+    Ok(collected)
+}
+
+/// Number of payouts the group makes before completing.
+///
+/// Every member has one slot per share, except those who forfeited theirs
+/// by defaulting.
+pub fn payout_slot_count(env: &Env, group: &Group) -> u32 {
+    let forfeited = crate::storage::get_forfeited_members(env, group.id);
+    group
+        .members
+        .iter()
+        .filter(|member| !is_member(&forfeited, member))
+        .map(|member| crate::storage::get_member_shares(env, group.id, &member))
+        .sum()
+}
+
+/// Total number of shares held by the group's members.
+pub fn total_shares(env: &Env, group: &Group) -> u32 {
+    group
+        .members
+        .iter()
+        .map(|member| crate::storage::get_member_shares(env, group.id, &member))
+        .sum()
+}
+
+/// Compact view of `group` for browsing.
+pub fn group_summary(env: &Env, group: &Group) -> crate::types::GroupSummary {
+    let name = crate::storage::get_group_metadata(env, group.id)
+        .map(|metadata| metadata.name)
+        .unwrap_or_else(|| soroban_sdk::String::from_str(env, ""));
+    crate::types::GroupSummary {
+        id: group.id,
+        token_address: group.token_address.clone(),
+        contribution_amount: group.contribution_amount,
+        seats_taken: total_shares(env, group),
+        max_members: group.max_members,
+        cycle_duration: group.cycle_duration,
+        state: crate::storage::listed_state(group),
+        name,
+    }
+}
+
+/// What `member` owes each cycle: their contribution tier once per share.
+pub fn member_contribution(env: &Env, group: &Group, member: &Address) -> i128 {
+    member_tier(env, group, member) * crate::storage::get_member_shares(env, group.id, member) as i128
+}
+
+/// Returns `true` while `member` has received fewer payouts than they hold shares.
+pub fn has_payout_remaining(env: &Env, group_id: u64, member: &Address) -> bool {
+    crate::storage::get_payouts_received(env, group_id, member)
+        < crate::storage::get_member_shares(env, group_id, member)
+}
+
+/// Returns how much `member` has paid towards `cycle`, including partial installments.
+///
+/// Falls back to the member's full contribution when only the contribution flag
+/// exists (e.g. contributions recorded through `contribute_with_token`).
+pub fn get_amount_paid(env: &Env, group: &Group, cycle: u32, member: &Address) -> i128 {
+    match crate::storage::get_partial_contribution(env, group.id, cycle, member) {
+        Some(record) => record.amount_paid,
+        None if crate::storage::has_contributed(env, group.id, cycle, member) => {
+            member_contribution(env, group, member)
+        }
+        None => 0,
+    }
+}
+
+/// Returns `true` once `member` has paid the full contribution for `cycle`.
+///
+/// Checks the contribution flag first so the common case costs a single
+/// storage read; the running total is only consulted when the flag is unset.
+pub fn has_paid_in_full(env: &Env, group: &Group, cycle: u32, member: &Address) -> bool {
+    crate::storage::has_contributed(env, group.id, cycle, member)
+        || crate::storage::get_partial_contribution(env, group.id, cycle, member)
+            .map(|record| record.amount_paid >= member_contribution(env, group, member))
+            .unwrap_or(false)
+}
+
+/// Returns the member's running total for `cycle`, or an empty record if
+/// they have paid nothing towards it.
+pub fn partial_contribution(
+    env: &Env,
+    group_id: u64,
+    cycle: u32,
+    member: &Address,
+) -> crate::types::PartialContribution {
+    crate::storage::get_partial_contribution(env, group_id, cycle, member).unwrap_or(
+        crate::types::PartialContribution {
+            group_id,
+            cycle,
+            member: member.clone(),
+            amount_paid: 0,
+            installments: 0,
+            last_paid_at: 0,
+        },
+    )
+}
+
+/// Adds `amount` to the member's running total for the current cycle and
+/// returns the updated record.
+pub fn record_payment(
+    env: &Env,
+    group: &Group,
+    member: &Address,
+    amount: i128,
+) -> crate::types::PartialContribution {
+    let cycle = group.current_cycle;
+    let mut record = partial_contribution(env, group.id, cycle, member);
+    record.amount_paid += amount;
+    record.installments += 1;
+    record.last_paid_at = get_current_timestamp(env);
+    crate::storage::store_partial_contribution(env, group.id, cycle, member, &record);
+    record
+}
+
+/// Refunds every member's payments towards the current cycle.
+///
+/// Premiums deducted from the cycle's pot are released from the insurance
+/// reserve first so contributions can be returned in full, and anything
+/// parked with a yield adapter is withdrawn. A loss at the adapter is made up
+/// from the insurance reserve where possible and any remainder is borne by
+/// members in proportion to their payments. Each refund is recorded and
+/// emitted with `reason`; yield earned is then shared out as there is no
+/// payout recipient.
+///
+/// # Returns
+/// The total amount refunded
+///
+/// # Errors
+/// * `TransferFailed` - If any refund transfer fails
+pub fn refund_cycle_contributions(
+    env: &Env,
+    group: &Group,
+    reason: crate::types::RefundReason,
+) -> Result<i128, AjoError> {
+    crate::insurance::release_cycle_premiums(env, group);
+    let yield_earned = crate::yield_adapter::recall(env, group);
+    let shortfall = -yield_earned.min(0);
+    let shortfall = shortfall - crate::insurance::cover_shortfall(env, group, shortfall);
+
+    let contract_address = env.current_contract_address();
+    let now = get_current_timestamp(env);
+    let mut total_refunded = 0i128;
+
+    let mut total_paid = 0i128;
+    for member in group.members.iter() {
+        total_paid += get_amount_paid(env, group, group.current_cycle, &member);
+    }
+
+    for member in group.members.iter() {
+        let mut refund_amount = get_amount_paid(env, group, group.current_cycle, &member);
+        if shortfall > 0 && total_paid > 0 {
+            // Round the share of the loss up so refunds never exceed the pot
+            refund_amount -= (shortfall * refund_amount + total_paid - 1) / total_paid;
+        }
+        if refund_amount > 0 {
+            total_refunded += refund_amount;
+
+            // Transfer tokens back to member
+            crate::token::transfer_token(
+                env,
+                &group.token_address,
+                &contract_address,
+                &member,
+                refund_amount,
+            )?;
+
+            // Store refund record
+            let refund_record = crate::types::RefundRecord {
+                group_id: group.id,
+                member: member.clone(),
+                amount: refund_amount,
+                timestamp: now,
+                reason,
+            };
+            crate::storage::store_refund_record(env, group.id, &member, &refund_record);
+
+            // Emit refund event
+            crate::events::emit_refund_processed(env, group.id, &member, refund_amount, reason as u32);
+        }
+    }
+
+    crate::insurance::adjust_pot_liability(env, &group.token_address, -total_refunded);
+    crate::yield_adapter::distribute(env, group, None, yield_earned)?;
+
+    Ok(total_refunded)
+}
+
+/// Marks the member's contribution for the current cycle as complete.
+///
+/// Sets the contribution flag, stores the contribution record, deposits the
+/// insurance premium, applies any late penalty to the cycle penalty pool and
+/// updates penalty records, member stats and achievements. Callers are
+/// responsible for transferring the tokens (including `penalty`) beforehand.
+///
+/// # Returns
+/// The premium deducted from the pot (`0` unless premiums come out of it)
+pub fn complete_contribution(
+    env: &Env,
+    group: &Group,
+    member: &Address,
+    is_late: bool,
+    penalty: i128,
+) -> i128 {
+    let group_id = group.id;
+    let current_cycle = group.current_cycle;
+    let contribution_amount = member_contribution(env, group, member);
+    let now = get_current_timestamp(env);
+
+    // Insurance logic: move the premium into the group's reserve. Priced
+    // before the member's history below is updated.
+    let deducted =
+        crate::insurance::collect_premium(env, group, &group.token_address, member, contribution_amount);
+
+    crate::storage::store_contribution(env, group_id, current_cycle, member, true);
+    crate::storage::store_contribution_detail(
+        env,
+        group_id,
+        current_cycle,
+        member,
+        &crate::types::ContributionRecord {
+            group_id,
+            cycle: current_cycle,
+            member: member.clone(),
+            amount: contribution_amount,
+            timestamp: now,
+            is_late,
+            penalty_amount: penalty,
+        },
+    );
+
+    // Penalty bookkeeping
+    let mut penalty_record = crate::storage::get_member_penalty(env, group_id, member)
+        .unwrap_or_else(|| default_penalty_record(group_id, member));
+    if is_late {
+        penalty_record.late_count += 1;
+        penalty_record.total_penalties += penalty;
+        if penalty > 0 {
+            crate::storage::add_to_penalty_pool(env, group_id, current_cycle, penalty);
+        }
+        crate::events::emit_late_contribution(
+            env,
+            group_id,
+            member,
+            current_cycle,
+            contribution_amount,
+            penalty,
+        );
+    } else {
+        penalty_record.on_time_count += 1;
+    }
+    penalty_record.reliability_score =
+        calculate_reliability_score(penalty_record.on_time_count, missed_count(&penalty_record));
+    crate::storage::store_member_penalty(env, group_id, member, &penalty_record);
+
+    crate::events::emit_contribution_made(env, group_id, member, current_cycle, contribution_amount);
+
+    // Update member stats
+    let mut stats = crate::storage::get_member_stats(env, member)
+        .unwrap_or_else(|| default_member_stats(env, member));
+    stats.total_contributions += 1;
+    if is_late {
+        stats.late_contributions += 1;
+        stats.last_incident_at = get_current_timestamp(env);
+    } else {
+        stats.on_time_contributions += 1;
+    }
+    stats.total_amount_contributed += contribution_amount;
+    crate::storage::store_member_stats(env, member, &stats);
+
+    // Check and record member achievements
+    let achievements = check_member_achievements(env, member, &stats);
+    for achievement in achievements.iter() {
+        let record = crate::types::AchievementRecord {
+            member: member.clone(),
+            achievement,
+            earned_at: now,
+            group_id,
+        };
+        crate::storage::add_member_achievement(env, member, &record);
+        crate::events::emit_achievement_earned(env, member, record.achievement as u32, group_id);
+    }
+
+    deducted
+}
+
+/// Collects a member's outstanding contribution for the current cycle.
+///
+/// Only what installments have not covered is due, less any auction credit,
+/// plus the late penalty and any upfront insurance premium. With `debit`
+/// set, the tokens are pulled from the allowance the member granted the
+/// contract instead of transferred under the member's signature. Allowance
+/// and balance are checked before anything is recorded, so a failure
+/// leaves no trace. Callers are responsible for the membership and
+/// already-contributed checks.
+///
+/// # Errors
+/// * `GracePeriodExpired` - If the grace period has ended
+/// * `InsufficientAllowance` - If `debit` is set and the allowance does not cover the amount due
+/// * `InsufficientBalance` - If the member doesn't have enough tokens
+/// * `TransferFailed` - If the token transfer fails
+pub fn collect_contribution(env: &Env, group: &Group, member: &Address, debit: bool) -> Result<(), AjoError> {
+    // Members holding several shares owe the contribution once per share
+    let contribution = member_contribution(env, group, member);
+
+    // Late contributions within the grace period owe a penalty on top
+    let (is_late, penalty) = assess_late_penalty(group, contribution, get_current_timestamp(env))?;
+
+    // Only the outstanding balance is due if installments were already paid
+    let partial = crate::storage::get_partial_contribution(env, group.id, group.current_cycle, member);
+    let amount_due = contribution - partial.as_ref().map(|record| record.amount_paid).unwrap_or(0);
+    let premium = crate::insurance::upfront_premium(env, group, member, contribution);
+    // Auction discount shares credited to the member cover part of the pot
+    let credit = crate::storage::get_auction_credit(env, group.id, member).min(amount_due);
+    let total_due = amount_due - credit + penalty + premium;
+
+    // Get contract address for token transfer
+    let contract_address = env.current_contract_address();
+
+    // Check allowance and member balance before transfer
+    if debit {
+        crate::token::check_allowance(env, &group.token_address, member, &contract_address, total_due)?;
+    }
+    crate::token::check_balance(env, &group.token_address, member, total_due)?;
+    use_auction_credit(env, group.id, member, amount_due);
+
+    // Transfer tokens (plus any late penalty and premium) from member to contract
+    if debit {
+        crate::token::transfer_token_from(
+            env,
+            &group.token_address,
+            &contract_address,
+            member,
+            &contract_address,
+            total_due,
+        )?;
+    } else {
+        crate::token::transfer_token(env, &group.token_address, member, &contract_address, total_due)?;
+    }
+    crate::insurance::adjust_pot_liability(env, &group.token_address, amount_due - credit + penalty);
+
+    // Record contribution; the running total is only kept for installments
+    if partial.is_some() {
+        record_payment(env, group, member, amount_due);
+    }
+    let deducted = complete_contribution(env, group, member, is_late, penalty);
+
+    // Park the pot's share of the payment with the group's yield adapter
+    crate::yield_adapter::park(env, group, amount_due - credit + penalty - deducted)
+}
+
 /// Calculates the total payout amount for a single cycle.
 ///
-/// The payout equals each member's fixed contribution multiplied by the total
-/// number of members. This ensures the recipient receives the full pool of contributions.
+/// The payout equals every member's contribution for the cycle: their tier
+/// once per share held. When all members share the group's tier this is the
+/// fixed contribution multiplied by the total number of shares. This ensures
+/// the recipient receives the full pool of contributions; see
+/// [`apply_tier_adjustment`] for members on other tiers.
 ///
 /// # Arguments
+/// * `env` - The contract environment (needed for storage reads)
 /// * `group` - The group whose payout is being calculated
 ///
 /// # Returns
-/// Total payout in stroops (`contribution_amount × member_count`)
+/// Total payout in stroops (`Σ tier × shares` over the members)
 #[inline]
-pub fn calculate_payout_amount(group: &Group) -> i128 {
-    let member_count = group.members.len() as i128;
-    group.contribution_amount * member_count
+pub fn calculate_payout_amount(env: &Env, group: &Group) -> i128 {
+    group
+        .members
+        .iter()
+        .map(|member| member_contribution(env, group, &member))
+        .sum()
 }
 
 /// Returns the current ledger timestamp in seconds since Unix epoch.
@@ -164,6 +653,70 @@ pub fn is_within_grace_period(group: &crate::types::Group, current_time: u64) ->
     current_time > cycle_end && current_time <= grace_end
 }
 
+/// How long before a cycle ends auto-debit contributions can be collected.
+pub const AUTO_DEBIT_WINDOW: u64 = 86_400;
+
+/// Returns `true` from [`AUTO_DEBIT_WINDOW`] before the cycle end until the
+/// grace period end.
+pub fn is_within_collection_window(group: &crate::types::Group, current_time: u64) -> bool {
+    let cycle_end = group.cycle_start_time + group.cycle_duration;
+    current_time + AUTO_DEBIT_WINDOW >= cycle_end && current_time <= get_grace_period_end(group)
+}
+
+/// Works out whether a contribution made at `current_time` is late and the
+/// penalty owed on top of the regular contribution.
+///
+/// Contributions after the cycle end but within the grace period are late and
+/// owe `penalty_rate` percent of `amount`, the member's contribution.
+///
+/// # Returns
+/// `(is_late, penalty)` — `penalty` is `0` for on-time contributions
+///
+/// # Errors
+/// * `GracePeriodExpired` - If `current_time` is past the grace period end
+pub fn assess_late_penalty(group: &Group, amount: i128, current_time: u64) -> Result<(bool, i128), AjoError> {
+    if current_time > get_grace_period_end(group) {
+        return Err(AjoError::GracePeriodExpired);
+    }
+    if is_within_grace_period(group, current_time) {
+        let penalty = amount * (group.penalty_rate as i128) / 100;
+        Ok((true, penalty))
+    } else {
+        Ok((false, 0))
+    }
+}
+
+/// Returns the percentage of cycles paid on time (`100` if none yet).
+///
+/// `missed_count` covers both late contributions and defaults.
+#[inline]
+pub fn calculate_reliability_score(on_time_count: u32, missed_count: u32) -> u32 {
+    let total = on_time_count + missed_count;
+    if total == 0 {
+        return 100;
+    }
+    on_time_count * 100 / total
+}
+
+/// Number of cycles a member paid late or not at all.
+#[inline]
+pub fn missed_count(record: &crate::types::MemberPenaltyRecord) -> u32 {
+    record.late_count + record.default_count
+}
+
+/// Default penalty record used when a member has no history in the group.
+pub fn default_penalty_record(group_id: u64, member: &Address) -> crate::types::MemberPenaltyRecord {
+    crate::types::MemberPenaltyRecord {
+        member: member.clone(),
+        group_id,
+        late_count: 0,
+        on_time_count: 0,
+        total_penalties: 0,
+        reliability_score: 100,
+        default_count: 0,
+    }
+}
+
 // ── Dynamic payout ordering ───────────────────────────────────────────────────
 
 /// Determines and records the next payout recipient according to the group's
@@ -183,7 +736,7 @@ pub fn is_within_grace_period(group: &crate::types::Group, current_time: u64) ->
 /// * [`AjoError::NoMembers`] — used only for Sequential when `payout_index`
 ///   points beyond the members list (should never happen in practice).
 pub fn determine_next_recipient(env: &Env, group: &Group) -> Result<Address, AjoError> {
-    let recipient = preview_next_recipient(env, group)?;
+    let (recipient, seed) = select_next_recipient(env, group)?;
 
     // Persist the determined order for audit / transparency
     let order = PayoutOrder {
@@ -192,6 +745,7 @@ pub fn determine_next_recipient(env: &Env, group: &Group) -> Result<Address, Ajo
         recipient: recipient.clone(),
         selection_method: group.payout_strategy,
         determined_at: get_current_timestamp(env),
+        seed,
     };
     crate::storage::store_payout_order(env, group.id, group.current_cycle, &order);
 
@@ -200,46 +754,388 @@ pub fn determine_next_recipient(env: &Env, group: &Group) -> Result<Address, Ajo
 
 /// Computes the next recipient using the group's payout strategy without
 /// writing any storage side effects.
+///
+/// For a `Random` group in which nobody revealed a secret the draw uses the
+/// ledger PRNG, so the preview may differ from the eventual payout.
 pub fn preview_next_recipient(env: &Env, group: &Group) -> Result<Address, AjoError> {
-    match group.payout_strategy {
-        PayoutOrderingStrategy::Sequential => select_sequential(group),
-        PayoutOrderingStrategy::Random => select_random(env, group),
-        PayoutOrderingStrategy::VotingBased | PayoutOrderingStrategy::NeedBased => {
-            select_by_votes(env, group)
-        }
-        PayoutOrderingStrategy::ContributionBased => select_by_contribution(env, group),
+    select_next_recipient(env, group).map(|(recipient, _)| recipient)
+}
+
+/// The next recipient, along with the seed of a random draw (zeroes for
+/// every other strategy).
+fn select_next_recipient(env: &Env, group: &Group) -> Result<(Address, BytesN<32>), AjoError> {
+    let no_seed = BytesN::from_array(env, &[0u8; 32]);
+    // A fixed schedule overrides the strategy
+    if crate::storage::get_payout_schedule(env, group.id).is_some() {
+        return Ok((select_sequential(env, group)?, no_seed));
     }
+    let recipient = match group.payout_strategy {
+        PayoutOrderingStrategy::Sequential => select_sequential(env, group)?,
+        PayoutOrderingStrategy::Random => {
+            let seed = random_seed(env, group);
+            return Ok((select_random(env, group, &seed)?, seed));
+        }
+        PayoutOrderingStrategy::VotingBased => select_by_votes(env, group)?,
+        PayoutOrderingStrategy::NeedBased => select_by_need(env, group)?,
+        PayoutOrderingStrategy::ContributionBased => select_by_contribution(env, group)?,
+        PayoutOrderingStrategy::Auction => select_by_auction(env, group)?,
+    };
+    Ok((recipient, no_seed))
 }
 
-/// Selects the next recipient by join order (current `payout_index`).
-fn select_sequential(group: &Group) -> Result<Address, AjoError> {
-    group
-        .members
-        .get(group.payout_index)
+/// Selects the next recipient by rotation order (current `payout_index`),
+/// skipping members who forfeited their slots.
+fn select_sequential(env: &Env, group: &Group) -> Result<Address, AjoError> {
+    let rotation = payout_rotation(env, group);
+    let forfeited = crate::storage::get_forfeited_members(env, group.id);
+    if forfeited.is_empty() {
+        return rotation.get(group.payout_index).ok_or(AjoError::NoMembers);
+    }
+
+    // Forfeits only ever remove unpaid slots, so the members already paid
+    // are exactly the first `payout_index` non-forfeited members.
+    rotation
+        .iter()
+        .filter(|member| !is_member(&forfeited, member))
+        .nth(group.payout_index as usize)
         .ok_or(AjoError::NoMembers)
 }
 
-/// Selects a random eligible member using ledger sequence and timestamp as
-/// entropy.  This is *verifiable* but not unpredictable by validators —
-/// acceptable for informal savings groups.
+/// The order members are paid in: the fixed schedule if one was drawn,
+/// otherwise [`share_rotation`].
+fn payout_rotation(env: &Env, group: &Group) -> Vec<Address> {
+    crate::storage::get_payout_schedule(env, group.id)
+        .map(|schedule| schedule.recipients)
+        .unwrap_or_else(|| share_rotation(env, group))
+}
+
+/// Join order with each member repeated once per share, so a member holding
+/// several shares is paid in consecutive cycles.
+fn share_rotation(env: &Env, group: &Group) -> Vec<Address> {
+    let mut rotation = Vec::new(env);
+    for member in group.members.iter() {
+        for _ in 0..crate::storage::get_member_shares(env, group.id, &member) {
+            rotation.push_back(member.clone());
+        }
+    }
+    rotation
+}
+
+/// Fixes the group's whole payout rotation using its schedule rule and
+/// stores it. Members appear once per share.
+///
+/// `Shuffled` draws a seed from the host PRNG and runs a Fisher–Yates
+/// shuffle over join order: for `i` from the last position down to 1, `i`
+/// is swapped with `j = u64_be(sha256(seed ‖ u32_be(i))[..8]) % (i + 1)`.
+/// The seed is stored with the schedule so anyone can repeat the shuffle.
+///
+/// The shuffle is only as fair as the host PRNG. Its seed comes from the
+/// network, not from the members, so a validator that can influence the
+/// PRNG, or whoever submits the transaction that fills the group and picks
+/// its ledger, can bias the rotation. Groups that cannot accept that trust
+/// should use `Random` ordering, which draws from the members' own secrets.
+/// `MostReliableFirst` orders by [`get_member_risk_score`](crate::insurance::get_member_risk_score),
+/// highest first, keeping join order on ties.
+pub fn lock_payout_schedule(env: &Env, group: &Group) {
+    let mut recipients = share_rotation(env, group);
+    let mut seed = BytesN::from_array(env, &[0u8; 32]);
+
+    match group.schedule_rule {
+        crate::types::ScheduleRule::Unlocked | crate::types::ScheduleRule::JoinOrder => {}
+        crate::types::ScheduleRule::Shuffled => {
+            seed = env.prng().gen::<BytesN<32>>();
+            let mut i = recipients.len();
+            while i > 1 {
+                i -= 1;
+                let mut data = Bytes::from_array(env, &seed.to_array());
+                data.extend_from_array(&i.to_be_bytes());
+                let hash = env.crypto().sha256(&data).to_array();
+                let mut prefix = [0u8; 8];
+                prefix.copy_from_slice(&hash[..8]);
+                let j = (u64::from_be_bytes(prefix) % (i as u64 + 1)) as u32;
+                let a = recipients.get_unchecked(i);
+                let b = recipients.get_unchecked(j);
+                recipients.set(i, b);
+                recipients.set(j, a);
+            }
+        }
+        crate::types::ScheduleRule::MostReliableFirst => {
+            // Insertion sort keeps join order between equal scores
+            let mut sorted: Vec<Address> = Vec::new(env);
+            let mut scores: Vec<u32> = Vec::new(env);
+            for member in recipients.iter() {
+                let score = crate::insurance::get_member_risk_score(env, &member);
+                let mut index = scores.len();
+                while index > 0 && scores.get_unchecked(index - 1) < score {
+                    index -= 1;
+                }
+                sorted.insert(index, member);
+                scores.insert(index, score);
+            }
+            recipients = sorted;
+        }
+    }
+
+    let schedule = crate::types::PayoutSchedule {
+        group_id: group.id,
+        rule: group.schedule_rule,
+        recipients,
+        seed,
+        locked_at: get_current_timestamp(env),
+    };
+    crate::storage::store_payout_schedule(env, &schedule);
+    crate::events::emit_schedule_locked(env, group.id, group.schedule_rule as u32, &schedule.seed);
+}
+
+/// Lists every cycle of a fixed schedule with its recipient and date.
+///
+/// Past cycles carry the date they paid out. Upcoming cycles are projected
+/// assuming each one pays out as soon as its grace period ends and the next
+/// cycle starts straight away.
+pub fn scheduled_payouts(
+    env: &Env,
+    group: &Group,
+    schedule: &crate::types::PayoutSchedule,
+) -> Vec<crate::types::ScheduledPayout> {
+    let forfeited = crate::storage::get_forfeited_members(env, group.id);
+    let cycle_length = group.cycle_duration + group.grace_period;
+
+    let mut payouts = Vec::new(env);
+    let mut seen: Vec<Address> = Vec::new(env);
+    let mut cycle = 0u32;
+    for recipient in schedule.recipients.iter() {
+        if is_member(&forfeited, &recipient) {
+            continue;
+        }
+        cycle += 1;
+        // A member's n-th slot is paid once they have received n payouts
+        let slot = seen.iter().filter(|member| *member == recipient).count() as u32;
+        seen.push_back(recipient.clone());
+        let projected_date = if cycle < group.current_cycle || group.is_complete {
+            crate::storage::get_payout_order(env, group.id, cycle)
+                .map(|order| order.determined_at)
+                .unwrap_or(0)
+        } else {
+            group.cycle_start_time + (cycle - group.current_cycle + 1) as u64 * cycle_length
+        };
+        payouts.push_back(crate::types::ScheduledPayout {
+            cycle,
+            paid: slot < crate::storage::get_payouts_received(env, group.id, &recipient),
+            recipient,
+            projected_date,
+        });
+    }
+    payouts
+}
+
+/// Projects the recipient of an upcoming `cycle` under sequential ordering
+/// or a fixed schedule.
+///
+/// Returns `None` for past cycles and cycles beyond the end of the rotation.
+pub fn projected_sequential_recipient(env: &Env, group: &Group, cycle: u32) -> Option<Address> {
+    if cycle < group.current_cycle {
+        return None;
+    }
+    let forfeited = crate::storage::get_forfeited_members(env, group.id);
+    let position = group.payout_index + (cycle - group.current_cycle);
+    payout_rotation(env, group)
+        .iter()
+        .filter(|member| !is_member(&forfeited, member))
+        .nth(position as usize)
+}
+
+/// `member`'s 1-based place in the payout rotation under sequential
+/// ordering or a fixed schedule, counting only slots not forfeited.
+///
+/// Returns `0` for other strategies, which pick each recipient when the
+/// cycle pays out, and for members who forfeited their slots.
+pub fn payout_position(env: &Env, group: &Group, member: &Address) -> u32 {
+    if group.payout_strategy != PayoutOrderingStrategy::Sequential
+        && crate::storage::get_payout_schedule(env, group.id).is_none()
+    {
+        return 0;
+    }
+    let forfeited = crate::storage::get_forfeited_members(env, group.id);
+    if is_member(&forfeited, member) {
+        return 0;
+    }
+    payout_rotation(env, group)
+        .iter()
+        .filter(|slot| !is_member(&forfeited, slot))
+        .position(|slot| slot == *member)
+        .map(|index| index as u32 + 1)
+        .unwrap_or(0)
+}
+
+/// `member`'s view of `group` for their portfolio.
+pub fn portfolio_entry(env: &Env, group: &Group, member: &Address) -> crate::types::PortfolioEntry {
+    let state = crate::storage::listed_state(group);
+    let cycle = group.current_cycle;
+    let running = state == crate::types::GroupState::Active;
+    let paid = running && crate::storage::has_contributed(env, group.id, cycle, member);
+    let (amount_due, due_at) = if running && !paid {
+        (
+            member_contribution(env, group, member) - get_amount_paid(env, group, cycle, member),
+            group.cycle_start_time + group.cycle_duration,
+        )
+    } else {
+        (0, 0)
+    };
+
+    crate::types::PortfolioEntry {
+        group_id: group.id,
+        state,
+        current_cycle: cycle,
+        amount_due,
+        due_at,
+        paid_this_cycle: paid,
+        payout_position: payout_position(env, group, member),
+        received_payout: crate::storage::has_received_payout(env, group.id, member),
+    }
+}
+
+/// Checks that `proposer` and `counterparty` can exchange payout positions.
+///
+/// # Errors
+/// * `InvalidStrategy` - If the group neither uses sequential ordering
+///   nor has a fixed schedule
+/// * `NotMember` - If either address is not a member
+/// * `InvalidInput` - If both addresses are the same, hold different numbers of
+///   shares or either forfeited their slots
+/// * `AlreadyReceivedPayout` - If either member has already been paid
+pub fn validate_position_swap(
+    env: &Env,
+    group: &Group,
+    proposer: &Address,
+    counterparty: &Address,
+) -> Result<(), AjoError> {
+    if group.payout_strategy != PayoutOrderingStrategy::Sequential
+        && crate::storage::get_payout_schedule(env, group.id).is_none()
+    {
+        return Err(AjoError::InvalidStrategy);
+    }
+    if !is_member(&group.members, proposer) || !is_member(&group.members, counterparty) {
+        return Err(AjoError::NotMember);
+    }
+    if proposer == counterparty {
+        return Err(AjoError::InvalidInput);
+    }
+    let forfeited = crate::storage::get_forfeited_members(env, group.id);
+    if is_member(&forfeited, proposer) || is_member(&forfeited, counterparty) {
+        return Err(AjoError::InvalidInput);
+    }
+    // Exchanging blocks of different sizes would move everyone in between
+    if crate::storage::get_member_shares(env, group.id, proposer)
+        != crate::storage::get_member_shares(env, group.id, counterparty)
+    {
+        return Err(AjoError::InvalidInput);
+    }
+    if crate::storage::has_received_payout(env, group.id, proposer)
+        || crate::storage::has_received_payout(env, group.id, counterparty)
+    {
+        return Err(AjoError::AlreadyReceivedPayout);
+    }
+    Ok(())
+}
+
+/// Exchanges the positions of two members in the group's rotation, and in
+/// its fixed schedule if there is one, and stores the group.
+///
+/// Both members are unpaid, so the already-paid prefix of the rotation is
+/// left untouched.
+///
+/// # Errors
+/// See [`validate_position_swap`].
+pub fn swap_positions(
+    env: &Env,
+    group: &mut Group,
+    proposer: &Address,
+    counterparty: &Address,
+) -> Result<(), AjoError> {
+    validate_position_swap(env, group, proposer, counterparty)?;
+
+    let first = group.members.first_index_of(proposer).ok_or(AjoError::NotMember)?;
+    let second = group.members.first_index_of(counterparty).ok_or(AjoError::NotMember)?;
+    group.members.set(first, counterparty.clone());
+    group.members.set(second, proposer.clone());
+    crate::storage::store_group(env, group.id, group);
+
+    if let Some(mut schedule) = crate::storage::get_payout_schedule(env, group.id) {
+        // Every slot of each member changes hands
+        for (slot, recipient) in schedule.recipients.clone().iter().enumerate() {
+            if recipient == *proposer {
+                schedule.recipients.set(slot as u32, counterparty.clone());
+            } else if recipient == *counterparty {
+                schedule.recipients.set(slot as u32, proposer.clone());
+            }
+        }
+        crate::storage::store_payout_schedule(env, &schedule);
+    }
+    Ok(())
+}
+
+/// Seed for the current cycle's random draw.
+///
+/// `sha256(group_id ‖ cycle ‖ secret₁ ‖ secret₂ ‖ …)` over the revealed
+/// secrets, in commit order, with the ids big-endian. Reveals close when the
+/// grace period ends, before a payout can run, so the draw only ever sees
+/// secrets revealed inside the window.
+///
+/// With no reveals the group and cycle alone would make the draw known in
+/// advance, so 32 bytes from the ledger PRNG stand in for the secrets. That
+/// fallback trusts the validators, which can influence the PRNG, and the
+/// seed it produces cannot be recomputed from the commitments.
+fn random_seed(env: &Env, group: &Group) -> BytesN<32> {
+    let mut data = Bytes::from_array(env, &group.id.to_be_bytes());
+    data.extend_from_array(&group.current_cycle.to_be_bytes());
+    let mut revealed = false;
+    for commitment in crate::storage::get_random_commitments(env, group.id, group.current_cycle).iter() {
+        if commitment.revealed {
+            data.append(&commitment.secret.into());
+            revealed = true;
+        }
+    }
+    if !revealed {
+        data.extend_from_array(&env.prng().gen::<BytesN<32>>().to_array());
+    }
+    env.crypto().sha256(&data).to_bytes()
+}
+
+/// Selects a random eligible member from the members' commit-reveal seed.
 ///
-/// Entropy is mixed with a 64-bit multiplicative hash constant to improve
-/// distribution when the raw seed values are small.
-fn select_random(env: &Env, group: &Group) -> Result<Address, AjoError> {
+/// Members who committed but did not reveal are left out of the draw, so
+/// withholding a secret cannot improve a member's own odds; if that leaves
+/// nobody, every eligible member is drawn from. The first eight bytes of
+/// [`random_seed`] (big-endian) modulo the number of candidates pick the
+/// recipient.  Unless nobody revealed, the ledger is not used, so the caller
+/// of `execute_payout` cannot influence the result by choosing when to call it.
+fn select_random(env: &Env, group: &Group, seed: &BytesN<32>) -> Result<Address, AjoError> {
     let eligible = get_eligible_members(env, group)?;
-    let len = eligible.len() as u64;
 
-    // Combine ledger sequence and timestamp, then mix bits
-    let raw = (env.ledger().sequence() as u64)
-        .wrapping_add(env.ledger().timestamp())
-        .wrapping_mul(0x9e3779b97f4a7c15_u64); // Fibonacci-hashing constant
-    let index = (raw % len) as u32;
+    let commitments = crate::storage::get_random_commitments(env, group.id, group.current_cycle);
+    let mut candidates = Vec::new(env);
+    for member in eligible.iter() {
+        let withheld = commitments
+            .iter()
+            .any(|commitment| commitment.member == member && !commitment.revealed);
+        if !withheld {
+            candidates.push_back(member);
+        }
+    }
+    if candidates.is_empty() {
+        candidates = eligible;
+    }
+
+    let seed = seed.to_array();
+    let mut prefix = [0u8; 8];
+    prefix.copy_from_slice(&seed[..8]);
+    let index = (u64::from_be_bytes(prefix) % candidates.len() as u64) as u32;
 
-    eligible.get(index).ok_or(AjoError::NoEligibleMembers)
+    candidates.get(index).ok_or(AjoError::NoEligibleMembers)
 }
 
-/// Selects the eligible member who received the most votes this cycle.
-/// In the event of a tie the candidate who appears first in `group.members`
+/// Selects the eligible member who received the most votes this cycle,
+/// each vote weighing as many shares as the voter holds.  In the event of a tie the candidate who appears first in `group.members`
 /// wins (deterministic tiebreaker).  If no votes have been cast the
 /// function falls back to the first eligible member in join order so that a
 /// payout can always be executed.
@@ -258,7 +1154,7 @@ fn select_by_votes(env: &Env, group: &Group) -> Result<Address, AjoError> {
                 crate::storage::get_payout_vote(env, group.id, group.current_cycle, &voter)
             {
                 if vote.nominee == candidate {
-                    count += 1;
+                    count += crate::storage::get_member_shares(env, group.id, &voter);
                 }
             }
         }
@@ -273,21 +1169,41 @@ fn select_by_votes(env: &Env, group: &Group) -> Result<Address, AjoError> {
     best.or_else(|| eligible.get(0)).ok_or(AjoError::NoEligibleMembers)
 }
 
-/// Selects the eligible member with the highest reliability score
-/// (fewest late payments).  In the event of a tie the member who appears
-/// first in `group.members` wins.
-fn select_by_contribution(env: &Env, group: &Group) -> Result<Address, AjoError> {
+/// Scores a need declaration for need-based selection (0–200).
+///
+/// * Urgency: 20 points per level, up to 100
+/// * Endorsements: up to 60 points, by the share of other members endorsing
+/// * Waiting: 10 points per cycle since the declaration, up to 20
+/// * Deadline: 20 points if the funds are needed before the current cycle's
+///   contribution deadline
+pub fn need_score(group: &Group, declaration: &crate::types::NeedDeclaration) -> u32 {
+    let urgency_points = declaration.urgency * 20;
+
+    let others = group.members.len().saturating_sub(1);
+    let endorsement_points = (declaration.endorsers.len() * 60).checked_div(others).unwrap_or(0);
+
+    let waited = group.current_cycle.saturating_sub(declaration.declared_cycle);
+    let waiting_points = (waited * 10).min(20);
+
+    let deadline = group.cycle_start_time + group.cycle_duration;
+    let deadline_points = if declaration.needed_by <= deadline { 20 } else { 0 };
+
+    urgency_points + endorsement_points + waiting_points + deadline_points
+}
+
+/// Selects the eligible member with the most pressing declared need, as
+/// scored by [`need_score`].  Ties, and members without a declaration, fall
+/// back to join order.
+fn select_by_need(env: &Env, group: &Group) -> Result<Address, AjoError> {
     let eligible = get_eligible_members(env, group)?;
 
     let mut best: Option<Address> = None;
     let mut best_score: u32 = 0;
-
     for member in eligible.iter() {
-        let score = crate::storage::get_member_penalty(env, group.id, &member)
-            .map(|r| r.reliability_score)
-            .unwrap_or(100_u32); // 100 = perfect — no penalty record yet
-
-        // Strict greater-than: first member wins ties (preserves join-order fairness).
+        let score = crate::storage::get_need_declaration(env, group.id, &member)
+            .map(|declaration| need_score(group, &declaration))
+            .unwrap_or(0);
+        // Strict greater-than preserves join order on ties.
         if score > best_score || best.is_none() {
             best_score = score;
             best = Some(member);
@@ -297,14 +1213,172 @@ fn select_by_contribution(env: &Env, group: &Group) -> Result<Address, AjoError>
     best.ok_or(AjoError::NoEligibleMembers)
 }
 
-/// Returns the subset of group members who have **not** yet received a payout.
-///
-/// Preserves the original join order of `group.members`.
-fn get_eligible_members(env: &Env, group: &Group) -> Result<Vec<Address>, AjoError> {
-    let mut eligible = Vec::new(env);
-    for member in group.members.iter() {
-        if !crate::storage::has_received_payout(env, group.id, &member) {
-            eligible.push_back(member);
+/// Selects the eligible member who bid the largest discount this cycle.
+/// Ties go to the earlier bid.  With no bids the first eligible member in
+/// join order is selected, taking the pot without a discount.
+fn select_by_auction(env: &Env, group: &Group) -> Result<Address, AjoError> {
+    let eligible = get_eligible_members(env, group)?;
+
+    let mut best: Option<Address> = None;
+    let mut best_discount: i128 = 0;
+    for bid in crate::storage::get_cycle_bids(env, group.id, group.current_cycle).iter() {
+        // Strict greater-than keeps the earliest of equal bids.
+        if bid.discount > best_discount && is_member(&eligible, &bid.bidder) {
+            best_discount = bid.discount;
+            best = Some(bid.bidder);
+        }
+    }
+
+    best.or_else(|| eligible.get(0)).ok_or(AjoError::NoEligibleMembers)
+}
+
+/// Returns the discount `member` bid for the group's current cycle, or `0`.
+pub fn winning_bid_discount(env: &Env, group: &Group, member: &Address) -> i128 {
+    crate::storage::get_cycle_bids(env, group.id, group.current_cycle)
+        .iter()
+        .find(|bid| bid.bidder == *member)
+        .map(|bid| bid.discount)
+        .unwrap_or(0)
+}
+
+/// Shares an auction winner's discount among the other members in proportion
+/// to the shares they hold. Members who defaulted this cycle get no portion.
+///
+/// The discount is capped at `pot`. Depending on the group's
+/// [`DiscountSettlement`](crate::types::DiscountSettlement), each member's
+/// portion is transferred immediately or credited against the member's next
+/// contribution. Any remainder that does not divide evenly stays with the
+/// winner. Does nothing for groups that do not use auction ordering.
+///
+/// # Returns
+/// The amount taken from the winner's payout
+///
+/// # Errors
+/// * `TransferFailed` - If a share transfer fails
+pub fn share_auction_discount(env: &Env, group: &Group, winner: &Address, pot: i128) -> Result<i128, AjoError> {
+    if group.payout_strategy != PayoutOrderingStrategy::Auction {
+        return Ok(0);
+    }
+    let discount = winning_bid_discount(env, group, winner).min(pot);
+    let defaulters = crate::storage::get_cycle_defaults(env, group.id, group.current_cycle);
+    let mut recipients = Vec::new(env);
+    let mut others = 0i128;
+    for member in group.members.iter() {
+        if member == *winner || defaulters.contains(&member) {
+            continue;
+        }
+        let shares = crate::storage::get_member_shares(env, group.id, &member);
+        others += shares as i128;
+        recipients.push_back((member, shares));
+    }
+    if discount <= 0 || others <= 0 {
+        return Ok(0);
+    }
+
+    let share = discount / others;
+    if share == 0 {
+        return Ok(0);
+    }
+    for (member, shares) in recipients.iter() {
+        let portion = share * shares as i128;
+        match group.discount_settlement {
+            crate::types::DiscountSettlement::PayOut => {
+                crate::token::transfer_token(
+                    env,
+                    &group.token_address,
+                    &env.current_contract_address(),
+                    &member,
+                    portion,
+                )?;
+            }
+            crate::types::DiscountSettlement::CreditNextContribution => {
+                let credit = crate::storage::get_auction_credit(env, group.id, &member);
+                crate::storage::store_auction_credit(env, group.id, &member, credit + portion);
+            }
+        }
+    }
+
+    // Shares paid out leave the pot; credited shares stay towards the next one
+    let shared = share * others;
+    if group.discount_settlement == crate::types::DiscountSettlement::PayOut {
+        crate::insurance::adjust_pot_liability(env, &group.token_address, -shared);
+    }
+    crate::events::emit_discount_shared(env, group.id, group.current_cycle, winner, discount, share);
+    Ok(shared)
+}
+
+/// Applies up to `amount` of the member's auction credit to a payment.
+///
+/// # Returns
+/// The amount of credit used, which the member does not need to transfer
+pub fn use_auction_credit(env: &Env, group_id: u64, member: &Address, amount: i128) -> i128 {
+    let credit = crate::storage::get_auction_credit(env, group_id, member);
+    let used = credit.min(amount);
+    if used > 0 {
+        crate::storage::store_auction_credit(env, group_id, member, credit - used);
+    }
+    used
+}
+
+/// Transfers every member's unused auction credit to them, e.g. when the
+/// group completes or is refunded.
+///
+/// # Errors
+/// * `TransferFailed` - If a transfer fails
+pub fn pay_out_auction_credits(env: &Env, group: &Group) -> Result<(), AjoError> {
+    for member in group.members.iter() {
+        let credit = crate::storage::get_auction_credit(env, group.id, &member);
+        if credit > 0 {
+            crate::token::transfer_token(
+                env,
+                &group.token_address,
+                &env.current_contract_address(),
+                &member,
+                credit,
+            )?;
+            crate::storage::store_auction_credit(env, group.id, &member, 0);
+            crate::insurance::adjust_pot_liability(env, &group.token_address, -credit);
+        }
+    }
+    Ok(())
+}
+
+/// Selects the eligible member with the highest reliability score
+/// (fewest late payments).  In the event of a tie the member who appears
+/// first in `group.members` wins.
+fn select_by_contribution(env: &Env, group: &Group) -> Result<Address, AjoError> {
+    let eligible = get_eligible_members(env, group)?;
+
+    let mut best: Option<Address> = None;
+    let mut best_score: u32 = 0;
+
+    for member in eligible.iter() {
+        let score = crate::storage::get_member_penalty(env, group.id, &member)
+            .map(|r| r.reliability_score)
+            .unwrap_or(100_u32); // 100 = perfect — no penalty record yet
+
+        // Strict greater-than: first member wins ties (preserves join-order fairness).
+        if score > best_score || best.is_none() {
+            best_score = score;
+            best = Some(member);
+        }
+    }
+
+    best.ok_or(AjoError::NoEligibleMembers)
+}
+
+/// Returns the subset of group members who have **not** yet received a payout
+/// for every share they hold and have not forfeited their slots.
+///
+/// Preserves the original join order of `group.members`.
+fn get_eligible_members(env: &Env, group: &Group) -> Result<Vec<Address>, AjoError> {
+    let forfeited = crate::storage::get_forfeited_members(env, group.id);
+    let mut eligible = Vec::new(env);
+    for member in group.members.iter() {
+        if has_payout_remaining(env, group.id, &member)
+            && !is_member(&forfeited, &member)
+        {
+            eligible.push_back(member);
         }
     }
     if eligible.is_empty() {
@@ -313,13 +1387,104 @@ fn get_eligible_members(env: &Env, group: &Group) -> Result<Vec<Address>, AjoErr
     Ok(eligible)
 }
 
+// ── Reminders ─────────────────────────────────────────────────────────────
+
+/// Sends contribution reminders to every member of `group` who has not yet
+/// contributed this cycle and whose notification preferences call for one
+/// right now.
+///
+/// Each reminder is persisted as a [`ReminderRecord`](crate::types::ReminderRecord)
+/// and emitted as a `remind` event. With `only_new` set, members already sent
+/// the same kind of reminder this cycle are skipped.
+///
+/// # Returns
+/// The members who were reminded
+pub fn send_reminders(env: &Env, group: &Group, only_new: bool) -> Vec<Address> {
+    let now = get_current_timestamp(env);
+    let cycle_end = group.cycle_start_time + group.cycle_duration;
+    let grace_end = get_grace_period_end(group);
+
+    let mut reminded = Vec::new(env);
+
+    for member in group.members.iter() {
+        // Skip members who already contributed this cycle
+        if crate::storage::has_contributed(env, group.id, group.current_cycle, &member) {
+            continue;
+        }
+
+        // Only remind members who have opted in
+        let prefs = match crate::storage::get_notification_preferences(env, &member) {
+            Some(p) if p.enabled => p,
+            _ => continue,
+        };
+
+        // Determine which reminder type applies right now
+        let reminder_type = if now < cycle_end {
+            // Before the deadline — check the member's lead-time threshold
+            let secs_until_deadline = cycle_end - now;
+            let threshold_secs = prefs.reminder_hours_before * 3600;
+            if secs_until_deadline <= threshold_secs {
+                Some(crate::types::ReminderType::ContributionDue)
+            } else {
+                None
+            }
+        } else if now <= grace_end && prefs.grace_period_reminders {
+            Some(crate::types::ReminderType::GracePeriod)
+        } else if now > grace_end {
+            Some(crate::types::ReminderType::Overdue)
+        } else {
+            None
+        };
+
+        if let Some(rtype) = reminder_type {
+            if only_new {
+                let previous =
+                    crate::storage::get_reminder_record(env, group.id, group.current_cycle, &member);
+                if previous.is_some_and(|record| record.reminder_type == rtype) {
+                    continue;
+                }
+            }
+
+            let record = crate::types::ReminderRecord {
+                group_id: group.id,
+                cycle: group.current_cycle,
+                member: member.clone(),
+                reminder_type: rtype,
+                triggered_at: now,
+                deadline: cycle_end,
+            };
+
+            crate::storage::store_reminder_record(env, group.id, group.current_cycle, &member, &record);
+
+            crate::events::emit_reminder_triggered(env, group.id, &member, rtype as u32, cycle_end);
+
+            reminded.push_back(member);
+        }
+    }
+
+    reminded
+}
+
+// ── Keepers ───────────────────────────────────────────────────────────────
+
+/// Bounty owed to whoever cranks `group`, before it is capped by the group's
+/// keeper reserve.
+///
+/// Uses the group's own rate if it set one, otherwise the protocol default,
+/// applied to the group's contribution amount.
+pub fn keeper_bounty(env: &Env, group: &Group) -> i128 {
+    let fee_bps = crate::storage::get_keeper_fee(env, group.id)
+        .unwrap_or_else(|| crate::storage::get_default_keeper_fee(env));
+    group.contribution_amount * fee_bps as i128 / 10_000
+}
+
 // ── Milestone & achievement detection ─────────────────────────────────────
 
 /// Checks which group milestones have been newly achieved based on group state.
 pub fn check_group_milestones(env: &Env, group: &Group) -> Vec<GroupMilestone> {
     let mut milestones = Vec::new(env);
 
-    let total_cycles = group.members.len() as u32;
+    let total_cycles = payout_slot_count(env, group);
     let completed_cycles = group.payout_index;
 
     // First payout
@@ -369,7 +1534,7 @@ fn check_perfect_attendance(env: &Env, group: &Group) -> bool {
 
 /// Returns true if no penalties were incurred across all cycles.
 fn check_zero_penalties(env: &Env, group: &Group) -> bool {
-    let total_cycles = group.members.len() as u32;
+    let total_cycles = payout_slot_count(env, group);
     for cycle in 1..=total_cycles {
         let pool = crate::storage::get_cycle_penalty_pool(env, group.id, cycle);
         if pool > 0 {
@@ -427,9 +1592,719 @@ pub fn default_member_stats(env: &Env, member: &Address) -> crate::types::Member
         total_contributions: 0,
         on_time_contributions: 0,
         late_contributions: 0,
+        defaulted_contributions: 0,
         total_amount_contributed: 0,
         achievements: Vec::new(env),
+        disputes_lost: 0,
+        last_incident_at: 0,
+    }
+}
+
+// ── Contribution tiers ────────────────────────────────────────────────────
+
+/// `member`'s contribution per share: their own tier if one was set when they
+/// joined, otherwise the group's `contribution_amount`.
+pub fn member_tier(env: &Env, group: &Group, member: &Address) -> i128 {
+    crate::storage::get_contribution_tier(env, group.id, member).unwrap_or(group.contribution_amount)
+}
+
+/// Checks that `amount` can be used as a contribution tier in `group`.
+///
+/// # Errors
+/// * `ContributionAmountZero` - If `amount == 0`
+/// * `ContributionAmountNegative` - If `amount < 0`
+/// * `InvalidMultiTokenConfig` - If `amount` differs from the group's contribution
+///   and the group accepts several tokens
+pub fn validate_contribution_tier(env: &Env, group: &Group, amount: i128) -> Result<(), AjoError> {
+    if amount == 0 {
+        return Err(AjoError::ContributionAmountZero);
+    } else if amount < 0 {
+        return Err(AjoError::ContributionAmountNegative);
+    }
+    if amount != group.contribution_amount
+        && crate::storage::get_multi_token_config(env, group.id).is_some()
+    {
+        return Err(AjoError::InvalidMultiTokenConfig);
+    }
+    Ok(())
+}
+
+/// Records `amount` as `member`'s contribution per share. Nothing is stored
+/// for the group's own tier. Callers must run [`validate_contribution_tier`] first.
+pub fn set_contribution_tier(env: &Env, group: &Group, member: &Address, amount: i128) {
+    if amount == group.contribution_amount {
+        return;
+    }
+    crate::storage::store_contribution_tier(env, group.id, member, amount);
+    crate::events::emit_contribution_tier_set(env, group.id, member, amount);
+}
+
+/// What one payout to `member` is worth: their tier once for every share in
+/// the group. The group makes one payout per share, so across the round each
+/// member gets back exactly what they paid in.
+pub fn tier_payout_amount(env: &Env, group: &Group, member: &Address) -> i128 {
+    member_tier(env, group, member) * total_shares(env, group) as i128
+}
+
+/// Sizes a payout of the cycle's `pot` to `recipient` by their tier.
+///
+/// The recipient's share is the pot scaled by their tier payout over the pot a
+/// full cycle collects, so a pot left short by defaults shrinks every share
+/// alike. A share smaller than the pot is paid and the difference is held
+/// back. A larger one gets as much of the held-back pot as there is and is
+/// owed the rest, which [`settle_tier_balances`] pays out at later payouts.
+/// Groups where every member pays the same amount are unaffected.
+///
+/// # Returns
+/// The amount to transfer to `recipient`
+pub fn apply_tier_adjustment(env: &Env, group: &Group, recipient: &Address, pot: i128) -> i128 {
+    let full_pot = calculate_payout_amount(env, group);
+    let entitled = tier_payout_amount(env, group, recipient);
+    if entitled == full_pot || full_pot <= 0 || pot <= 0 {
+        return pot;
+    }
+    let difference = pot * entitled / full_pot - pot;
+
+    let held = crate::storage::get_tier_held(env, group.id);
+    if difference < 0 {
+        let withheld = -difference;
+        crate::storage::store_tier_held(env, group.id, held + withheld);
+        pot - withheld
+    } else {
+        let drawn = difference.min(held);
+        crate::storage::store_tier_held(env, group.id, held - drawn);
+        let owed = crate::storage::get_tier_owed(env, group.id, recipient);
+        crate::storage::store_tier_owed(env, group.id, recipient, owed + difference - drawn);
+        pot + drawn
+    }
+}
+
+/// Pays members what they are still owed from the held-back pot, in join order.
+///
+/// # Errors
+/// * `TransferFailed` - If a transfer fails
+pub fn settle_tier_balances(env: &Env, group: &Group) -> Result<(), AjoError> {
+    let mut held = crate::storage::get_tier_held(env, group.id);
+    if held <= 0 {
+        return Ok(());
+    }
+    for member in group.members.iter() {
+        let owed = crate::storage::get_tier_owed(env, group.id, &member);
+        let paid = owed.min(held);
+        if paid <= 0 {
+            continue;
+        }
+        crate::token::transfer_token(
+            env,
+            &group.token_address,
+            &env.current_contract_address(),
+            &member,
+            paid,
+        )?;
+        crate::insurance::adjust_pot_liability(env, &group.token_address, -paid);
+        held -= paid;
+        crate::storage::store_tier_owed(env, group.id, &member, owed - paid);
+        crate::events::emit_tier_settled(env, group.id, &member, paid, owed - paid);
+    }
+    crate::storage::store_tier_held(env, group.id, held);
+    Ok(())
+}
+
+/// Clears the group's tier balances once it completes or is cancelled.
+///
+/// Members still owed are paid from the held-back pot first. Any pot left
+/// after that goes back to the members in proportion to what they pay each
+/// cycle, and anything still owed is written off, since the group has
+/// nothing left to pay it from.
+///
+/// # Errors
+/// * `TransferFailed` - If a transfer fails
+pub fn close_tier_balances(env: &Env, group: &Group) -> Result<(), AjoError> {
+    settle_tier_balances(env, group)?;
+    let held = crate::storage::get_tier_held(env, group.id);
+    let full_pot = calculate_payout_amount(env, group);
+    let mut remaining = held;
+    for (index, member) in group.members.iter().enumerate() {
+        let refund = if held <= 0 || full_pot <= 0 {
+            0
+        } else if index as u32 + 1 == group.members.len() {
+            remaining
+        } else {
+            held * member_contribution(env, group, &member) / full_pot
+        };
+        if refund > 0 {
+            crate::token::transfer_token(
+                env,
+                &group.token_address,
+                &env.current_contract_address(),
+                &member,
+                refund,
+            )?;
+            crate::insurance::adjust_pot_liability(env, &group.token_address, -refund);
+            remaining -= refund;
+        }
+        let written_off = crate::storage::get_tier_owed(env, group.id, &member);
+        if written_off > 0 {
+            crate::storage::store_tier_owed(env, group.id, &member, 0);
+        }
+        if refund > 0 || written_off > 0 {
+            crate::events::emit_tier_closed(env, group.id, &member, refund, written_off);
+        }
+    }
+    crate::storage::store_tier_held(env, group.id, 0);
+    Ok(())
+}
+
+/// Creates or replaces an invitation for `invitee` to join at `contribution_amount`
+/// per share. An `expires_in` too large to add to the current time gives an
+/// invitation that never expires.
+pub fn issue_invitation(
+    env: &Env,
+    group_id: u64,
+    creator: &Address,
+    invitee: &Address,
+    expires_in: u64,
+    contribution_amount: i128,
+) {
+    let now = get_current_timestamp(env);
+    let invitation = crate::types::GroupInvitation {
+        group_id,
+        invitee: invitee.clone(),
+        invited_by: creator.clone(),
+        created_at: now,
+        expires_at: now.saturating_add(expires_in),
+        accepted: false,
+        contribution_amount,
+    };
+    crate::storage::store_invitation(env, group_id, invitee, &invitation);
+
+    let mut invitees = crate::storage::get_invitation_list(env, group_id);
+    if !is_member(&invitees, invitee) {
+        invitees.push_back(invitee.clone());
+        crate::storage::store_invitation_list(env, group_id, &invitees);
+    }
+
+    crate::events::emit_member_invited(env, group_id, invitee, invitation.expires_at);
+}
+
+// ── Rollover ──────────────────────────────────────────────────────────────
+
+/// Opens the next round of a completed group that has rollover enabled.
+///
+/// The successor gets a new id and the same parameters, metadata, accepted
+/// tokens and yield adapter settings. Its members are every member who opted
+/// in, in their original join order, keeping their shares, tiers and any
+/// escrowed collateral. Its first cycle starts now, unless the group uses a
+/// formation phase (`min_members > 0`) and the successor has seats left: it
+/// then forms for one cycle's duration so newcomers can fill the seats, and
+/// starts like any forming group. Nothing happens if the creator did not opt
+/// in or fewer than two members would carry on; all collateral then stays with
+/// this group to be returned. `group.successor_id` is set; callers are
+/// responsible for storing `group`.
+pub fn roll_over(env: &Env, group: &mut Group) {
+    if !group.rollover_enabled {
+        return;
+    }
+    let opted_in = crate::storage::get_rollover_opt_ins(env, group.id);
+    if !is_member(&opted_in, &group.creator) {
+        return;
+    }
+    let mut members = Vec::new(env);
+    for member in group.members.iter() {
+        if is_member(&opted_in, &member) {
+            members.push_back(member);
+        }
+    }
+    if members.len() < 2 {
+        return;
+    }
+
+    let successor_id = crate::storage::get_next_group_id(env);
+    let now = get_current_timestamp(env);
+    let mut successor = Group {
+        id: successor_id,
+        members: members.clone(),
+        current_cycle: 1,
+        payout_index: 0,
+        created_at: now,
+        cycle_start_time: now,
+        is_complete: false,
+        state: crate::types::GroupState::Active,
+        formation_deadline: 0,
+        round: group.round + 1,
+        predecessor_id: group.id,
+        successor_id: 0,
+        ..group.clone()
+    };
+
+    for member in members.iter() {
+        let shares = crate::storage::get_member_shares(env, group.id, &member);
+        if shares > 1 {
+            crate::storage::store_member_shares(env, successor_id, &member, shares);
+        }
+        if let Some(tier) = crate::storage::get_contribution_tier(env, group.id, &member) {
+            crate::storage::store_contribution_tier(env, successor_id, &member, tier);
+        }
+        let collateral = crate::storage::get_collateral(env, group.id, &member);
+        if collateral > 0 {
+            crate::storage::remove_collateral(env, group.id, &member);
+            crate::storage::store_collateral(env, successor_id, &member, collateral);
+        }
+
+        let mut stats = crate::storage::get_member_stats(env, &member)
+            .unwrap_or_else(|| default_member_stats(env, &member));
+        stats.total_groups_joined += 1;
+        crate::storage::store_member_stats(env, &member, &stats);
+    }
+    if let Some(metadata) = crate::storage::get_group_metadata(env, group.id) {
+        crate::storage::store_group_metadata(env, successor_id, &metadata);
+    }
+    if let Some(config) = crate::storage::get_yield_config(env, group.id) {
+        crate::storage::store_yield_config(env, successor_id, &config);
+    }
+    if let Some(mut config) = crate::storage::get_multi_token_config(env, group.id) {
+        config.group_id = successor_id;
+        crate::storage::store_multi_token_config(env, successor_id, &config);
+    }
+
+    let full = total_shares(env, &successor) >= successor.max_members;
+    if !full && successor.min_members > 0 {
+        successor.state = crate::types::GroupState::Forming;
+        successor.formation_deadline = now + successor.cycle_duration;
+    }
+
+    crate::storage::store_group(env, successor_id, &successor);
+    if successor.schedule_rule != crate::types::ScheduleRule::Unlocked && full {
+        lock_payout_schedule(env, &successor);
+    }
+    group.successor_id = successor_id;
+
+    crate::events::emit_group_created(
+        env,
+        successor_id,
+        &successor.creator,
+        successor.contribution_amount,
+        successor.max_members,
+    );
+    crate::events::emit_round_opened(env, group.id, successor_id, successor.round);
+    if successor.state == crate::types::GroupState::Forming {
+        crate::events::emit_formation_set(
+            env,
+            successor_id,
+            successor.min_members,
+            successor.formation_deadline,
+            successor.allow_late_join,
+        );
+    }
+}
+
+// ── Membership changes ────────────────────────────────────────────────────
+
+/// Returns what `member` has paid towards the current cycle when they leave.
+///
+/// Premiums already moved into the insurance reserve are not returned: with
+/// [`PremiumMode::DeductFromPot`](crate::types::PremiumMode) the refund is
+/// reduced by the member's premium and the cycle's deduction is released.
+/// A completed contribution is also taken back out of the member's stats.
+///
+/// # Returns
+/// The amount refunded
+///
+/// # Errors
+/// * `TransferFailed` - If the refund transfer fails
+pub fn refund_member_contribution(env: &Env, group: &Group, member: &Address) -> Result<i128, AjoError> {
+    let cycle = group.current_cycle;
+    let token_record = crate::storage::get_token_contribution(env, group.id, cycle, member);
+    let (token, paid) = match &token_record {
+        Some(record) => (record.token.clone(), record.amount),
+        None => (group.token_address.clone(), get_amount_paid(env, group, cycle, member)),
+    };
+
+    let deducted = if group.insurance_config.premium_mode == crate::types::PremiumMode::DeductFromPot {
+        crate::insurance::member_cycle_premium(env, group, member)
+    } else {
+        0
+    };
+    let refund = paid - deducted;
+
+    if deducted > 0 {
+        let total = crate::storage::get_cycle_premiums(env, group.id, cycle);
+        crate::storage::store_cycle_premiums(env, group.id, cycle, total - deducted);
+    }
+    if token_record.is_some() {
+        crate::storage::add_group_token_balance(env, group.id, cycle, &token, -refund);
+        crate::storage::remove_token_contribution(env, group.id, cycle, member);
+    }
+
+    if crate::storage::has_contributed(env, group.id, cycle, member) {
+        let mut stats = crate::storage::get_member_stats(env, member)
+            .unwrap_or_else(|| default_member_stats(env, member));
+        let is_late = crate::storage::get_contribution_detail(env, group.id, cycle, member)
+            .map(|record| record.is_late)
+            .unwrap_or(false);
+        stats.total_contributions = stats.total_contributions.saturating_sub(1);
+        if is_late {
+            stats.late_contributions = stats.late_contributions.saturating_sub(1);
+        } else {
+            stats.on_time_contributions = stats.on_time_contributions.saturating_sub(1);
+        }
+        stats.total_amount_contributed -= member_contribution(env, group, member);
+        crate::storage::store_member_stats(env, member, &stats);
+        crate::storage::remove_contribution(env, group.id, cycle, member);
+    }
+    crate::storage::remove_partial_contribution(env, group.id, cycle, member);
+
+    if refund > 0 {
+        if token_record.is_none() {
+            crate::yield_adapter::release(env, group, refund);
+        }
+        crate::token::transfer_token(env, &token, &env.current_contract_address(), member, refund)?;
+        crate::insurance::adjust_pot_liability(env, &token, -refund);
+
+        let reason = crate::types::RefundReason::MemberLeft;
+        crate::storage::store_refund_record(
+            env,
+            group.id,
+            member,
+            &crate::types::RefundRecord {
+                group_id: group.id,
+                member: member.clone(),
+                amount: refund,
+                timestamp: get_current_timestamp(env),
+                reason,
+            },
+        );
+        crate::events::emit_refund_processed(env, group.id, member, refund, reason as u32);
+    }
+
+    Ok(refund)
+}
+
+/// Hands `from`'s slot in the group to `to`.
+///
+/// `to` takes `from`'s position in the member list, so the payout order is
+/// unchanged, and inherits `from`'s shares, tier, payouts received and
+/// anything still owed to them, the current cycle's contribution,
+/// installments and any default or forfeited payout. `from`'s collateral and
+/// any prepaid cycles are returned and `to` escrows their own collateral. The
+/// updated group is stored.
+///
+/// # Errors
+/// * `NotMember` - If `from` is not a member
+/// * `InsufficientBalance` - If `to` cannot cover the collateral
+pub fn transfer_slot(env: &Env, group: &mut Group, from: &Address, to: &Address) -> Result<(), AjoError> {
+    let index = group.members.first_index_of(from).ok_or(AjoError::NotMember)?;
+    group.members.set(index, to.clone());
+    if let Some(mut schedule) = crate::storage::get_payout_schedule(env, group.id) {
+        while let Some(slot) = schedule.recipients.first_index_of(from) {
+            schedule.recipients.set(slot, to.clone());
+        }
+        crate::storage::store_payout_schedule(env, &schedule);
+    }
+
+    let shares = crate::storage::get_member_shares(env, group.id, from);
+    crate::storage::remove_member_shares(env, group.id, from);
+    crate::storage::store_member_shares(env, group.id, to, shares);
+    if let Some(tier) = crate::storage::get_contribution_tier(env, group.id, from) {
+        crate::storage::remove_contribution_tier(env, group.id, from);
+        crate::storage::store_contribution_tier(env, group.id, to, tier);
+    }
+    let owed = crate::storage::get_tier_owed(env, group.id, from);
+    if owed > 0 {
+        crate::storage::store_tier_owed(env, group.id, from, 0);
+        crate::storage::store_tier_owed(env, group.id, to, owed);
+    }
+
+    let cycle = group.current_cycle;
+    if crate::storage::has_received_payout(env, group.id, from) {
+        let payouts = crate::storage::get_payouts_received(env, group.id, from);
+        crate::storage::clear_payout_received(env, group.id, from);
+        crate::storage::mark_payout_received(env, group.id, to);
+        crate::storage::store_payouts_received(env, group.id, to, payouts);
+    }
+    if crate::storage::has_contributed(env, group.id, cycle, from) {
+        crate::storage::remove_contribution(env, group.id, cycle, from);
+        crate::storage::store_contribution(env, group.id, cycle, to, true);
+    }
+    if let Some(mut record) = crate::storage::get_partial_contribution(env, group.id, cycle, from) {
+        record.member = to.clone();
+        crate::storage::remove_partial_contribution(env, group.id, cycle, from);
+        crate::storage::store_partial_contribution(env, group.id, cycle, to, &record);
+    }
+    if let Some(mut record) = crate::storage::get_token_contribution(env, group.id, cycle, from) {
+        record.member = to.clone();
+        crate::storage::remove_token_contribution(env, group.id, cycle, from);
+        crate::storage::store_token_contribution(env, group.id, cycle, to, &record);
+    }
+    let credit = crate::storage::get_auction_credit(env, group.id, from);
+    if credit > 0 {
+        crate::storage::store_auction_credit(env, group.id, from, 0);
+        crate::storage::store_auction_credit(env, group.id, to, credit);
+    }
+    if let Some(mut record) = crate::storage::get_default_record(env, group.id, cycle, from) {
+        record.member = to.clone();
+        crate::storage::remove_default_record(env, group.id, cycle, from);
+        crate::storage::store_default_record(env, group.id, cycle, to, &record);
+    }
+
+    let mut defaults = crate::storage::get_cycle_defaults(env, group.id, cycle);
+    if let Some(i) = defaults.first_index_of(from) {
+        defaults.set(i, to.clone());
+        crate::storage::store_cycle_defaults(env, group.id, cycle, &defaults);
+    }
+    let mut forfeited = crate::storage::get_forfeited_members(env, group.id);
+    if let Some(i) = forfeited.first_index_of(from) {
+        forfeited.set(i, to.clone());
+        crate::storage::store_forfeited_members(env, group.id, &forfeited);
+    }
+
+    return_collateral(env, group, from)?;
+    refund_prepayment(env, group, from)?;
+    escrow_collateral(env, group, to)?;
+
+    crate::storage::store_group(env, group.id, group);
+
+    let mut stats = crate::storage::get_member_stats(env, from)
+        .unwrap_or_else(|| default_member_stats(env, from));
+    stats.total_groups_joined = stats.total_groups_joined.saturating_sub(1);
+    crate::storage::store_member_stats(env, from, &stats);
+
+    let mut stats = crate::storage::get_member_stats(env, to)
+        .unwrap_or_else(|| default_member_stats(env, to));
+    stats.total_groups_joined += 1;
+    crate::storage::store_member_stats(env, to, &stats);
+    Ok(())
+}
+
+// ── Collateral ────────────────────────────────────────────────────────────
+
+fn adjust_collateral_liability(env: &Env, token: &Address, delta: i128) {
+    let mut liabilities = crate::storage::get_token_liabilities(env, token);
+    liabilities.collateral += delta;
+    crate::storage::store_token_liabilities(env, token, &liabilities);
+}
+
+/// Transfers the group's collateral amount from `member` into escrow.
+///
+/// Does nothing when the group requires no collateral.
+///
+/// # Errors
+/// * `InsufficientBalance` - If the member cannot cover the collateral
+pub fn escrow_collateral(env: &Env, group: &Group, member: &Address) -> Result<(), AjoError> {
+    let amount = group.collateral_amount;
+    if amount <= 0 {
+        return Ok(());
+    }
+
+    crate::token::check_balance(env, &group.token_address, member, amount)?;
+    crate::token::transfer_token(
+        env,
+        &group.token_address,
+        member,
+        &env.current_contract_address(),
+        amount,
+    )?;
+
+    let held = crate::storage::get_collateral(env, group.id, member);
+    crate::storage::store_collateral(env, group.id, member, held + amount);
+    adjust_collateral_liability(env, &group.token_address, amount);
+    crate::events::emit_collateral_deposited(env, group.id, member, amount);
+    Ok(())
+}
+
+/// Returns whatever collateral `member` still has escrowed with the group.
+///
+/// # Returns
+/// The amount returned
+pub fn return_collateral(env: &Env, group: &Group, member: &Address) -> Result<i128, AjoError> {
+    let held = crate::storage::get_collateral(env, group.id, member);
+    if held <= 0 {
+        return Ok(0);
+    }
+
+    crate::token::transfer_token(
+        env,
+        &group.token_address,
+        &env.current_contract_address(),
+        member,
+        held,
+    )?;
+
+    crate::storage::remove_collateral(env, group.id, member);
+    adjust_collateral_liability(env, &group.token_address, -held);
+    crate::events::emit_collateral_returned(env, group.id, member, held);
+    Ok(held)
+}
+
+/// Returns every member's remaining collateral, and that of anyone with a
+/// pending join request, e.g. when the group completes or is cancelled.
+pub fn return_all_collateral(env: &Env, group: &Group) -> Result<(), AjoError> {
+    for member in group.members.iter() {
+        return_collateral(env, group, &member)?;
+    }
+    for requester in crate::storage::get_join_request_list(env, group.id).iter() {
+        return_collateral(env, group, &requester)?;
+    }
+    Ok(())
+}
+
+/// Moves up to `owed` of a defaulter's collateral into the current cycle's pot.
+///
+/// # Returns
+/// The amount slashed
+pub fn slash_collateral(env: &Env, group: &Group, member: &Address, owed: i128) -> i128 {
+    let held = crate::storage::get_collateral(env, group.id, member);
+    let slashed = held.min(owed);
+    if slashed <= 0 {
+        return 0;
+    }
+
+    crate::storage::store_collateral(env, group.id, member, held - slashed);
+    adjust_collateral_liability(env, &group.token_address, -slashed);
+    crate::insurance::adjust_pot_liability(env, &group.token_address, slashed);
+
+    // Multi-token payouts are paid from per-token balances
+    if crate::storage::get_multi_token_config(env, group.id).is_some() {
+        crate::storage::add_group_token_balance(
+            env,
+            group.id,
+            group.current_cycle,
+            &group.token_address,
+            slashed,
+        );
+    }
+
+    crate::events::emit_collateral_slashed(env, group.id, member, group.current_cycle, slashed);
+    slashed
+}
+
+/// Lists the collateral each member currently has escrowed.
+pub fn collateral_balances(env: &Env, group: &Group) -> Vec<crate::types::CollateralBalance> {
+    let mut balances = Vec::new(env);
+    for member in group.members.iter() {
+        let amount = crate::storage::get_collateral(env, group.id, &member);
+        balances.push_back(crate::types::CollateralBalance { member, amount });
     }
+    balances
+}
+
+// ── Prepayments ───────────────────────────────────────────────────────────
+
+fn adjust_prepaid_liability(env: &Env, token: &Address, delta: i128) {
+    let mut liabilities = crate::storage::get_token_liabilities(env, token);
+    liabilities.prepaid += delta;
+    crate::storage::store_token_liabilities(env, token, &liabilities);
+}
+
+/// What `member` sets aside per prepaid cycle: their contribution plus any
+/// premium charged on top of it.
+pub fn prepaid_cycle_cost(env: &Env, group: &Group, member: &Address) -> i128 {
+    let contribution = member_contribution(env, group, member);
+    contribution + crate::insurance::upfront_premium(env, group, member, contribution)
+}
+
+/// Transfers `cycles` future contributions from `member` into escrow.
+///
+/// # Returns
+/// The member's prepayment after escrowing
+///
+/// # Errors
+/// * `InsufficientBalance` - If the member cannot cover the prepayment
+pub fn escrow_prepayment(
+    env: &Env,
+    group: &Group,
+    member: &Address,
+    cycles: u32,
+) -> Result<crate::types::Prepayment, AjoError> {
+    let amount = prepaid_cycle_cost(env, group, member) * cycles as i128;
+
+    crate::token::check_balance(env, &group.token_address, member, amount)?;
+    crate::token::transfer_token(
+        env,
+        &group.token_address,
+        member,
+        &env.current_contract_address(),
+        amount,
+    )?;
+
+    let mut prepayment = crate::storage::get_prepayment(env, group.id, member)
+        .unwrap_or(crate::types::Prepayment { cycles: 0, amount: 0 });
+    prepayment.cycles += cycles;
+    prepayment.amount += amount;
+    crate::storage::store_prepayment(env, group.id, member, &prepayment);
+    adjust_prepaid_liability(env, &group.token_address, amount);
+    crate::events::emit_contributions_prepaid(env, group.id, member, cycles, amount);
+    Ok(prepayment)
+}
+
+/// Contributes for every member with a prepayment at the start of a new
+/// cycle.
+///
+/// A prepayment that no longer covers a cycle, e.g. because the member's
+/// premium rate went up, is left alone; the member contributes as usual and
+/// the rest is refunded later. Once a member's prepaid cycles are used up,
+/// anything left over is refunded straight away.
+pub fn apply_prepayments(env: &Env, group: &Group) -> Result<(), AjoError> {
+    for member in group.members.iter() {
+        let mut prepayment = match crate::storage::get_prepayment(env, group.id, &member) {
+            Some(prepayment) => prepayment,
+            None => continue,
+        };
+        let cost = prepaid_cycle_cost(env, group, &member);
+        if prepayment.cycles == 0 || prepayment.amount < cost {
+            continue;
+        }
+
+        let contribution = member_contribution(env, group, &member);
+        prepayment.cycles -= 1;
+        prepayment.amount -= cost;
+        crate::storage::store_prepayment(env, group.id, &member, &prepayment);
+        adjust_prepaid_liability(env, &group.token_address, -cost);
+        crate::insurance::adjust_pot_liability(env, &group.token_address, contribution);
+
+        let deducted = complete_contribution(env, group, &member, false, 0);
+        crate::events::emit_prepayment_applied(env, group.id, &member, group.current_cycle, contribution);
+        crate::yield_adapter::park(env, group, contribution - deducted)?;
+
+        if prepayment.cycles == 0 {
+            refund_prepayment(env, group, &member)?;
+        }
+    }
+    Ok(())
+}
+
+/// Returns whatever `member` still has prepaid with the group.
+///
+/// # Returns
+/// The amount refunded
+pub fn refund_prepayment(env: &Env, group: &Group, member: &Address) -> Result<i128, AjoError> {
+    let prepayment = match crate::storage::get_prepayment(env, group.id, member) {
+        Some(prepayment) => prepayment,
+        None => return Ok(0),
+    };
+
+    if prepayment.amount > 0 {
+        crate::token::transfer_token(
+            env,
+            &group.token_address,
+            &env.current_contract_address(),
+            member,
+            prepayment.amount,
+        )?;
+        adjust_prepaid_liability(env, &group.token_address, -prepayment.amount);
+        crate::events::emit_prepayment_refunded(env, group.id, member, prepayment.amount);
+    }
+    crate::storage::remove_prepayment(env, group.id, member);
+    Ok(prepayment.amount)
+}
+
+/// Refunds every member's unused prepayment, e.g. when the group completes
+/// or is cancelled.
+pub fn refund_all_prepayments(env: &Env, group: &Group) -> Result<(), AjoError> {
+    for member in group.members.iter() {
+        refund_prepayment(env, group, &member)?;
+    }
+    Ok(())
 }
 
 // ── Multi-token helpers ───────────────────────────────────────────────────
@@ -0,0 +1,211 @@
+use soroban_sdk::auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation};
+use soroban_sdk::{contractclient, vec, Address, Env, IntoVal, Symbol};
+use crate::errors::AjoError;
+use crate::events;
+use crate::storage;
+use crate::types::{Group, YieldDistribution, YieldPosition};
+use crate::utils;
+
+/// Interface a lending contract must expose to hold group pots.
+///
+/// Adapters are allowlisted by the admin for a single token. The contract
+/// holds one position per adapter on behalf of every group that opts in.
+#[contractclient(name = "YieldAdapterClient")]
+pub trait YieldAdapter {
+    /// Pulls `amount` of the adapter's token from `from` into the position.
+    fn deposit(env: Env, from: Address, amount: i128);
+
+    /// Sends `amount` from `to`'s position back to `to`.
+    fn withdraw(env: Env, to: Address, amount: i128);
+
+    /// What `owner` could withdraw right now, including accrued yield.
+    fn balance(env: Env, owner: Address) -> i128;
+}
+
+/// Parks `amount` of a group's pot with its yield adapter.
+///
+/// Does nothing if the group has not opted in or its adapter has since been
+/// removed from the allowlist; the funds then stay with the contract.
+pub fn park(env: &Env, group: &Group, amount: i128) -> Result<(), AjoError> {
+    if amount <= 0 {
+        return Ok(());
+    }
+    let config = match storage::get_yield_config(env, group.id) {
+        Some(config) => config,
+        None => return Ok(()),
+    };
+    if storage::get_yield_adapter(env, &config.adapter).is_none() {
+        return Ok(());
+    }
+
+    let contract = env.current_contract_address();
+    let adapter = YieldAdapterClient::new(env, &config.adapter);
+    let total_units = storage::get_yield_units(env, &config.adapter);
+    let balance = adapter.balance(&contract);
+    // Units are priced against the position so earlier deposits keep their yield
+    let units = if total_units == 0 || balance <= 0 {
+        amount
+    } else {
+        amount * total_units / balance
+    };
+
+    // The adapter pulls the tokens, so authorize that transfer on our behalf
+    env.authorize_as_current_contract(vec![
+        env,
+        InvokerContractAuthEntry::Contract(SubContractInvocation {
+            context: ContractContext {
+                contract: group.token_address.clone(),
+                fn_name: Symbol::new(env, "transfer"),
+                args: (contract.clone(), config.adapter.clone(), amount).into_val(env),
+            },
+            sub_invocations: vec![env],
+        }),
+    ]);
+    adapter.deposit(&contract, &amount);
+
+    storage::store_yield_units(env, &config.adapter, total_units + units);
+    let mut position = storage::get_yield_position(env, group.id).unwrap_or(YieldPosition {
+        adapter: config.adapter.clone(),
+        units: 0,
+        principal: 0,
+    });
+    position.units += units;
+    position.principal += amount;
+    storage::store_yield_position(env, group.id, &position);
+    crate::insurance::adjust_parked_liability(env, &group.token_address, amount);
+
+    events::emit_funds_parked(env, group.id, &config.adapter, amount);
+    Ok(())
+}
+
+/// Withdraws everything a group has parked so its pot can be paid out or
+/// refunded.
+///
+/// Whatever comes back joins the group's pot: yield earned on top of the
+/// principal raises the pot and a loss at the adapter lowers it, so callers
+/// can only pay out what was actually recovered.
+///
+/// # Returns
+/// The yield earned, negative for a loss (`0` if nothing was parked)
+pub fn recall(env: &Env, group: &Group) -> i128 {
+    let mut position = match storage::get_yield_position(env, group.id) {
+        Some(position) => position,
+        None => return 0,
+    };
+    let principal = position.principal;
+    let units = position.units;
+    let value = redeem(env, &mut position, units);
+    position.principal = 0;
+    storage::store_yield_position(env, group.id, &position);
+    crate::insurance::adjust_parked_liability(env, &group.token_address, -principal);
+
+    let yield_earned = value - principal;
+    crate::insurance::adjust_pot_liability(env, &group.token_address, yield_earned);
+    events::emit_yield_withdrawn(env, group.id, principal, yield_earned);
+    yield_earned
+}
+
+/// Withdraws `amount` of a group's parked principal, leaving the rest and the
+/// yield accrued so far parked.
+pub fn release(env: &Env, group: &Group, amount: i128) {
+    let mut position = match storage::get_yield_position(env, group.id) {
+        Some(position) => position,
+        None => return,
+    };
+    let amount = amount.min(position.principal);
+    if amount <= 0 {
+        return;
+    }
+
+    let adapter = YieldAdapterClient::new(env, &position.adapter);
+    let balance = adapter.balance(&env.current_contract_address());
+    let total_units = storage::get_yield_units(env, &position.adapter);
+    // Round up so the withdrawal always covers `amount`
+    let units = if balance <= 0 {
+        position.units
+    } else {
+        ((amount * total_units + balance - 1) / balance).min(position.units)
+    };
+    redeem(env, &mut position, units);
+    position.principal -= amount;
+    storage::store_yield_position(env, group.id, &position);
+    crate::insurance::adjust_parked_liability(env, &group.token_address, -amount);
+}
+
+/// Shares out yield that has joined a group's pot according to its
+/// [`YieldDistribution`].
+///
+/// With no `recipient` (refunds), yield meant for the recipient is shared
+/// among members instead.
+///
+/// # Returns
+/// The amount to add to `recipient`'s payout
+///
+/// # Errors
+/// * `TransferFailed` - If a member's share cannot be transferred
+pub fn distribute(
+    env: &Env,
+    group: &Group,
+    recipient: Option<&Address>,
+    amount: i128,
+) -> Result<i128, AjoError> {
+    if amount <= 0 {
+        return Ok(0);
+    }
+    let distribution = storage::get_yield_config(env, group.id)
+        .map(|config| config.distribution)
+        .unwrap_or(YieldDistribution::Members);
+
+    match (distribution, recipient) {
+        (YieldDistribution::Recipient, Some(_)) => Ok(amount),
+        (YieldDistribution::InsurancePool, _) => {
+            crate::insurance::adjust_pot_liability(env, &group.token_address, -amount);
+            crate::insurance::deposit_to_pool(env, group.id, &group.token_address, amount);
+            Ok(0)
+        }
+        _ => {
+            share_among_members(env, group, amount)?;
+            Ok(0)
+        }
+    }
+}
+
+/// Pays `amount` out to members in proportion to their per-cycle
+/// contribution. Rounding dust stays with the contract.
+fn share_among_members(env: &Env, group: &Group, amount: i128) -> Result<(), AjoError> {
+    let mut total = 0i128;
+    for member in group.members.iter() {
+        total += utils::member_contribution(env, group, &member);
+    }
+    if total <= 0 {
+        return Ok(());
+    }
+
+    let contract = env.current_contract_address();
+    for member in group.members.iter() {
+        let share = amount * utils::member_contribution(env, group, &member) / total;
+        if share > 0 {
+            crate::token::transfer_token(env, &group.token_address, &contract, &member, share)?;
+        }
+    }
+    crate::insurance::adjust_pot_liability(env, &group.token_address, -amount);
+    Ok(())
+}
+
+/// Redeems `units` of a group's position, returning the amount withdrawn.
+fn redeem(env: &Env, position: &mut YieldPosition, units: i128) -> i128 {
+    let contract = env.current_contract_address();
+    let adapter = YieldAdapterClient::new(env, &position.adapter);
+    let total_units = storage::get_yield_units(env, &position.adapter);
+    let value = if total_units > 0 {
+        adapter.balance(&contract) * units / total_units
+    } else {
+        0
+    };
+    if value > 0 {
+        adapter.withdraw(&contract, &value);
+    }
+    storage::store_yield_units(env, &position.adapter, total_units - units);
+    position.units -= units;
+    value
+}
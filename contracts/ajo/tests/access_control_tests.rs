@@ -0,0 +1,166 @@
+#![cfg(test)]
+
+use soroban_ajo::{AjoContract, AjoContractClient, AjoError, GroupAccessType};
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address, Env};
+
+/// Helper function to create a test environment and contract
+fn setup_test_env() -> (Env, AjoContractClient<'static>, Address, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AjoContract);
+    let client = AjoContractClient::new(&env, &contract_id);
+
+    let creator = Address::generate(&env);
+    let member2 = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = env.register_stellar_asset_contract(token_admin);
+
+    (env, client, creator, member2, token)
+}
+
+fn create_group(client: &AjoContractClient, creator: &Address, token: &Address, max_members: u32) -> u64 {
+    client.create_group(creator, token, &100_000_000i128, &604_800u64, &max_members, &86400u64, &5u32, &0u32)
+}
+
+#[test]
+fn test_only_creator_can_set_access_type() {
+    let (_env, client, creator, member2, token) = setup_test_env();
+    let group_id = create_group(&client, &creator, &token, 5);
+
+    let result = client.try_set_group_access_type(&member2, &group_id, &GroupAccessType::InviteOnly);
+    assert_eq!(result, Err(Ok(AjoError::Unauthorized)));
+
+    client.set_group_access_type(&creator, &group_id, &GroupAccessType::InviteOnly);
+    assert_eq!(client.get_group(&group_id).access_type, GroupAccessType::InviteOnly);
+}
+
+#[test]
+fn test_invite_only_requires_invitation() {
+    let (_env, client, creator, member2, token) = setup_test_env();
+    let group_id = create_group(&client, &creator, &token, 5);
+    client.set_group_access_type(&creator, &group_id, &GroupAccessType::InviteOnly);
+
+    let result = client.try_join_group(&member2, &group_id);
+    assert_eq!(result, Err(Ok(AjoError::Unauthorized)));
+
+    client.invite_member(&creator, &group_id, &member2, &86400u64);
+    client.join_group(&member2, &group_id);
+
+    assert!(client.is_member(&group_id, &member2));
+    assert!(client.get_invitation(&group_id, &member2).accepted);
+}
+
+#[test]
+fn test_accept_invitation_joins_group() {
+    let (_env, client, creator, member2, token) = setup_test_env();
+    let group_id = create_group(&client, &creator, &token, 5);
+    client.set_group_access_type(&creator, &group_id, &GroupAccessType::InviteOnly);
+
+    client.invite_member(&creator, &group_id, &member2, &86400u64);
+    assert_eq!(client.get_group_invitations(&group_id).len(), 1);
+
+    client.accept_invitation(&member2, &group_id);
+    assert!(client.is_member(&group_id, &member2));
+
+    let invitations = client.get_group_invitations(&group_id);
+    assert_eq!(invitations.len(), 1);
+    assert!(invitations.get(0).unwrap().accepted);
+
+    let result = client.try_accept_invitation(&member2, &group_id);
+    assert_eq!(result, Err(Ok(AjoError::AlreadyMember)));
+}
+
+#[test]
+fn test_expired_invitation_rejected() {
+    let (env, client, creator, member2, token) = setup_test_env();
+    let group_id = create_group(&client, &creator, &token, 5);
+    client.set_group_access_type(&creator, &group_id, &GroupAccessType::InviteOnly);
+
+    client.invite_member(&creator, &group_id, &member2, &3600u64);
+    env.ledger().with_mut(|li| {
+        li.timestamp += 3601;
+    });
+
+    let result = client.try_accept_invitation(&member2, &group_id);
+    assert_eq!(result, Err(Ok(AjoError::Unauthorized)));
+    let result = client.try_join_group(&member2, &group_id);
+    assert_eq!(result, Err(Ok(AjoError::Unauthorized)));
+}
+
+#[test]
+fn test_revoke_invitation() {
+    let (_env, client, creator, member2, token) = setup_test_env();
+    let group_id = create_group(&client, &creator, &token, 5);
+    client.set_group_access_type(&creator, &group_id, &GroupAccessType::InviteOnly);
+
+    client.invite_member(&creator, &group_id, &member2, &86400u64);
+    client.revoke_invitation(&creator, &group_id, &member2);
+
+    assert_eq!(client.get_group_invitations(&group_id).len(), 0);
+    let result = client.try_accept_invitation(&member2, &group_id);
+    assert_eq!(result, Err(Ok(AjoError::Unauthorized)));
+}
+
+#[test]
+fn test_approval_required_flow() {
+    let (env, client, creator, member2, token) = setup_test_env();
+    let member3 = Address::generate(&env);
+    let group_id = create_group(&client, &creator, &token, 5);
+    client.set_group_access_type(&creator, &group_id, &GroupAccessType::ApprovalRequired);
+
+    let result = client.try_join_group(&member2, &group_id);
+    assert_eq!(result, Err(Ok(AjoError::Unauthorized)));
+
+    client.request_to_join(&member2, &group_id);
+    client.request_to_join(&member3, &group_id);
+    let result = client.try_request_to_join(&member2, &group_id);
+    assert_eq!(result, Err(Ok(AjoError::InvalidState)));
+    assert_eq!(client.get_pending_join_requests(&group_id).len(), 2);
+
+    client.approve_join_request(&creator, &group_id, &member2);
+    assert!(client.is_member(&group_id, &member2));
+
+    client.reject_join_request(&creator, &group_id, &member3);
+    assert!(!client.is_member(&group_id, &member3));
+    assert_eq!(client.get_pending_join_requests(&group_id).len(), 0);
+
+    let result = client.try_approve_join_request(&creator, &group_id, &member3);
+    assert_eq!(result, Err(Ok(AjoError::InvalidInput)));
+}
+
+#[test]
+fn test_request_to_join_open_group_rejected() {
+    let (_env, client, creator, member2, token) = setup_test_env();
+    let group_id = create_group(&client, &creator, &token, 5);
+
+    let result = client.try_request_to_join(&member2, &group_id);
+    assert_eq!(result, Err(Ok(AjoError::Unauthorized)));
+}
+
+#[test]
+fn test_member_who_left_can_request_again() {
+    let (_env, client, creator, member2, token) = setup_test_env();
+    let group_id = create_group(&client, &creator, &token, 5);
+    client.set_group_access_type(&creator, &group_id, &GroupAccessType::ApprovalRequired);
+
+    client.request_to_join(&member2, &group_id);
+    client.approve_join_request(&creator, &group_id, &member2);
+    client.leave_group(&member2, &group_id);
+
+    client.request_to_join(&member2, &group_id);
+    assert_eq!(client.get_pending_join_requests(&group_id).len(), 1);
+}
+
+#[test]
+fn test_invitation_expiry_saturates() {
+    let (_env, client, creator, member2, token) = setup_test_env();
+    let group_id = create_group(&client, &creator, &token, 5);
+    client.set_group_access_type(&creator, &group_id, &GroupAccessType::InviteOnly);
+
+    client.invite_member(&creator, &group_id, &member2, &u64::MAX);
+    assert_eq!(client.get_invitation(&group_id, &member2).expires_at, u64::MAX);
+
+    client.accept_invitation(&member2, &group_id);
+    assert!(client.is_member(&group_id, &member2));
+}
@@ -0,0 +1,211 @@
+#![cfg(test)]
+
+use soroban_ajo::{
+    AjoContract, AjoContractClient, AjoError, DiscountSettlement, PayoutOrderingStrategy,
+};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env,
+};
+
+const CONTRIBUTION: i128 = 100_000_000;
+const CYCLE_DURATION: u64 = 604_800;
+const GRACE_PERIOD: u64 = 86_400;
+const INITIAL_BALANCE: i128 = 1_000_000_000;
+
+/// Helper function to create a test environment and contract
+fn setup_test_env() -> (
+    Env,
+    AjoContractClient<'static>,
+    Address,
+    Address,
+    Address,
+    Address,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AjoContract);
+    let client = AjoContractClient::new(&env, &contract_id);
+
+    let creator = Address::generate(&env);
+    let member2 = Address::generate(&env);
+    let member3 = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = env.register_stellar_asset_contract(token_admin);
+
+    let token_client = token::StellarAssetClient::new(&env, &token);
+    for account in [&creator, &member2, &member3] {
+        token_client.mint(account, &INITIAL_BALANCE);
+    }
+
+    (env, client, creator, member2, member3, token)
+}
+
+fn create_auction_group(
+    client: &AjoContractClient,
+    creator: &Address,
+    member2: &Address,
+    member3: &Address,
+    token: &Address,
+) -> u64 {
+    let group_id = client.create_group_with_ordering(
+        creator,
+        token,
+        &CONTRIBUTION,
+        &CYCLE_DURATION,
+        &3u32,
+        &GRACE_PERIOD,
+        &5u32,
+        &0u32,
+        &PayoutOrderingStrategy::Auction,
+    );
+    client.join_group(member2, &group_id);
+    client.join_group(member3, &group_id);
+    group_id
+}
+
+fn contribute_all_and_pay(env: &Env, client: &AjoContractClient, group_id: u64) {
+    for member in client.list_members(&group_id).iter() {
+        client.contribute(&member, &group_id);
+    }
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE_DURATION + GRACE_PERIOD + 1;
+    });
+    client.execute_payout(&group_id);
+}
+
+#[test]
+fn test_largest_discount_wins_and_is_shared() {
+    let (env, client, creator, member2, member3, token) = setup_test_env();
+    let group_id = create_auction_group(&client, &creator, &member2, &member3, &token);
+    let token_client = token::Client::new(&env, &token);
+
+    client.place_payout_bid(&member2, &group_id, &20_000_000);
+    client.place_payout_bid(&member3, &group_id, &30_000_000);
+    assert_eq!(client.get_group_status(&group_id).next_recipient, member3);
+
+    contribute_all_and_pay(&env, &client, group_id);
+
+    assert_eq!(client.get_payout_order(&group_id, &1u32).recipient, member3);
+    assert_eq!(
+        token_client.balance(&member3),
+        INITIAL_BALANCE - CONTRIBUTION + CONTRIBUTION * 3 - 30_000_000
+    );
+    assert_eq!(
+        token_client.balance(&creator),
+        INITIAL_BALANCE - CONTRIBUTION + 15_000_000
+    );
+    assert_eq!(
+        token_client.balance(&member2),
+        INITIAL_BALANCE - CONTRIBUTION + 15_000_000
+    );
+    assert!(client.is_solvent(&token));
+}
+
+#[test]
+fn test_defaulters_get_no_share_of_discount() {
+    let (env, client, creator, member2, member3, token) = setup_test_env();
+    let group_id = create_auction_group(&client, &creator, &member2, &member3, &token);
+    let token_client = token::Client::new(&env, &token);
+
+    client.place_payout_bid(&member3, &group_id, &30_000_000);
+    client.contribute(&creator, &group_id);
+    client.contribute(&member3, &group_id);
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE_DURATION + GRACE_PERIOD + 1;
+    });
+    client.mark_defaults(&group_id);
+    client.execute_payout(&group_id);
+
+    // member2 defaulted, so the whole discount goes to the creator
+    assert_eq!(
+        token_client.balance(&creator),
+        INITIAL_BALANCE - CONTRIBUTION + 30_000_000
+    );
+    assert_eq!(token_client.balance(&member2), INITIAL_BALANCE);
+    assert_eq!(
+        token_client.balance(&member3),
+        INITIAL_BALANCE - CONTRIBUTION + CONTRIBUTION * 2 - 30_000_000
+    );
+    assert!(client.is_solvent(&token));
+}
+
+#[test]
+fn test_equal_bids_go_to_earliest_and_no_bids_to_join_order() {
+    let (env, client, creator, member2, member3, token) = setup_test_env();
+    let group_id = create_auction_group(&client, &creator, &member2, &member3, &token);
+
+    client.place_payout_bid(&member3, &group_id, &10_000_000);
+    client.place_payout_bid(&member2, &group_id, &10_000_000);
+    contribute_all_and_pay(&env, &client, group_id);
+    assert_eq!(client.get_payout_order(&group_id, &1u32).recipient, member3);
+
+    // Nobody bids in cycle 2: first unpaid member in join order takes the pot
+    contribute_all_and_pay(&env, &client, group_id);
+    assert_eq!(client.get_payout_order(&group_id, &2u32).recipient, creator);
+}
+
+#[test]
+fn test_discount_credited_against_next_contribution() {
+    let (env, client, creator, member2, member3, token) = setup_test_env();
+    let group_id = create_auction_group(&client, &creator, &member2, &member3, &token);
+    let token_client = token::Client::new(&env, &token);
+    client.set_discount_settlement(
+        &creator,
+        &group_id,
+        &DiscountSettlement::CreditNextContribution,
+    );
+
+    client.place_payout_bid(&member3, &group_id, &30_000_000);
+    contribute_all_and_pay(&env, &client, group_id);
+    assert_eq!(client.get_auction_credit(&group_id, &creator), 15_000_000);
+    assert_eq!(
+        token_client.balance(&creator),
+        INITIAL_BALANCE - CONTRIBUTION
+    );
+
+    client.contribute(&creator, &group_id);
+    assert_eq!(
+        token_client.balance(&creator),
+        INITIAL_BALANCE - CONTRIBUTION * 2 + 15_000_000
+    );
+    assert_eq!(client.get_auction_credit(&group_id, &creator), 0);
+    assert!(client.is_solvent(&token));
+}
+
+#[test]
+fn test_bidding_closes_at_contribution_deadline() {
+    let (env, client, creator, member2, member3, token) = setup_test_env();
+    let group_id = create_auction_group(&client, &creator, &member2, &member3, &token);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE_DURATION + 1;
+    });
+    let result = client.try_place_payout_bid(&member2, &group_id, &10_000_000);
+    assert_eq!(result, Err(Ok(AjoError::OutsideCycleWindow)));
+}
+
+#[test]
+fn test_invalid_bids_rejected() {
+    let (_env, client, creator, member2, member3, token) = setup_test_env();
+    let group_id = create_auction_group(&client, &creator, &member2, &member3, &token);
+
+    let result = client.try_place_payout_bid(&member2, &group_id, &0i128);
+    assert_eq!(result, Err(Ok(AjoError::InvalidInput)));
+    let result = client.try_place_payout_bid(&member2, &group_id, &(CONTRIBUTION * 3));
+    assert_eq!(result, Err(Ok(AjoError::InvalidInput)));
+
+    let sequential = client.create_group(
+        &creator,
+        &token,
+        &CONTRIBUTION,
+        &CYCLE_DURATION,
+        &3u32,
+        &GRACE_PERIOD,
+        &5u32,
+        &0u32,
+    );
+    let result = client.try_place_payout_bid(&creator, &sequential, &10_000_000);
+    assert_eq!(result, Err(Ok(AjoError::InvalidStrategy)));
+}
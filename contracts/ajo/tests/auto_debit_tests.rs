@@ -0,0 +1,226 @@
+#![cfg(test)]
+
+use soroban_ajo::{AjoContract, AjoContractClient, AjoError};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, Vec,
+};
+
+const CONTRIBUTION: i128 = 100_000_000;
+const CYCLE_DURATION: u64 = 604_800;
+const GRACE_PERIOD: u64 = 86_400;
+const STARTING_BALANCE: i128 = 1_000_000_000;
+const ALLOWANCE_EXPIRY: u32 = 10_000;
+
+/// Helper function to create a test environment and contract
+fn setup_test_env() -> (Env, AjoContractClient<'static>, Vec<Address>, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AjoContract);
+    let client = AjoContractClient::new(&env, &contract_id);
+
+    let token_admin = Address::generate(&env);
+    let token = env.register_stellar_asset_contract(token_admin);
+    let token_client = token::StellarAssetClient::new(&env, &token);
+
+    let mut members = Vec::new(&env);
+    for _ in 0..3 {
+        let member = Address::generate(&env);
+        token_client.mint(&member, &STARTING_BALANCE);
+        members.push_back(member);
+    }
+
+    (env, client, members, token)
+}
+
+/// Creates a full three-member group
+fn create_full_group(client: &AjoContractClient, members: &Vec<Address>, token: &Address) -> u64 {
+    let group_id = client.create_group(
+        &members.get(0).unwrap(),
+        token,
+        &CONTRIBUTION,
+        &CYCLE_DURATION,
+        &3u32,
+        &GRACE_PERIOD,
+        &5u32,
+        &0u32,
+    );
+    client.join_group(&members.get(1).unwrap(), &group_id);
+    client.join_group(&members.get(2).unwrap(), &group_id);
+    group_id
+}
+
+/// Opts a member into auto-debit and approves the contract for `allowance`
+fn enable_auto_debit(
+    env: &Env,
+    client: &AjoContractClient,
+    token: &Address,
+    member: &Address,
+    group_id: u64,
+    allowance: i128,
+) {
+    client.set_auto_debit(member, &group_id, &true);
+    token::Client::new(env, token).approve(member, &client.address, &allowance, &ALLOWANCE_EXPIRY);
+}
+
+fn advance_to_deadline(env: &Env) {
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE_DURATION - 3_600;
+    });
+}
+
+#[test]
+fn test_collects_from_approved_members() {
+    let (env, client, members, token) = setup_test_env();
+    let creator = members.get(0).unwrap();
+    let manual = members.get(2).unwrap();
+    let token_client = token::Client::new(&env, &token);
+
+    let group_id = create_full_group(&client, &members, &token);
+    for member in [creator.clone(), members.get(1).unwrap()] {
+        enable_auto_debit(&env, &client, &token, &member, group_id, 10 * CONTRIBUTION);
+    }
+    assert_eq!(client.get_auto_debit_members(&group_id).len(), 2);
+
+    advance_to_deadline(&env);
+    let report = client.collect_contributions(&group_id);
+    assert_eq!(report.cycle, 1);
+    assert_eq!(report.collected.len(), 2);
+    assert_eq!(report.insufficient_allowance.len(), 0);
+    assert_eq!(report.insufficient_balance.len(), 0);
+    assert_eq!(
+        token_client.balance(&creator),
+        STARTING_BALANCE - CONTRIBUTION
+    );
+    assert_eq!(
+        token_client.allowance(&creator, &client.address),
+        9 * CONTRIBUTION
+    );
+
+    client.contribute(&manual, &group_id);
+    env.ledger().with_mut(|li| {
+        li.timestamp += 3_600 + GRACE_PERIOD;
+    });
+    client.execute_payout(&group_id);
+    assert_eq!(client.get_group(&group_id).current_cycle, 2);
+}
+
+#[test]
+fn test_shortfalls_reported_without_failing_batch() {
+    let (env, client, members, token) = setup_test_env();
+    let paying = members.get(0).unwrap();
+    let unapproved = members.get(1).unwrap();
+    let broke = members.get(2).unwrap();
+    let token_client = token::Client::new(&env, &token);
+
+    let group_id = create_full_group(&client, &members, &token);
+    enable_auto_debit(&env, &client, &token, &paying, group_id, CONTRIBUTION);
+    enable_auto_debit(
+        &env,
+        &client,
+        &token,
+        &unapproved,
+        group_id,
+        CONTRIBUTION / 2,
+    );
+    enable_auto_debit(&env, &client, &token, &broke, group_id, CONTRIBUTION);
+    token_client.transfer(&broke, &paying, &(STARTING_BALANCE - CONTRIBUTION / 2));
+
+    advance_to_deadline(&env);
+    let report = client.collect_contributions(&group_id);
+    assert_eq!(report.collected, Vec::from_array(&env, [paying.clone()]));
+    assert_eq!(
+        report.insufficient_allowance,
+        Vec::from_array(&env, [unapproved.clone()])
+    );
+    assert_eq!(
+        report.insufficient_balance,
+        Vec::from_array(&env, [broke.clone()])
+    );
+
+    // Nothing was taken from the members who fell short
+    assert_eq!(token_client.balance(&unapproved), STARTING_BALANCE);
+    assert_eq!(token_client.balance(&broke), CONTRIBUTION / 2);
+
+    // A later attempt picks up the member once the allowance is topped up
+    token_client.approve(
+        &unapproved,
+        &client.address,
+        &CONTRIBUTION,
+        &ALLOWANCE_EXPIRY,
+    );
+    let report = client.collect_contributions(&group_id);
+    assert_eq!(
+        report.collected,
+        Vec::from_array(&env, [unapproved.clone()])
+    );
+}
+
+#[test]
+fn test_collection_window() {
+    let (env, client, members, token) = setup_test_env();
+    let member = members.get(1).unwrap();
+    let token_client = token::Client::new(&env, &token);
+
+    let group_id = create_full_group(&client, &members, &token);
+    enable_auto_debit(&env, &client, &token, &member, group_id, 10 * CONTRIBUTION);
+
+    let result = client.try_collect_contributions(&group_id);
+    assert_eq!(result, Err(Ok(AjoError::OutsideCycleWindow)));
+
+    // Collected during the grace period with the late penalty
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE_DURATION + 1;
+    });
+    client.collect_contributions(&group_id);
+    assert_eq!(
+        token_client.balance(&member),
+        STARTING_BALANCE - CONTRIBUTION - CONTRIBUTION * 5 / 100
+    );
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += GRACE_PERIOD;
+    });
+    let result = client.try_collect_contributions(&group_id);
+    assert_eq!(result, Err(Ok(AjoError::OutsideCycleWindow)));
+}
+
+#[test]
+fn test_opt_out_stops_collection() {
+    let (env, client, members, token) = setup_test_env();
+    let member = members.get(1).unwrap();
+    let token_client = token::Client::new(&env, &token);
+
+    let group_id = create_full_group(&client, &members, &token);
+    enable_auto_debit(&env, &client, &token, &member, group_id, 10 * CONTRIBUTION);
+    client.set_auto_debit(&member, &group_id, &false);
+    assert_eq!(client.get_auto_debit_members(&group_id).len(), 0);
+
+    advance_to_deadline(&env);
+    let report = client.collect_contributions(&group_id);
+    assert_eq!(report.collected.len(), 0);
+    assert_eq!(token_client.balance(&member), STARTING_BALANCE);
+}
+
+#[test]
+fn test_members_who_already_paid_are_skipped() {
+    let (env, client, members, token) = setup_test_env();
+    let member = members.get(1).unwrap();
+    let token_client = token::Client::new(&env, &token);
+
+    let group_id = create_full_group(&client, &members, &token);
+    enable_auto_debit(&env, &client, &token, &member, group_id, 10 * CONTRIBUTION);
+    client.contribute(&member, &group_id);
+
+    advance_to_deadline(&env);
+    let report = client.collect_contributions(&group_id);
+    assert_eq!(report.collected.len(), 0);
+    assert_eq!(
+        token_client.balance(&member),
+        STARTING_BALANCE - CONTRIBUTION
+    );
+
+    let result = client.try_set_auto_debit(&Address::generate(&env), &group_id, &true);
+    assert_eq!(result, Err(Ok(AjoError::NotMember)));
+}
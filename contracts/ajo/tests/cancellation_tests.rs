@@ -91,7 +91,7 @@ fn test_only_creator_can_cancel() {
 
     // Non-creator tries to cancel - should fail
     let result = client.try_cancel_group(&member2, &group_id);
-    assert_eq!(result, Err(Ok(AjoError::OnlyCreatorCanCancel)));
+    assert_eq!(result, Err(Ok(AjoError::Unauthorized)));
 }
 
 #[test]
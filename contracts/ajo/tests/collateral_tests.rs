@@ -0,0 +1,166 @@
+#![cfg(test)]
+
+use soroban_ajo::{AjoContract, AjoContractClient, AjoError, GroupAccessType};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env,
+};
+
+const CONTRIBUTION: i128 = 100_000_000;
+const COLLATERAL: i128 = 60_000_000;
+const CYCLE_DURATION: u64 = 604_800;
+const GRACE_PERIOD: u64 = 86_400;
+const INITIAL_BALANCE: i128 = 1_000_000_000;
+
+/// Helper function to create a test environment and contract
+fn setup_test_env() -> (Env, AjoContractClient<'static>, Address, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AjoContract);
+    let client = AjoContractClient::new(&env, &contract_id);
+
+    let creator = Address::generate(&env);
+    let member2 = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = env.register_stellar_asset_contract(token_admin);
+
+    let token_client = token::StellarAssetClient::new(&env, &token);
+    token_client.mint(&creator, &INITIAL_BALANCE);
+    token_client.mint(&member2, &INITIAL_BALANCE);
+
+    (env, client, creator, member2, token)
+}
+
+fn create_collateral_group(client: &AjoContractClient, creator: &Address, token: &Address) -> u64 {
+    let group_id = client.create_group(
+        creator,
+        token,
+        &CONTRIBUTION,
+        &CYCLE_DURATION,
+        &2u32,
+        &GRACE_PERIOD,
+        &5u32,
+        &0u32,
+    );
+    client.set_collateral_amount(creator, &group_id, &COLLATERAL);
+    group_id
+}
+
+fn advance_past_grace_period(env: &Env) {
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE_DURATION + GRACE_PERIOD + 1;
+    });
+}
+
+#[test]
+fn test_join_escrows_collateral() {
+    let (env, client, creator, member2, token) = setup_test_env();
+    let group_id = create_collateral_group(&client, &creator, &token);
+    let token_client = token::Client::new(&env, &token);
+
+    assert_eq!(token_client.balance(&creator), INITIAL_BALANCE - COLLATERAL);
+
+    client.join_group(&member2, &group_id);
+    assert_eq!(token_client.balance(&member2), INITIAL_BALANCE - COLLATERAL);
+    assert_eq!(
+        client.get_member_collateral(&group_id, &member2),
+        COLLATERAL
+    );
+
+    let status = client.get_group_status(&group_id);
+    assert_eq!(status.collateral_amount, COLLATERAL);
+    assert_eq!(status.collateral_balances.len(), 2);
+    assert_eq!(status.collateral_balances.get(1).unwrap().member, member2);
+    assert_eq!(
+        status.collateral_balances.get(1).unwrap().amount,
+        COLLATERAL
+    );
+
+    assert_eq!(
+        client.get_token_liabilities(&token).collateral,
+        COLLATERAL * 2
+    );
+}
+
+#[test]
+fn test_collateral_locked_once_members_join() {
+    let (_env, client, creator, member2, token) = setup_test_env();
+    let group_id = create_collateral_group(&client, &creator, &token);
+    client.join_group(&member2, &group_id);
+
+    let result = client.try_set_collateral_amount(&creator, &group_id, &0i128);
+    assert_eq!(result, Err(Ok(AjoError::InvalidState)));
+}
+
+#[test]
+fn test_default_slashes_collateral_into_pot() {
+    let (env, client, creator, member2, token) = setup_test_env();
+    let group_id = create_collateral_group(&client, &creator, &token);
+    let token_client = token::Client::new(&env, &token);
+    client.join_group(&member2, &group_id);
+
+    client.contribute(&creator, &group_id);
+    advance_past_grace_period(&env);
+    client.mark_defaults(&group_id);
+
+    let record = client.get_cycle_defaults(&group_id, &1u32).get(0).unwrap();
+    assert_eq!(record.collateral_applied, COLLATERAL);
+    assert_eq!(client.get_member_collateral(&group_id, &member2), 0);
+
+    let before = token_client.balance(&creator);
+    client.execute_payout(&group_id);
+    assert_eq!(
+        token_client.balance(&creator) - before,
+        CONTRIBUTION + COLLATERAL
+    );
+    assert!(client.is_solvent(&token));
+}
+
+#[test]
+fn test_collateral_returned_on_completion() {
+    let (env, client, creator, member2, token) = setup_test_env();
+    let group_id = create_collateral_group(&client, &creator, &token);
+    let token_client = token::Client::new(&env, &token);
+    client.join_group(&member2, &group_id);
+
+    for _ in 0..2 {
+        client.contribute(&creator, &group_id);
+        client.contribute(&member2, &group_id);
+        advance_past_grace_period(&env);
+        client.execute_payout(&group_id);
+    }
+
+    assert!(client.get_group(&group_id).is_complete);
+    assert_eq!(token_client.balance(&creator), INITIAL_BALANCE);
+    assert_eq!(token_client.balance(&member2), INITIAL_BALANCE);
+    assert_eq!(client.get_token_liabilities(&token).collateral, 0);
+}
+
+#[test]
+fn test_collateral_returned_on_cancel() {
+    let (env, client, creator, member2, token) = setup_test_env();
+    let group_id = create_collateral_group(&client, &creator, &token);
+    let token_client = token::Client::new(&env, &token);
+    client.join_group(&member2, &group_id);
+
+    client.cancel_group(&creator, &group_id);
+
+    assert_eq!(token_client.balance(&creator), INITIAL_BALANCE);
+    assert_eq!(token_client.balance(&member2), INITIAL_BALANCE);
+}
+
+#[test]
+fn test_join_request_escrows_and_rejection_returns() {
+    let (env, client, creator, member2, token) = setup_test_env();
+    let group_id = create_collateral_group(&client, &creator, &token);
+    let token_client = token::Client::new(&env, &token);
+    client.set_group_access_type(&creator, &group_id, &GroupAccessType::ApprovalRequired);
+
+    client.request_to_join(&member2, &group_id);
+    assert_eq!(token_client.balance(&member2), INITIAL_BALANCE - COLLATERAL);
+
+    client.reject_join_request(&creator, &group_id, &member2);
+    assert_eq!(token_client.balance(&member2), INITIAL_BALANCE);
+    assert_eq!(client.get_member_collateral(&group_id, &member2), 0);
+}
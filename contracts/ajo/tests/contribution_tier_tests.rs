@@ -0,0 +1,252 @@
+#![cfg(test)]
+
+use soroban_ajo::{AjoContract, AjoContractClient, AjoError, GroupAccessType};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, Vec,
+};
+
+const CONTRIBUTION: i128 = 100_000_000;
+const CYCLE_DURATION: u64 = 604_800;
+const GRACE_PERIOD: u64 = 86_400;
+const STARTING_BALANCE: i128 = 10_000_000_000;
+
+/// Helper function to create a test environment and contract
+fn setup_test_env() -> (Env, AjoContractClient<'static>, Vec<Address>, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AjoContract);
+    let client = AjoContractClient::new(&env, &contract_id);
+
+    let token_admin = Address::generate(&env);
+    let token = env.register_stellar_asset_contract(token_admin);
+    let token_client = token::StellarAssetClient::new(&env, &token);
+
+    let mut members = Vec::new(&env);
+    for _ in 0..3 {
+        let member = Address::generate(&env);
+        token_client.mint(&member, &STARTING_BALANCE);
+        members.push_back(member);
+    }
+
+    (env, client, members, token)
+}
+
+fn create_group(
+    client: &AjoContractClient,
+    creator: &Address,
+    token: &Address,
+    amount: i128,
+) -> u64 {
+    client.create_group(
+        creator,
+        token,
+        &amount,
+        &CYCLE_DURATION,
+        &3u32,
+        &GRACE_PERIOD,
+        &5u32,
+        &0u32,
+    )
+}
+
+fn contribute_all_and_pay(env: &Env, client: &AjoContractClient, group_id: u64) {
+    for member in client.list_members(&group_id).iter() {
+        client.contribute(&member, &group_id);
+    }
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE_DURATION + GRACE_PERIOD;
+    });
+    client.execute_payout(&group_id);
+}
+
+#[test]
+fn test_tiered_members_get_back_what_they_paid() {
+    let (env, client, members, token) = setup_test_env();
+    let creator = members.get(0).unwrap();
+    let middle = members.get(1).unwrap();
+    let high = members.get(2).unwrap();
+    let token_client = token::Client::new(&env, &token);
+
+    let group_id = create_group(&client, &creator, &token, CONTRIBUTION);
+    client.join_group_with_tier(&middle, &group_id, &(2 * CONTRIBUTION));
+    client.join_group_with_tier(&high, &group_id, &(3 * CONTRIBUTION));
+    assert_eq!(
+        client.get_contribution_tier(&group_id, &creator),
+        CONTRIBUTION
+    );
+    assert_eq!(
+        client.get_contribution_tier(&group_id, &high),
+        3 * CONTRIBUTION
+    );
+
+    // Each member pays their own tier
+    client.contribute(&high, &group_id);
+    assert_eq!(
+        token_client.balance(&high),
+        STARTING_BALANCE - 3 * CONTRIBUTION
+    );
+    client.contribute(&creator, &group_id);
+    client.contribute(&middle, &group_id);
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE_DURATION + GRACE_PERIOD;
+    });
+    client.execute_payout(&group_id);
+
+    // The lowest tier is paid three contributions of its own, not the whole pot
+    assert_eq!(
+        token_client.balance(&creator),
+        STARTING_BALANCE - CONTRIBUTION + 3 * CONTRIBUTION
+    );
+
+    contribute_all_and_pay(&env, &client, group_id);
+    contribute_all_and_pay(&env, &client, group_id);
+    assert!(client.get_group(&group_id).is_complete);
+
+    for member in members.iter() {
+        assert_eq!(token_client.balance(&member), STARTING_BALANCE);
+        assert_eq!(client.get_tier_owed(&group_id, &member), 0);
+    }
+    assert_eq!(token_client.balance(&client.address), 0);
+}
+
+#[test]
+fn test_higher_tier_paid_first_is_owed_the_rest() {
+    let (env, client, members, token) = setup_test_env();
+    let creator = members.get(0).unwrap();
+    let low = members.get(1).unwrap();
+    let middle = members.get(2).unwrap();
+    let token_client = token::Client::new(&env, &token);
+
+    let group_id = create_group(&client, &creator, &token, 3 * CONTRIBUTION);
+    client.join_group_with_tier(&low, &group_id, &CONTRIBUTION);
+    client.join_group_with_tier(&middle, &group_id, &(2 * CONTRIBUTION));
+
+    // The pot only holds six contributions, so three of nine are owed
+    contribute_all_and_pay(&env, &client, group_id);
+    assert_eq!(client.get_tier_owed(&group_id, &creator), 3 * CONTRIBUTION);
+    assert_eq!(
+        token_client.balance(&creator),
+        STARTING_BALANCE - 3 * CONTRIBUTION + 6 * CONTRIBUTION
+    );
+
+    // The low tier's payout leaves enough of the pot to settle the rest
+    contribute_all_and_pay(&env, &client, group_id);
+    assert_eq!(client.get_tier_owed(&group_id, &creator), 0);
+
+    contribute_all_and_pay(&env, &client, group_id);
+    for member in members.iter() {
+        assert_eq!(token_client.balance(&member), STARTING_BALANCE);
+    }
+}
+
+#[test]
+fn test_default_in_mixed_tier_group_shrinks_shares_and_clears_balances() {
+    let (env, client, members, token) = setup_test_env();
+    let creator = members.get(0).unwrap();
+    let middle = members.get(1).unwrap();
+    let high = members.get(2).unwrap();
+    let token_client = token::Client::new(&env, &token);
+
+    let group_id = create_group(&client, &creator, &token, CONTRIBUTION);
+    client.join_group_with_tier(&middle, &group_id, &(2 * CONTRIBUTION));
+    client.join_group_with_tier(&high, &group_id, &(3 * CONTRIBUTION));
+
+    // The high tier defaults, so the pot holds three of six contributions
+    client.contribute(&creator, &group_id);
+    client.contribute(&middle, &group_id);
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE_DURATION + GRACE_PERIOD + 1;
+    });
+    client.mark_defaults(&group_id);
+    client.execute_payout(&group_id);
+
+    // The creator gets half of their usual three contributions
+    assert_eq!(
+        token_client.balance(&creator),
+        STARTING_BALANCE - CONTRIBUTION + 3 * CONTRIBUTION / 2
+    );
+
+    contribute_all_and_pay(&env, &client, group_id);
+    contribute_all_and_pay(&env, &client, group_id);
+    assert!(client.get_group(&group_id).is_complete);
+
+    // Nothing is left held back or owed once the round completes
+    for member in members.iter() {
+        assert_eq!(client.get_tier_owed(&group_id, &member), 0);
+    }
+    assert_eq!(token_client.balance(&client.address), 0);
+    assert!(client.is_solvent(&token));
+}
+
+#[test]
+fn test_invitation_sets_tier() {
+    let (env, client, members, token) = setup_test_env();
+    let creator = members.get(0).unwrap();
+    let invitee = members.get(1).unwrap();
+    let token_client = token::Client::new(&env, &token);
+
+    let group_id = create_group(&client, &creator, &token, CONTRIBUTION);
+    client.set_group_access_type(&creator, &group_id, &GroupAccessType::InviteOnly);
+
+    let result = client.try_join_group_with_tier(&invitee, &group_id, &(2 * CONTRIBUTION));
+    assert_eq!(result, Err(Ok(AjoError::Unauthorized)));
+
+    client.invite_member_with_tier(&creator, &group_id, &invitee, &86_400, &(2 * CONTRIBUTION));
+    assert_eq!(
+        client
+            .get_invitation(&group_id, &invitee)
+            .contribution_amount,
+        2 * CONTRIBUTION
+    );
+    client.join_group(&invitee, &group_id);
+    assert_eq!(
+        client.get_contribution_tier(&group_id, &invitee),
+        2 * CONTRIBUTION
+    );
+
+    client.contribute(&invitee, &group_id);
+    assert_eq!(
+        token_client.balance(&invitee),
+        STARTING_BALANCE - 2 * CONTRIBUTION
+    );
+}
+
+#[test]
+fn test_late_penalty_uses_member_tier() {
+    let (env, client, members, token) = setup_test_env();
+    let creator = members.get(0).unwrap();
+    let member = members.get(1).unwrap();
+    let token_client = token::Client::new(&env, &token);
+
+    let group_id = create_group(&client, &creator, &token, CONTRIBUTION);
+    client.join_group_with_tier(&member, &group_id, &(4 * CONTRIBUTION));
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE_DURATION + 1;
+    });
+    client.contribute(&member, &group_id);
+
+    // 5% of the member's own contribution
+    let penalty = 4 * CONTRIBUTION * 5 / 100;
+    assert_eq!(
+        token_client.balance(&member),
+        STARTING_BALANCE - 4 * CONTRIBUTION - penalty
+    );
+}
+
+#[test]
+fn test_invalid_tier_rejected() {
+    let (_env, client, members, token) = setup_test_env();
+    let creator = members.get(0).unwrap();
+    let member = members.get(1).unwrap();
+
+    let group_id = create_group(&client, &creator, &token, CONTRIBUTION);
+
+    let result = client.try_join_group_with_tier(&member, &group_id, &0);
+    assert_eq!(result, Err(Ok(AjoError::ContributionAmountZero)));
+
+    let result = client.try_join_group_with_tier(&member, &group_id, &-1);
+    assert_eq!(result, Err(Ok(AjoError::ContributionAmountNegative)));
+}
@@ -0,0 +1,216 @@
+#![cfg(test)]
+
+use soroban_ajo::{AjoContract, AjoContractClient, AjoError};
+use soroban_sdk::{testutils::{Address as _, Ledger}, token, Address, Env};
+
+const CONTRIBUTION: i128 = 100_000_000;
+const CYCLE_DURATION: u64 = 604_800;
+const GRACE_PERIOD: u64 = 86_400;
+
+/// Helper function to create a test environment and contract
+fn setup_test_env() -> (Env, AjoContractClient<'static>, Address, Address, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AjoContract);
+    let client = AjoContractClient::new(&env, &contract_id);
+
+    let creator = Address::generate(&env);
+    let member2 = Address::generate(&env);
+    let member3 = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = env.register_stellar_asset_contract(token_admin);
+
+    (env, client, creator, member2, member3, token)
+}
+
+fn mint_tokens(env: &Env, token_id: &Address, members: &[Address], amount: i128) {
+    let token_client = token::StellarAssetClient::new(env, token_id);
+    for member in members {
+        token_client.mint(member, &amount);
+    }
+}
+
+fn create_three_member_group(
+    env: &Env,
+    client: &AjoContractClient,
+    members: &[Address; 3],
+    token: &Address,
+) -> u64 {
+    let group_id = client.create_group(
+        &members[0],
+        token,
+        &CONTRIBUTION,
+        &CYCLE_DURATION,
+        &3u32,
+        &GRACE_PERIOD,
+        &5u32,
+        &0u32,
+    );
+    client.join_group(&members[1], &group_id);
+    client.join_group(&members[2], &group_id);
+    mint_tokens(env, token, members, 1_000_000_000);
+    group_id
+}
+
+fn advance_past_grace_period(env: &Env) {
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE_DURATION + GRACE_PERIOD + 1;
+    });
+}
+
+#[test]
+fn test_mark_defaults_before_grace_end_fails() {
+    let (env, client, creator, member2, member3, token) = setup_test_env();
+    let group_id = create_three_member_group(&env, &client, &[creator, member2, member3], &token);
+
+    let result = client.try_mark_defaults(&group_id);
+    assert_eq!(result, Err(Ok(AjoError::OutsideCycleWindow)));
+}
+
+#[test]
+fn test_defaulter_no_longer_blocks_payout() {
+    let (env, client, creator, member2, member3, token) = setup_test_env();
+    let group_id = create_three_member_group(
+        &env,
+        &client,
+        &[creator.clone(), member2.clone(), member3.clone()],
+        &token,
+    );
+    let token_client = token::Client::new(&env, &token);
+
+    client.contribute(&creator, &group_id);
+    client.contribute(&member3, &group_id);
+    advance_past_grace_period(&env);
+
+    let result = client.try_execute_payout(&group_id);
+    assert_eq!(result, Err(Ok(AjoError::IncompleteContributions)));
+
+    let defaulted = client.mark_defaults(&group_id);
+    assert_eq!(defaulted.len(), 1);
+    assert_eq!(defaulted.get(0).unwrap(), member2);
+
+    // Re-running for the same cycle records nothing new
+    assert_eq!(client.mark_defaults(&group_id).len(), 0);
+
+    let before = token_client.balance(&creator);
+    client.execute_payout(&group_id);
+    assert_eq!(token_client.balance(&creator) - before, CONTRIBUTION * 2);
+
+    let records = client.get_cycle_defaults(&group_id, &1u32);
+    assert_eq!(records.len(), 1);
+    let record = records.get(0).unwrap();
+    assert_eq!(record.amount_owed, CONTRIBUTION);
+    assert!(!record.payout_forfeited);
+
+    let penalty = client.get_member_penalty_record(&group_id, &member2);
+    assert_eq!(penalty.default_count, 1);
+    assert_eq!(penalty.reliability_score, 0);
+    assert_eq!(client.get_member_stats(&member2).defaulted_contributions, 1);
+}
+
+#[test]
+fn test_partial_installments_stay_in_pot() {
+    let (env, client, creator, member2, member3, token) = setup_test_env();
+    let group_id = create_three_member_group(
+        &env,
+        &client,
+        &[creator.clone(), member2.clone(), member3.clone()],
+        &token,
+    );
+    let token_client = token::Client::new(&env, &token);
+
+    client.contribute(&creator, &group_id);
+    client.contribute(&member3, &group_id);
+    client.contribute_partial(&member2, &group_id, &30_000_000i128);
+    advance_past_grace_period(&env);
+
+    client.mark_defaults(&group_id);
+    let record = client.get_cycle_defaults(&group_id, &1u32).get(0).unwrap();
+    assert_eq!(record.amount_paid, 30_000_000);
+    assert_eq!(record.amount_owed, 70_000_000);
+
+    let before = token_client.balance(&creator);
+    client.execute_payout(&group_id);
+    assert_eq!(token_client.balance(&creator) - before, CONTRIBUTION * 2 + 30_000_000);
+}
+
+#[test]
+fn test_defaulter_keeps_slot_by_default() {
+    let (env, client, creator, member2, member3, token) = setup_test_env();
+    let group_id = create_three_member_group(
+        &env,
+        &client,
+        &[creator.clone(), member2.clone(), member3.clone()],
+        &token,
+    );
+
+    client.contribute(&creator, &group_id);
+    client.contribute(&member3, &group_id);
+    advance_past_grace_period(&env);
+    client.mark_defaults(&group_id);
+    client.execute_payout(&group_id);
+
+    // Member2 is still next in line
+    client.contribute(&creator, &group_id);
+    client.contribute(&member2, &group_id);
+    client.contribute(&member3, &group_id);
+    advance_past_grace_period(&env);
+
+    let token_client = token::Client::new(&env, &token);
+    let before = token_client.balance(&member2);
+    client.execute_payout(&group_id);
+    assert_eq!(token_client.balance(&member2) - before, CONTRIBUTION * 3);
+
+    assert_eq!(client.get_forfeited_members(&group_id).len(), 0);
+    assert!(!client.get_group(&group_id).is_complete);
+}
+
+#[test]
+fn test_defaulter_forfeits_slot_when_configured() {
+    let (env, client, creator, member2, member3, token) = setup_test_env();
+    let group_id = create_three_member_group(
+        &env,
+        &client,
+        &[creator.clone(), member2.clone(), member3.clone()],
+        &token,
+    );
+    let token_client = token::Client::new(&env, &token);
+
+    client.set_default_policy(&creator, &group_id, &true);
+
+    client.contribute(&creator, &group_id);
+    client.contribute(&member3, &group_id);
+    advance_past_grace_period(&env);
+    client.mark_defaults(&group_id);
+    client.execute_payout(&group_id);
+
+    let forfeited = client.get_forfeited_members(&group_id);
+    assert_eq!(forfeited.len(), 1);
+    assert_eq!(forfeited.get(0).unwrap(), member2);
+
+    // Cycle 2 skips member2 and pays member3, completing the group
+    client.contribute(&creator, &group_id);
+    client.contribute(&member2, &group_id);
+    client.contribute(&member3, &group_id);
+    advance_past_grace_period(&env);
+
+    let before = token_client.balance(&member3);
+    client.execute_payout(&group_id);
+    assert_eq!(token_client.balance(&member3) - before, CONTRIBUTION * 3);
+    assert!(client.get_group(&group_id).is_complete);
+}
+
+#[test]
+fn test_only_creator_sets_default_policy() {
+    let (env, client, creator, member2, member3, token) = setup_test_env();
+    let group_id = create_three_member_group(
+        &env,
+        &client,
+        &[creator, member2.clone(), member3],
+        &token,
+    );
+
+    let result = client.try_set_default_policy(&member2, &group_id, &true);
+    assert_eq!(result, Err(Ok(AjoError::Unauthorized)));
+}
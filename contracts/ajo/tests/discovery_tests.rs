@@ -0,0 +1,386 @@
+#![cfg(test)]
+
+use soroban_ajo::{AjoContract, AjoContractClient, AjoError, GroupFilter, GroupState};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String, Vec,
+};
+
+const CONTRIBUTION: i128 = 100_000_000;
+const CYCLE_DURATION: u64 = 604_800;
+const GRACE_PERIOD: u64 = 86_400;
+const STARTING_BALANCE: i128 = 1_000_000_000;
+const FORMATION_PERIOD: u64 = 86_400;
+/// Group ids per discovery index page, as in the contract
+const INDEX_PAGE_SIZE: u64 = 100;
+/// Index pages read per listing call, as in the contract
+const MAX_INDEX_PAGES_SCANNED: u64 = 10;
+
+/// Helper function to create a test environment and contract
+fn setup_test_env() -> (Env, AjoContractClient<'static>, Vec<Address>, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AjoContract);
+    let client = AjoContractClient::new(&env, &contract_id);
+
+    let token_admin = Address::generate(&env);
+    let token = env.register_stellar_asset_contract(token_admin);
+    let token_client = token::StellarAssetClient::new(&env, &token);
+
+    let mut members = Vec::new(&env);
+    for _ in 0..3 {
+        let member = Address::generate(&env);
+        token_client.mint(&member, &STARTING_BALANCE);
+        members.push_back(member);
+    }
+
+    (env, client, members, token)
+}
+
+/// Creates a three-seat group with only its creator in it
+fn create_group(client: &AjoContractClient, creator: &Address, token: &Address) -> u64 {
+    client.create_group(
+        creator,
+        token,
+        &CONTRIBUTION,
+        &CYCLE_DURATION,
+        &3u32,
+        &GRACE_PERIOD,
+        &5u32,
+        &0u32,
+    )
+}
+
+fn listed_ids(
+    env: &Env,
+    client: &AjoContractClient,
+    filter: &GroupFilter,
+    cursor: u64,
+    limit: u32,
+) -> Vec<u64> {
+    let mut ids = Vec::new(env);
+    for summary in client.list_groups(filter, &cursor, &limit).groups.iter() {
+        ids.push_back(summary.id);
+    }
+    ids
+}
+
+#[test]
+fn test_groups_listed_by_state_and_token() {
+    let (env, client, members, token) = setup_test_env();
+    let creator = members.get(0).unwrap();
+    let other_token = env.register_stellar_asset_contract(Address::generate(&env));
+
+    let first = create_group(&client, &creator, &token);
+    let second = create_group(&client, &creator, &other_token);
+    let third = create_group(&client, &creator, &token);
+
+    let active = GroupFilter::State(GroupState::Active);
+    assert_eq!(
+        listed_ids(&env, &client, &active, 0, 10),
+        Vec::from_array(&env, [first, second, third])
+    );
+    assert_eq!(
+        listed_ids(&env, &client, &GroupFilter::Token(token.clone()), 0, 10),
+        Vec::from_array(&env, [first, third])
+    );
+
+    client.cancel_group(&creator, &second);
+    assert_eq!(
+        listed_ids(&env, &client, &active, 0, 10),
+        Vec::from_array(&env, [first, third])
+    );
+    assert_eq!(
+        listed_ids(
+            &env,
+            &client,
+            &GroupFilter::State(GroupState::Cancelled),
+            0,
+            10
+        ),
+        Vec::from_array(&env, [second])
+    );
+
+    // Completed groups move to the complete index
+    client.join_group(&members.get(1).unwrap(), &first);
+    client.join_group(&members.get(2).unwrap(), &first);
+    while !client.get_group(&first).is_complete {
+        for member in client.list_members(&first).iter() {
+            client.contribute(&member, &first);
+        }
+        env.ledger().with_mut(|li| {
+            li.timestamp += CYCLE_DURATION + GRACE_PERIOD;
+        });
+        client.execute_payout(&first);
+    }
+    assert_eq!(
+        listed_ids(&env, &client, &active, 0, 10),
+        Vec::from_array(&env, [third])
+    );
+    assert_eq!(
+        listed_ids(
+            &env,
+            &client,
+            &GroupFilter::State(GroupState::Complete),
+            0,
+            10
+        ),
+        Vec::from_array(&env, [first])
+    );
+}
+
+#[test]
+fn test_open_seats_follow_membership() {
+    let (env, client, members, token) = setup_test_env();
+    let creator = members.get(0).unwrap();
+    let leaving = members.get(2).unwrap();
+
+    let group_id = create_group(&client, &creator, &token);
+    let open = GroupFilter::OpenSeats;
+    assert_eq!(
+        listed_ids(&env, &client, &open, 0, 10),
+        Vec::from_array(&env, [group_id])
+    );
+
+    client.join_group(&members.get(1).unwrap(), &group_id);
+    client.join_group(&leaving, &group_id);
+    assert_eq!(listed_ids(&env, &client, &open, 0, 10).len(), 0);
+
+    client.leave_group(&leaving, &group_id);
+    assert_eq!(
+        listed_ids(&env, &client, &open, 0, 10),
+        Vec::from_array(&env, [group_id])
+    );
+
+    // Extra shares take up seats too
+    client.set_member_shares(&creator, &group_id, &2u32);
+    assert_eq!(listed_ids(&env, &client, &open, 0, 10).len(), 0);
+}
+
+#[test]
+fn test_pages_follow_cursor() {
+    let (env, client, members, token) = setup_test_env();
+    let creator = members.get(0).unwrap();
+
+    let mut ids = Vec::new(&env);
+    for _ in 0..5 {
+        ids.push_back(create_group(&client, &creator, &token));
+    }
+
+    let filter = GroupFilter::Token(token.clone());
+    let page = listed_ids(&env, &client, &filter, 0, 2);
+    assert_eq!(page, ids.slice(0..2));
+    let page = listed_ids(&env, &client, &filter, page.last().unwrap(), 2);
+    assert_eq!(page, ids.slice(2..4));
+    let page = listed_ids(&env, &client, &filter, page.last().unwrap(), 2);
+    assert_eq!(page, ids.slice(4..5));
+    assert_eq!(
+        listed_ids(&env, &client, &filter, page.last().unwrap(), 2).len(),
+        0
+    );
+
+    // Oversized pages are capped rather than rejected
+    assert_eq!(listed_ids(&env, &client, &filter, 0, 1_000).len(), 5);
+}
+
+#[test]
+fn test_listing_spans_index_pages() {
+    let (env, client, members, token) = setup_test_env();
+    let creator = members.get(0).unwrap();
+    let other_token = env.register_stellar_asset_contract(Address::generate(&env));
+
+    let first = create_group(&client, &creator, &other_token);
+    for _ in 1..INDEX_PAGE_SIZE + 4 {
+        create_group(&client, &creator, &token);
+    }
+    let last = create_group(&client, &creator, &other_token);
+    assert!(last / INDEX_PAGE_SIZE > first / INDEX_PAGE_SIZE);
+
+    // Pages with no matching groups are walked past
+    let filter = GroupFilter::Token(other_token.clone());
+    assert_eq!(
+        listed_ids(&env, &client, &filter, 0, 10),
+        Vec::from_array(&env, [first, last])
+    );
+    assert_eq!(
+        listed_ids(&env, &client, &filter, first, 10),
+        Vec::from_array(&env, [last])
+    );
+
+    let filter = GroupFilter::Token(token.clone());
+    let page = listed_ids(&env, &client, &filter, INDEX_PAGE_SIZE - 2, 3);
+    assert_eq!(
+        page,
+        Vec::from_array(&env, [INDEX_PAGE_SIZE - 1, INDEX_PAGE_SIZE, INDEX_PAGE_SIZE + 1])
+    );
+}
+
+#[test]
+fn test_listing_stops_after_scanning_page_cap() {
+    let (env, client, members, token) = setup_test_env();
+    let creator = members.get(0).unwrap();
+
+    // Skip ahead as if ten pages' worth of groups had come and gone
+    env.as_contract(&client.address, || {
+        let skipped = MAX_INDEX_PAGES_SCANNED * INDEX_PAGE_SIZE - 1;
+        env.storage().instance().set(&symbol_short!("GCOUNTER"), &skipped);
+    });
+    let last = create_group(&client, &creator, &token);
+
+    // The first call reads ten empty pages and stops short
+    let filter = GroupFilter::Token(token.clone());
+    let page = client.list_groups(&filter, &0u64, &10u32);
+    assert_eq!(page.groups.len(), 0);
+    assert_eq!(
+        page.next_cursor,
+        MAX_INDEX_PAGES_SCANNED * INDEX_PAGE_SIZE - 1
+    );
+
+    let page = client.list_groups(&filter, &page.next_cursor, &10u32);
+    assert_eq!(page.groups.len(), 1);
+    assert_eq!(page.groups.get(0).unwrap().id, last);
+    assert_eq!(page.next_cursor, 0);
+}
+
+#[test]
+fn test_full_page_continues_from_last_group() {
+    let (_env, client, members, token) = setup_test_env();
+    let creator = members.get(0).unwrap();
+
+    create_group(&client, &creator, &token);
+    let second = create_group(&client, &creator, &token);
+    create_group(&client, &creator, &token);
+
+    let filter = GroupFilter::Token(token.clone());
+    let page = client.list_groups(&filter, &0u64, &2u32);
+    assert_eq!(page.groups.get(1).unwrap().id, second);
+    assert_eq!(page.next_cursor, second);
+
+    let page = client.list_groups(&filter, &page.next_cursor, &2u32);
+    assert_eq!(page.groups.len(), 1);
+    assert_eq!(page.next_cursor, 0);
+}
+
+#[test]
+fn test_backfill_indexes_groups_stored_before_indexes() {
+    let (env, client, members, token) = setup_test_env();
+    let creator = members.get(0).unwrap();
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let first = create_group(&client, &creator, &token);
+    let second = create_group(&client, &creator, &token);
+    let active = GroupFilter::State(GroupState::Active);
+
+    // Drop the indexes, as if the groups predated them
+    env.as_contract(&client.address, || {
+        for filter in [
+            active.clone(),
+            GroupFilter::Token(token.clone()),
+            GroupFilter::OpenSeats,
+        ] {
+            let key = (symbol_short!("GIDX"), filter, 0u64);
+            env.storage().persistent().remove(&key);
+        }
+    });
+    assert_eq!(listed_ids(&env, &client, &active, 0, 10).len(), 0);
+
+    let result = client.try_backfill_group_indexes(&creator, &0u64, &1u32);
+    assert_eq!(result, Err(Ok(AjoError::Unauthorized)));
+
+    let cursor = client.backfill_group_indexes(&admin, &0u64, &1u32);
+    assert_eq!(cursor, first);
+    assert_eq!(
+        listed_ids(&env, &client, &active, 0, 10),
+        Vec::from_array(&env, [first])
+    );
+
+    let cursor = client.backfill_group_indexes(&admin, &cursor, &1u32);
+    assert_eq!(cursor, second);
+    for filter in [active, GroupFilter::Token(token.clone()), GroupFilter::OpenSeats] {
+        assert_eq!(
+            listed_ids(&env, &client, &filter, 0, 10),
+            Vec::from_array(&env, [first, second])
+        );
+    }
+    assert_eq!(client.backfill_group_indexes(&admin, &cursor, &1u32), second);
+}
+
+#[test]
+fn test_summary_describes_group() {
+    let (env, client, members, token) = setup_test_env();
+    let creator = members.get(0).unwrap();
+
+    let group_id = create_group(&client, &creator, &token);
+    client.join_group(&members.get(1).unwrap(), &group_id);
+    client.set_group_metadata(
+        &group_id,
+        &String::from_str(&env, "Market traders"),
+        &String::from_str(&env, "Weekly savings"),
+        &String::from_str(&env, "Pay on Fridays"),
+    );
+
+    let summary = client
+        .list_groups(&GroupFilter::OpenSeats, &0u64, &10u32)
+        .groups
+        .get(0)
+        .unwrap();
+    assert_eq!(summary.id, group_id);
+    assert_eq!(summary.token_address, token);
+    assert_eq!(summary.contribution_amount, CONTRIBUTION);
+    assert_eq!(summary.seats_taken, 2);
+    assert_eq!(summary.max_members, 3);
+    assert_eq!(summary.cycle_duration, CYCLE_DURATION);
+    assert_eq!(summary.state, GroupState::Active);
+    assert_eq!(summary.name, String::from_str(&env, "Market traders"));
+
+    let other = create_group(&client, &creator, &token);
+    let summary = client
+        .list_groups(&GroupFilter::OpenSeats, &group_id, &10u32)
+        .groups
+        .get(0)
+        .unwrap();
+    assert_eq!(summary.id, other);
+    assert_eq!(summary.name, String::from_str(&env, ""));
+}
+
+#[test]
+fn test_expired_formation_hidden_from_open_seats() {
+    let (env, client, members, token) = setup_test_env();
+    let creator = members.get(0).unwrap();
+
+    let group_id = create_group(&client, &creator, &token);
+    client.set_formation(&creator, &group_id, &2, &FORMATION_PERIOD, &false);
+    let forming = GroupFilter::State(GroupState::Forming);
+    assert_eq!(
+        listed_ids(&env, &client, &forming, 0, 10),
+        Vec::from_array(&env, [group_id])
+    );
+    assert_eq!(
+        listed_ids(&env, &client, &GroupFilter::OpenSeats, 0, 10),
+        Vec::from_array(&env, [group_id])
+    );
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += FORMATION_PERIOD + 1;
+    });
+    assert_eq!(
+        listed_ids(&env, &client, &GroupFilter::OpenSeats, 0, 10).len(),
+        0
+    );
+
+    client.expire_formation(&group_id);
+    assert_eq!(listed_ids(&env, &client, &forming, 0, 10).len(), 0);
+    assert_eq!(
+        listed_ids(
+            &env,
+            &client,
+            &GroupFilter::State(GroupState::Cancelled),
+            0,
+            10
+        ),
+        Vec::from_array(&env, [group_id])
+    );
+}
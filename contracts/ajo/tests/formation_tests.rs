@@ -0,0 +1,175 @@
+#![cfg(test)]
+
+use soroban_ajo::{AjoContract, AjoContractClient, AjoError, GroupState};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, Vec,
+};
+
+const CONTRIBUTION: i128 = 100_000_000;
+const CYCLE_DURATION: u64 = 604_800;
+const GRACE_PERIOD: u64 = 86_400;
+const FORMATION_PERIOD: u64 = 1_209_600;
+const STARTING_BALANCE: i128 = 1_000_000_000;
+
+/// Helper function to create a test environment and contract
+fn setup_test_env() -> (Env, AjoContractClient<'static>, Vec<Address>, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AjoContract);
+    let client = AjoContractClient::new(&env, &contract_id);
+
+    let token_admin = Address::generate(&env);
+    let token = env.register_stellar_asset_contract(token_admin);
+    let token_client = token::StellarAssetClient::new(&env, &token);
+
+    let mut members = Vec::new(&env);
+    for _ in 0..4 {
+        let member = Address::generate(&env);
+        token_client.mint(&member, &STARTING_BALANCE);
+        members.push_back(member);
+    }
+
+    (env, client, members, token)
+}
+
+fn create_group(
+    client: &AjoContractClient,
+    creator: &Address,
+    token: &Address,
+    max_members: u32,
+) -> u64 {
+    client.create_group(
+        creator,
+        token,
+        &CONTRIBUTION,
+        &CYCLE_DURATION,
+        &max_members,
+        &GRACE_PERIOD,
+        &5u32,
+        &0u32,
+    )
+}
+
+#[test]
+fn test_forming_group_starts_when_full() {
+    let (env, client, members, token) = setup_test_env();
+    let creator = members.get(0).unwrap();
+
+    let group_id = create_group(&client, &creator, &token, 3);
+    client.set_formation(&creator, &group_id, &2, &FORMATION_PERIOD, &false);
+    assert_eq!(client.get_group(&group_id).state, GroupState::Forming);
+
+    let result = client.try_contribute(&creator, &group_id);
+    assert_eq!(result, Err(Ok(AjoError::InvalidState)));
+
+    client.join_group(&members.get(1).unwrap(), &group_id);
+    env.ledger().with_mut(|li| {
+        li.timestamp += 3 * 86_400;
+    });
+    client.join_group(&members.get(2).unwrap(), &group_id);
+
+    // The first cycle's clock starts when the last member joins
+    let group = client.get_group(&group_id);
+    assert_eq!(group.state, GroupState::Active);
+    assert_eq!(group.current_cycle, 1);
+    assert_eq!(group.cycle_start_time, env.ledger().timestamp());
+    client.contribute(&creator, &group_id);
+}
+
+#[test]
+fn test_creator_starts_group_with_min_members() {
+    let (_env, client, members, token) = setup_test_env();
+    let creator = members.get(0).unwrap();
+
+    let group_id = create_group(&client, &creator, &token, 4);
+    client.set_formation(&creator, &group_id, &2, &FORMATION_PERIOD, &false);
+
+    let result = client.try_start_group(&creator, &group_id);
+    assert_eq!(result, Err(Ok(AjoError::InvalidState)));
+
+    client.join_group(&members.get(1).unwrap(), &group_id);
+    client.start_group(&creator, &group_id);
+    assert_eq!(client.get_group(&group_id).state, GroupState::Active);
+
+    let result = client.try_join_group(&members.get(2).unwrap(), &group_id);
+    assert_eq!(result, Err(Ok(AjoError::InvalidState)));
+
+    let result = client.try_start_group(&creator, &group_id);
+    assert_eq!(result, Err(Ok(AjoError::InvalidState)));
+}
+
+#[test]
+fn test_late_join_allowed_when_enabled() {
+    let (_env, client, members, token) = setup_test_env();
+    let creator = members.get(0).unwrap();
+
+    let group_id = create_group(&client, &creator, &token, 4);
+    client.set_formation(&creator, &group_id, &2, &FORMATION_PERIOD, &true);
+    client.join_group(&members.get(1).unwrap(), &group_id);
+    client.start_group(&creator, &group_id);
+
+    client.join_group(&members.get(2).unwrap(), &group_id);
+    assert_eq!(client.list_members(&group_id).len(), 3);
+}
+
+#[test]
+fn test_unfilled_group_expires_and_refunds_collateral() {
+    let (env, client, members, token) = setup_test_env();
+    let creator = members.get(0).unwrap();
+    let member = members.get(1).unwrap();
+    let token_client = token::Client::new(&env, &token);
+
+    let group_id = create_group(&client, &creator, &token, 4);
+    client.set_collateral_amount(&creator, &group_id, &CONTRIBUTION);
+    client.set_formation(&creator, &group_id, &3, &FORMATION_PERIOD, &false);
+    client.join_group(&member, &group_id);
+    assert_eq!(
+        token_client.balance(&member),
+        STARTING_BALANCE - CONTRIBUTION
+    );
+
+    let result = client.try_expire_formation(&group_id);
+    assert_eq!(result, Err(Ok(AjoError::InvalidState)));
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += FORMATION_PERIOD + 1;
+    });
+    let result = client.try_join_group(&members.get(2).unwrap(), &group_id);
+    assert_eq!(result, Err(Ok(AjoError::InvalidState)));
+
+    client.expire_formation(&group_id);
+    assert_eq!(client.get_group(&group_id).state, GroupState::Cancelled);
+    assert_eq!(token_client.balance(&member), STARTING_BALANCE);
+}
+
+#[test]
+fn test_huge_formation_period_never_expires() {
+    let (env, client, members, token) = setup_test_env();
+    let creator = members.get(0).unwrap();
+
+    let group_id = create_group(&client, &creator, &token, 4);
+    env.ledger().with_mut(|li| {
+        li.timestamp += FORMATION_PERIOD;
+    });
+    client.set_formation(&creator, &group_id, &2, &u64::MAX, &false);
+    assert_eq!(client.get_group(&group_id).formation_deadline, u64::MAX);
+
+    let result = client.try_expire_formation(&group_id);
+    assert_eq!(result, Err(Ok(AjoError::InvalidState)));
+}
+
+#[test]
+fn test_formation_rejected_after_first_contribution() {
+    let (_env, client, members, token) = setup_test_env();
+    let creator = members.get(0).unwrap();
+
+    let group_id = create_group(&client, &creator, &token, 4);
+    let result = client.try_set_formation(&creator, &group_id, &1, &FORMATION_PERIOD, &false);
+    assert_eq!(result, Err(Ok(AjoError::InvalidInput)));
+
+    client.contribute(&creator, &group_id);
+    let result = client.try_set_formation(&creator, &group_id, &2, &FORMATION_PERIOD, &false);
+    assert_eq!(result, Err(Ok(AjoError::InvalidState)));
+}
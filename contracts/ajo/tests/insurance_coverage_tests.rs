@@ -0,0 +1,241 @@
+#![cfg(test)]
+
+use soroban_ajo::{AjoContract, AjoContractClient, AjoError, ClaimStatus};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env,
+};
+
+const CONTRIBUTION: i128 = 100_000_000;
+const CYCLE_DURATION: u64 = 604_800;
+const GRACE_PERIOD: u64 = 86_400;
+
+/// Helper function to create a test environment and contract
+fn setup_test_env() -> (
+    Env,
+    AjoContractClient<'static>,
+    Address,
+    Address,
+    Address,
+    Address,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AjoContract);
+    let client = AjoContractClient::new(&env, &contract_id);
+
+    let creator = Address::generate(&env);
+    let member2 = Address::generate(&env);
+    let member3 = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = env.register_stellar_asset_contract(token_admin);
+
+    let token_client = token::StellarAssetClient::new(&env, &token);
+    for member in [&creator, &member2, &member3] {
+        token_client.mint(member, &1_000_000_000);
+    }
+
+    (env, client, creator, member2, member3, token)
+}
+
+fn create_group(
+    client: &AjoContractClient,
+    members: &[Address; 3],
+    token: &Address,
+    insurance_rate_bps: u32,
+) -> u64 {
+    let group_id = client.create_group(
+        &members[0],
+        token,
+        &CONTRIBUTION,
+        &CYCLE_DURATION,
+        &3u32,
+        &GRACE_PERIOD,
+        &5u32,
+        &insurance_rate_bps,
+    );
+    client.join_group(&members[1], &group_id);
+    client.join_group(&members[2], &group_id);
+    group_id
+}
+
+/// Creator and member3 pay, member2 defaults
+fn run_cycle_with_default(
+    env: &Env,
+    client: &AjoContractClient,
+    group_id: u64,
+    creator: &Address,
+    member3: &Address,
+) {
+    client.contribute(creator, &group_id);
+    client.contribute(member3, &group_id);
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE_DURATION + GRACE_PERIOD + 1;
+    });
+    client.mark_defaults(&group_id);
+}
+
+#[test]
+fn test_reserve_tops_up_pot_to_full_amount() {
+    let (env, client, creator, member2, member3, token) = setup_test_env();
+    // 50% premiums so two contributions fund a full missing contribution
+    let group_id = create_group(
+        &client,
+        &[creator.clone(), member2.clone(), member3.clone()],
+        &token,
+        5_000,
+    );
+    let token_client = token::Client::new(&env, &token);
+
+    run_cycle_with_default(&env, &client, group_id, &creator, &member3);
+
+    let before = token_client.balance(&creator);
+    client.execute_payout(&group_id);
+    assert_eq!(token_client.balance(&creator) - before, CONTRIBUTION * 3);
+
+    let claim = client.get_default_claim(&group_id, &1u32, &member2);
+    assert_eq!(claim.status, ClaimStatus::Paid);
+    assert_eq!(claim.amount, CONTRIBUTION);
+    assert_eq!(claim.claimant, creator);
+    assert_eq!(claim.defaulter, member2);
+
+    let pool = client.get_insurance_pool(&group_id, &token);
+    assert_eq!(pool.balance, 0);
+    assert_eq!(pool.total_payouts, CONTRIBUTION);
+
+    let liabilities = client.get_token_liabilities(&token);
+    assert_eq!(liabilities.pots, 0);
+    assert_eq!(liabilities.insurance_reserves, 0);
+    assert!(client.is_solvent(&token));
+}
+
+#[test]
+fn test_coverage_capped_by_reserve() {
+    let (env, client, creator, member2, member3, token) = setup_test_env();
+    let group_id = create_group(
+        &client,
+        &[creator.clone(), member2.clone(), member3.clone()],
+        &token,
+        200,
+    );
+    let token_client = token::Client::new(&env, &token);
+
+    run_cycle_with_default(&env, &client, group_id, &creator, &member3);
+
+    let before = token_client.balance(&creator);
+    client.execute_payout(&group_id);
+    // Reserve only holds two 2% premiums
+    assert_eq!(
+        token_client.balance(&creator) - before,
+        CONTRIBUTION * 2 + 4_000_000
+    );
+
+    let claim = client.get_default_claim(&group_id, &1u32, &member2);
+    assert_eq!(claim.amount, 4_000_000);
+    assert_eq!(client.get_insurance_pool(&group_id, &token).balance, 0);
+}
+
+#[test]
+fn test_manual_claim_rejected_after_auto_coverage() {
+    let (env, client, creator, member2, member3, token) = setup_test_env();
+    let group_id = create_group(
+        &client,
+        &[creator.clone(), member2.clone(), member3.clone()],
+        &token,
+        5_000,
+    );
+
+    run_cycle_with_default(&env, &client, group_id, &creator, &member3);
+    client.execute_payout(&group_id);
+
+    let result =
+        client.try_file_insurance_claim(&creator, &group_id, &1u32, &member2, &CONTRIBUTION);
+    assert_eq!(result, Err(Ok(AjoError::ClaimAlreadyProcessed)));
+}
+
+#[test]
+fn test_manual_claim_requires_recorded_default() {
+    let (env, client, creator, member2, member3, token) = setup_test_env();
+    let group_id = create_group(
+        &client,
+        &[creator.clone(), member2.clone(), member3.clone()],
+        &token,
+        5_000,
+    );
+
+    // No default has been recorded yet
+    let result =
+        client.try_file_insurance_claim(&creator, &group_id, &1u32, &member2, &CONTRIBUTION);
+    assert_eq!(result, Err(Ok(AjoError::InvalidClaim)));
+
+    run_cycle_with_default(&env, &client, group_id, &creator, &member3);
+
+    let outsider = Address::generate(&env);
+    let result =
+        client.try_file_insurance_claim(&outsider, &group_id, &1u32, &member2, &CONTRIBUTION);
+    assert_eq!(result, Err(Ok(AjoError::NotMember)));
+
+    let result =
+        client.try_file_insurance_claim(&creator, &group_id, &1u32, &member3, &CONTRIBUTION);
+    assert_eq!(result, Err(Ok(AjoError::InvalidClaim)));
+
+    let result =
+        client.try_file_insurance_claim(&creator, &group_id, &1u32, &member2, &(CONTRIBUTION + 1));
+    assert_eq!(result, Err(Ok(AjoError::InvalidClaim)));
+
+    client.file_insurance_claim(&creator, &group_id, &1u32, &member2, &CONTRIBUTION);
+}
+
+#[test]
+fn test_auto_coverage_supersedes_pending_claim() {
+    let (env, client, creator, member2, member3, token) = setup_test_env();
+    let group_id = create_group(
+        &client,
+        &[creator.clone(), member2.clone(), member3.clone()],
+        &token,
+        5_000,
+    );
+    let token_client = token::Client::new(&env, &token);
+
+    run_cycle_with_default(&env, &client, group_id, &creator, &member3);
+    let filed = client.file_insurance_claim(&member3, &group_id, &1u32, &member2, &CONTRIBUTION);
+
+    let before = token_client.balance(&creator);
+    client.execute_payout(&group_id);
+    assert_eq!(token_client.balance(&creator) - before, CONTRIBUTION * 3);
+
+    assert_eq!(client.get_insurance_claim(&filed).status, ClaimStatus::Rejected);
+    let claim = client.get_default_claim(&group_id, &1u32, &member2);
+    assert_ne!(claim.id, filed);
+    assert_eq!(claim.status, ClaimStatus::Paid);
+    assert_eq!(claim.claimant, creator);
+    assert_eq!(client.get_insurance_pool(&group_id, &token).pending_claims_count, 0);
+
+    // The superseded claim can no longer be paid out of the reserve
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    let result = client.try_process_insurance_claim(&admin, &filed, &true);
+    assert_eq!(result, Err(Ok(AjoError::ClaimAlreadyProcessed)));
+}
+
+#[test]
+fn test_uninsured_group_records_no_claim() {
+    let (env, client, creator, member2, member3, token) = setup_test_env();
+    let group_id = create_group(
+        &client,
+        &[creator.clone(), member2.clone(), member3.clone()],
+        &token,
+        0,
+    );
+    let token_client = token::Client::new(&env, &token);
+
+    run_cycle_with_default(&env, &client, group_id, &creator, &member3);
+
+    let before = token_client.balance(&creator);
+    client.execute_payout(&group_id);
+    assert_eq!(token_client.balance(&creator) - before, CONTRIBUTION * 2);
+
+    let result = client.try_get_default_claim(&group_id, &1u32, &member2);
+    assert_eq!(result, Err(Ok(AjoError::InvalidClaim)));
+}
@@ -0,0 +1,173 @@
+#![cfg(test)]
+
+use soroban_ajo::{AjoContract, AjoContractClient, AjoError, PremiumMode};
+use soroban_sdk::{testutils::{Address as _, Ledger}, token, Address, Env};
+
+const CONTRIBUTION: i128 = 100_000_000;
+const CYCLE_DURATION: u64 = 604_800;
+const GRACE_PERIOD: u64 = 86_400;
+const INSURANCE_RATE_BPS: u32 = 200; // 2%
+const PREMIUM: i128 = 2_000_000;
+
+/// Helper function to create a test environment and contract
+fn setup_test_env() -> (Env, AjoContractClient<'static>, Address, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AjoContract);
+    let client = AjoContractClient::new(&env, &contract_id);
+
+    let creator = Address::generate(&env);
+    let member2 = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = env.register_stellar_asset_contract(token_admin);
+
+    token::StellarAssetClient::new(&env, &token).mint(&creator, &1_000_000_000);
+    token::StellarAssetClient::new(&env, &token).mint(&member2, &1_000_000_000);
+
+    (env, client, creator, member2, token)
+}
+
+fn create_insured_group(
+    client: &AjoContractClient,
+    creator: &Address,
+    member2: &Address,
+    token: &Address,
+) -> u64 {
+    let group_id = client.create_group(
+        creator,
+        token,
+        &CONTRIBUTION,
+        &CYCLE_DURATION,
+        &2u32,
+        &GRACE_PERIOD,
+        &5u32,
+        &INSURANCE_RATE_BPS,
+    );
+    client.join_group(member2, &group_id);
+    group_id
+}
+
+fn advance_past_grace_period(env: &Env) {
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE_DURATION + GRACE_PERIOD + 1;
+    });
+}
+
+#[test]
+fn test_on_top_premium_charged_in_addition_to_contribution() {
+    let (env, client, creator, member2, token) = setup_test_env();
+    let group_id = create_insured_group(&client, &creator, &member2, &token);
+    let token_client = token::Client::new(&env, &token);
+
+    let before = token_client.balance(&creator);
+    client.contribute(&creator, &group_id);
+    assert_eq!(before - token_client.balance(&creator), CONTRIBUTION + PREMIUM);
+
+    let pool = client.get_insurance_pool(&group_id, &token);
+    assert_eq!(pool.balance, PREMIUM);
+    assert_eq!(pool.total_premiums, PREMIUM);
+
+    client.contribute(&member2, &group_id);
+    advance_past_grace_period(&env);
+
+    let before = token_client.balance(&creator);
+    client.execute_payout(&group_id);
+    assert_eq!(token_client.balance(&creator) - before, CONTRIBUTION * 2);
+}
+
+#[test]
+fn test_deduct_from_pot_shrinks_payout() {
+    let (env, client, creator, member2, token) = setup_test_env();
+    let group_id = create_insured_group(&client, &creator, &member2, &token);
+    let token_client = token::Client::new(&env, &token);
+
+    client.set_insurance_premium_mode(&creator, &group_id, &PremiumMode::DeductFromPot);
+
+    let before = token_client.balance(&creator);
+    client.contribute(&creator, &group_id);
+    assert_eq!(before - token_client.balance(&creator), CONTRIBUTION);
+    client.contribute(&member2, &group_id);
+    advance_past_grace_period(&env);
+
+    let before = token_client.balance(&creator);
+    client.execute_payout(&group_id);
+    assert_eq!(token_client.balance(&creator) - before, (CONTRIBUTION - PREMIUM) * 2);
+
+    let pool = client.get_insurance_pool(&group_id, &token);
+    assert_eq!(pool.balance, PREMIUM * 2);
+}
+
+#[test]
+fn test_pools_are_segregated_per_group() {
+    let (_env, client, creator, member2, token) = setup_test_env();
+    let group_a = create_insured_group(&client, &creator, &member2, &token);
+    let group_b = client.create_group(
+        &creator,
+        &token,
+        &CONTRIBUTION,
+        &CYCLE_DURATION,
+        &2u32,
+        &GRACE_PERIOD,
+        &5u32,
+        &0u32,
+    );
+
+    client.contribute(&creator, &group_a);
+    client.contribute(&creator, &group_b);
+
+    assert_eq!(client.get_insurance_pool(&group_a, &token).balance, PREMIUM);
+    let result = client.try_get_insurance_pool(&group_b, &token);
+    assert_eq!(result, Err(Ok(AjoError::PoolNotFound)));
+}
+
+#[test]
+fn test_liabilities_track_pots_and_reserves() {
+    let (env, client, creator, member2, token) = setup_test_env();
+    let group_id = create_insured_group(&client, &creator, &member2, &token);
+
+    client.contribute(&creator, &group_id);
+    client.contribute(&member2, &group_id);
+
+    let liabilities = client.get_token_liabilities(&token);
+    assert_eq!(liabilities.pots, CONTRIBUTION * 2);
+    assert_eq!(liabilities.insurance_reserves, PREMIUM * 2);
+    assert!(client.is_solvent(&token));
+
+    advance_past_grace_period(&env);
+    client.execute_payout(&group_id);
+
+    let liabilities = client.get_token_liabilities(&token);
+    assert_eq!(liabilities.pots, 0);
+    assert_eq!(liabilities.insurance_reserves, PREMIUM * 2);
+    assert!(client.is_solvent(&token));
+}
+
+#[test]
+fn test_cancel_refunds_deducted_premiums() {
+    let (env, client, creator, member2, token) = setup_test_env();
+    let group_id = create_insured_group(&client, &creator, &member2, &token);
+    let token_client = token::Client::new(&env, &token);
+
+    client.set_insurance_premium_mode(&creator, &group_id, &PremiumMode::DeductFromPot);
+    let before = token_client.balance(&creator);
+    client.contribute(&creator, &group_id);
+    client.cancel_group(&creator, &group_id);
+
+    assert_eq!(token_client.balance(&creator), before);
+    assert_eq!(client.get_insurance_pool(&group_id, &token).balance, 0);
+
+    let liabilities = client.get_token_liabilities(&token);
+    assert_eq!(liabilities.pots, 0);
+    assert_eq!(liabilities.insurance_reserves, 0);
+}
+
+#[test]
+fn test_only_creator_sets_premium_mode() {
+    let (_env, client, creator, member2, token) = setup_test_env();
+    let group_id = create_insured_group(&client, &creator, &member2, &token);
+
+    let result =
+        client.try_set_insurance_premium_mode(&member2, &group_id, &PremiumMode::DeductFromPot);
+    assert_eq!(result, Err(Ok(AjoError::Unauthorized)));
+}
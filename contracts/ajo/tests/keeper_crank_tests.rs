@@ -0,0 +1,260 @@
+#![cfg(test)]
+
+use soroban_ajo::{AjoContract, AjoContractClient, AjoError, ClaimStatus};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, Vec,
+};
+
+const CONTRIBUTION: i128 = 100_000_000;
+const CYCLE_DURATION: u64 = 604_800;
+const GRACE_PERIOD: u64 = 86_400;
+const STARTING_BALANCE: i128 = 1_000_000_000;
+const KEEPER_FEE_BPS: u32 = 100;
+const BOUNTY: i128 = CONTRIBUTION * KEEPER_FEE_BPS as i128 / 10_000;
+
+/// Helper function to create a test environment and contract
+fn setup_test_env() -> (Env, AjoContractClient<'static>, Vec<Address>, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AjoContract);
+    let client = AjoContractClient::new(&env, &contract_id);
+    client.initialize(&Address::generate(&env));
+
+    let token_admin = Address::generate(&env);
+    let token = env.register_stellar_asset_contract(token_admin);
+    let token_client = token::StellarAssetClient::new(&env, &token);
+
+    let mut members = Vec::new(&env);
+    for _ in 0..3 {
+        let member = Address::generate(&env);
+        token_client.mint(&member, &STARTING_BALANCE);
+        members.push_back(member);
+    }
+
+    (env, client, members, token)
+}
+
+/// Creates a full three-member group
+fn create_full_group(client: &AjoContractClient, members: &Vec<Address>, token: &Address) -> u64 {
+    let group_id = client.create_group(
+        &members.get(0).unwrap(),
+        token,
+        &CONTRIBUTION,
+        &CYCLE_DURATION,
+        &3u32,
+        &GRACE_PERIOD,
+        &5u32,
+        &0u32,
+    );
+    client.join_group(&members.get(1).unwrap(), &group_id);
+    client.join_group(&members.get(2).unwrap(), &group_id);
+    group_id
+}
+
+fn contribute_all(env: &Env, client: &AjoContractClient, group_id: u64) {
+    for member in client.list_members(&group_id).iter() {
+        client.contribute(&member, &group_id);
+    }
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE_DURATION + GRACE_PERIOD;
+    });
+}
+
+#[test]
+fn test_crank_executes_payout_and_pays_bounty() {
+    let (env, client, members, token) = setup_test_env();
+    let creator = members.get(0).unwrap();
+    let keeper = Address::generate(&env);
+    let token_client = token::Client::new(&env, &token);
+
+    let group_id = create_full_group(&client, &members, &token);
+    client.set_keeper_fee(&creator, &group_id, &KEEPER_FEE_BPS);
+    assert_eq!(
+        client.fund_keeper_reserve(&creator, &group_id, &(5 * BOUNTY)),
+        5 * BOUNTY
+    );
+    assert_eq!(client.get_keeper_bounty(&group_id), BOUNTY);
+
+    contribute_all(&env, &client, group_id);
+    let report = client.crank(&group_id, &keeper);
+    assert!(report.payout_executed);
+    assert_eq!(report.defaults_marked, 0);
+    assert_eq!(report.bounty, BOUNTY);
+
+    assert_eq!(token_client.balance(&keeper), BOUNTY);
+    assert_eq!(
+        token_client.balance(&creator),
+        STARTING_BALANCE + 2 * CONTRIBUTION - 5 * BOUNTY
+    );
+    assert_eq!(client.get_keeper_reserve(&group_id), 4 * BOUNTY);
+    assert_eq!(client.get_group(&group_id).current_cycle, 2);
+    assert_eq!(
+        client.get_token_liabilities(&token).keeper_reserves,
+        4 * BOUNTY
+    );
+    assert!(client.is_solvent(&token));
+}
+
+#[test]
+fn test_crank_marks_defaults_before_payout() {
+    let (env, client, members, token) = setup_test_env();
+    let keeper = Address::generate(&env);
+
+    let group_id = create_full_group(&client, &members, &token);
+    client.contribute(&members.get(0).unwrap(), &group_id);
+    client.contribute(&members.get(1).unwrap(), &group_id);
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE_DURATION + GRACE_PERIOD + 1;
+    });
+
+    // No reserve, so the crank runs without a bounty
+    let report = client.crank(&group_id, &keeper);
+    assert_eq!(report.defaults_marked, 1);
+    assert!(report.payout_executed);
+    assert_eq!(report.bounty, 0);
+    assert_eq!(client.get_group(&group_id).current_cycle, 2);
+}
+
+#[test]
+fn test_nothing_to_crank_and_reminders_sent_once() {
+    let (env, client, members, token) = setup_test_env();
+    let member = members.get(1).unwrap();
+    let keeper = Address::generate(&env);
+
+    let group_id = create_full_group(&client, &members, &token);
+    let result = client.try_crank(&group_id, &keeper);
+    assert_eq!(result, Err(Ok(AjoError::InvalidState)));
+
+    client.set_notification_preferences(&member, &true, &24u64, &true, &true);
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE_DURATION - 3_600;
+    });
+    let report = client.crank(&group_id, &keeper);
+    assert_eq!(report.reminders_sent, 1);
+    assert!(!report.payout_executed);
+
+    let result = client.try_crank(&group_id, &keeper);
+    assert_eq!(result, Err(Ok(AjoError::InvalidState)));
+
+    // The grace period reminder is a new one
+    env.ledger().with_mut(|li| {
+        li.timestamp += 3_601;
+    });
+    assert_eq!(client.crank(&group_id, &keeper).reminders_sent, 1);
+}
+
+#[test]
+fn test_settling_claims_alone_pays_no_bounty() {
+    let (env, client, members, token) = setup_test_env();
+    let creator = members.get(0).unwrap();
+    let defaulter = members.get(2).unwrap();
+    let keeper = Address::generate(&env);
+    let token_client = token::Client::new(&env, &token);
+
+    let group_id = client.create_group(
+        &creator,
+        &token,
+        &CONTRIBUTION,
+        &CYCLE_DURATION,
+        &3u32,
+        &GRACE_PERIOD,
+        &5u32,
+        &200u32,
+    );
+    client.join_group(&members.get(1).unwrap(), &group_id);
+    client.join_group(&defaulter, &group_id);
+    client.set_keeper_fee(&creator, &group_id, &KEEPER_FEE_BPS);
+    client.fund_keeper_reserve(&creator, &group_id, &(5 * BOUNTY));
+
+    // Only the creator pays; the reserve runs dry before the second default
+    client.contribute(&creator, &group_id);
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE_DURATION + GRACE_PERIOD + 1;
+    });
+    client.mark_defaults(&group_id);
+    client.execute_payout(&group_id);
+    let claim_id =
+        client.file_insurance_claim(&creator, &group_id, &1u32, &defaulter, &1_000_000i128);
+
+    // At the grace period's end the claim is due but defaults are not
+    client.contribute(&creator, &group_id);
+    client.contribute(&members.get(1).unwrap(), &group_id);
+    let group = client.get_group(&group_id);
+    env.ledger().with_mut(|li| {
+        li.timestamp = group.cycle_start_time + CYCLE_DURATION + GRACE_PERIOD;
+    });
+
+    let report = client.crank(&group_id, &keeper);
+    assert_eq!(report.claims_settled, 1);
+    assert_eq!(report.defaults_marked, 0);
+    assert!(!report.payout_executed);
+    assert_eq!(report.bounty, 0);
+    assert_eq!(token_client.balance(&keeper), 0);
+    assert_eq!(client.get_keeper_reserve(&group_id), 5 * BOUNTY);
+    assert_eq!(
+        client.get_insurance_claim(&claim_id).status,
+        ClaimStatus::Paid
+    );
+}
+
+#[test]
+fn test_default_fee_and_reserve_cap() {
+    let (env, client, members, token) = setup_test_env();
+    let creator = members.get(0).unwrap();
+    let keeper = Address::generate(&env);
+
+    let result = client.try_set_default_keeper_fee(&1_001u32);
+    assert_eq!(result, Err(Ok(AjoError::InvalidInput)));
+    client.set_default_keeper_fee(&KEEPER_FEE_BPS);
+
+    let group_id = create_full_group(&client, &members, &token);
+    let result = client.try_set_keeper_fee(&creator, &group_id, &1_001u32);
+    assert_eq!(result, Err(Ok(AjoError::InvalidInput)));
+    let result = client.try_set_keeper_fee(&members.get(1).unwrap(), &group_id, &10u32);
+    assert_eq!(result, Err(Ok(AjoError::Unauthorized)));
+
+    // The default applies, capped at what the reserve holds
+    client.fund_keeper_reserve(&members.get(2).unwrap(), &group_id, &(BOUNTY / 2));
+    assert_eq!(client.get_keeper_bounty(&group_id), BOUNTY / 2);
+
+    contribute_all(&env, &client, group_id);
+    assert_eq!(client.crank(&group_id, &keeper).bounty, BOUNTY / 2);
+    assert_eq!(client.get_keeper_reserve(&group_id), 0);
+    assert_eq!(client.get_keeper_bounty(&group_id), 0);
+}
+
+#[test]
+fn test_reserve_withdrawn_after_completion() {
+    let (env, client, members, token) = setup_test_env();
+    let creator = members.get(0).unwrap();
+    let keeper = Address::generate(&env);
+    let token_client = token::Client::new(&env, &token);
+
+    let group_id = create_full_group(&client, &members, &token);
+    client.set_keeper_fee(&creator, &group_id, &KEEPER_FEE_BPS);
+    let result = client.try_fund_keeper_reserve(&keeper, &group_id, &0i128);
+    assert_eq!(result, Err(Ok(AjoError::ContributionAmountZero)));
+    client.fund_keeper_reserve(&creator, &group_id, &(10 * BOUNTY));
+
+    let result = client.try_withdraw_keeper_reserve(&creator, &group_id);
+    assert_eq!(result, Err(Ok(AjoError::InvalidState)));
+
+    while !client.get_group(&group_id).is_complete {
+        contribute_all(&env, &client, group_id);
+        client.crank(&group_id, &keeper);
+    }
+    assert_eq!(token_client.balance(&keeper), 3 * BOUNTY);
+
+    let result = client.try_crank(&group_id, &keeper);
+    assert_eq!(result, Err(Ok(AjoError::GroupComplete)));
+
+    assert_eq!(
+        client.withdraw_keeper_reserve(&creator, &group_id),
+        7 * BOUNTY
+    );
+    assert_eq!(client.get_keeper_reserve(&group_id), 0);
+    assert_eq!(client.get_token_liabilities(&token).keeper_reserves, 0);
+    assert_eq!(token_client.balance(&client.address), 0);
+}
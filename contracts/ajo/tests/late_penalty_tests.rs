@@ -0,0 +1,161 @@
+#![cfg(test)]
+
+use soroban_ajo::{AjoContract, AjoContractClient, AjoError};
+use soroban_sdk::{testutils::{Address as _, Ledger}, token, Address, Env};
+
+const CONTRIBUTION: i128 = 100_000_000; // 10 XLM
+const CYCLE_DURATION: u64 = 604_800; // 1 week
+const GRACE_PERIOD: u64 = 86_400; // 24 hours
+const EXPECTED_PENALTY: i128 = 5_000_000; // 5% of 10 XLM
+
+/// Helper function to create a test environment and contract
+fn setup_test_env() -> (Env, AjoContractClient<'static>, Address, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AjoContract);
+    let client = AjoContractClient::new(&env, &contract_id);
+
+    let creator = Address::generate(&env);
+    let member2 = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = env.register_stellar_asset_contract(token_admin);
+
+    (env, client, creator, member2, token)
+}
+
+fn mint_tokens(env: &Env, token_id: &Address, members: &[Address], amount: i128) {
+    let token_client = token::StellarAssetClient::new(env, token_id);
+    for member in members {
+        token_client.mint(member, &amount);
+    }
+}
+
+fn create_two_member_group(
+    env: &Env,
+    client: &AjoContractClient,
+    creator: &Address,
+    member2: &Address,
+    token: &Address,
+) -> u64 {
+    let group_id = client.create_group(
+        creator,
+        token,
+        &CONTRIBUTION,
+        &CYCLE_DURATION,
+        &2u32,
+        &GRACE_PERIOD,
+        &5u32,
+        &0u32,
+    );
+    client.join_group(member2, &group_id);
+    mint_tokens(env, token, &[creator.clone(), member2.clone()], 1_000_000_000);
+    group_id
+}
+
+fn advance_into_grace_period(env: &Env) {
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE_DURATION + 3600;
+    });
+}
+
+#[test]
+fn test_on_time_contribution_records_detail() {
+    let (env, client, creator, member2, token) = setup_test_env();
+    let group_id = create_two_member_group(&env, &client, &creator, &member2, &token);
+
+    client.contribute(&creator, &group_id);
+
+    let detail = client.get_contribution_detail(&group_id, &1u32, &creator);
+    assert_eq!(detail.amount, CONTRIBUTION);
+    assert!(!detail.is_late);
+    assert_eq!(detail.penalty_amount, 0);
+
+    let record = client.get_member_penalty_record(&group_id, &creator);
+    assert_eq!(record.on_time_count, 1);
+    assert_eq!(record.late_count, 0);
+    assert_eq!(record.reliability_score, 100);
+}
+
+#[test]
+fn test_late_contribution_transfers_penalty() {
+    let (env, client, creator, member2, token) = setup_test_env();
+    let group_id = create_two_member_group(&env, &client, &creator, &member2, &token);
+    let token_client = token::Client::new(&env, &token);
+
+    client.contribute(&creator, &group_id);
+    advance_into_grace_period(&env);
+
+    let before = token_client.balance(&member2);
+    client.contribute(&member2, &group_id);
+    assert_eq!(before - token_client.balance(&member2), CONTRIBUTION + EXPECTED_PENALTY);
+
+    let detail = client.get_contribution_detail(&group_id, &1u32, &member2);
+    assert!(detail.is_late);
+    assert_eq!(detail.penalty_amount, EXPECTED_PENALTY);
+
+    let record = client.get_member_penalty_record(&group_id, &member2);
+    assert_eq!(record.late_count, 1);
+    assert_eq!(record.on_time_count, 0);
+    assert_eq!(record.total_penalties, EXPECTED_PENALTY);
+    assert_eq!(record.reliability_score, 0);
+
+    assert_eq!(client.get_cycle_penalty_pool(&group_id, &1u32), EXPECTED_PENALTY);
+
+    let stats = client.get_member_stats(&member2);
+    assert_eq!(stats.late_contributions, 1);
+    assert_eq!(stats.on_time_contributions, 0);
+}
+
+#[test]
+fn test_contribution_after_grace_period_rejected() {
+    let (env, client, creator, member2, token) = setup_test_env();
+    let group_id = create_two_member_group(&env, &client, &creator, &member2, &token);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE_DURATION + GRACE_PERIOD + 1;
+    });
+
+    let result = client.try_contribute(&member2, &group_id);
+    assert_eq!(result, Err(Ok(AjoError::GracePeriodExpired)));
+
+    let result = client.try_contribute_partial(&member2, &group_id, &10_000_000i128);
+    assert_eq!(result, Err(Ok(AjoError::GracePeriodExpired)));
+}
+
+#[test]
+fn test_late_completing_installment_carries_penalty() {
+    let (env, client, creator, member2, token) = setup_test_env();
+    let group_id = create_two_member_group(&env, &client, &creator, &member2, &token);
+    let token_client = token::Client::new(&env, &token);
+
+    client.contribute_partial(&member2, &group_id, &40_000_000i128);
+    advance_into_grace_period(&env);
+
+    let before = token_client.balance(&member2);
+    client.contribute_partial(&member2, &group_id, &60_000_000i128);
+    assert_eq!(before - token_client.balance(&member2), 60_000_000 + EXPECTED_PENALTY);
+
+    let detail = client.get_contribution_detail(&group_id, &1u32, &member2);
+    assert!(detail.is_late);
+    assert_eq!(client.get_cycle_penalty_pool(&group_id, &1u32), EXPECTED_PENALTY);
+}
+
+#[test]
+fn test_payout_includes_penalty_pool() {
+    let (env, client, creator, member2, token) = setup_test_env();
+    let group_id = create_two_member_group(&env, &client, &creator, &member2, &token);
+    let token_client = token::Client::new(&env, &token);
+
+    client.contribute(&creator, &group_id);
+    advance_into_grace_period(&env);
+    client.contribute(&member2, &group_id);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += GRACE_PERIOD;
+    });
+
+    let before = token_client.balance(&creator);
+    client.execute_payout(&group_id);
+    assert_eq!(token_client.balance(&creator) - before, CONTRIBUTION * 2 + EXPECTED_PENALTY);
+}
@@ -0,0 +1,203 @@
+#![cfg(test)]
+
+use soroban_ajo::{AjoContract, AjoContractClient, AjoError, PayoutOrderingStrategy};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, Vec,
+};
+
+const CONTRIBUTION: i128 = 100_000_000;
+const CYCLE_DURATION: u64 = 604_800;
+const GRACE_PERIOD: u64 = 86_400;
+const STARTING_BALANCE: i128 = 1_000_000_000;
+
+/// Helper function to create a test environment and contract
+fn setup_test_env() -> (Env, AjoContractClient<'static>, Vec<Address>, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AjoContract);
+    let client = AjoContractClient::new(&env, &contract_id);
+
+    let token_admin = Address::generate(&env);
+    let token = env.register_stellar_asset_contract(token_admin);
+    let token_client = token::StellarAssetClient::new(&env, &token);
+
+    let mut members = Vec::new(&env);
+    for _ in 0..4 {
+        let member = Address::generate(&env);
+        token_client.mint(&member, &STARTING_BALANCE);
+        members.push_back(member);
+    }
+
+    (env, client, members, token)
+}
+
+fn create_group(
+    client: &AjoContractClient,
+    creator: &Address,
+    token: &Address,
+    max_members: u32,
+) -> u64 {
+    client.create_group(
+        creator,
+        token,
+        &CONTRIBUTION,
+        &CYCLE_DURATION,
+        &max_members,
+        &GRACE_PERIOD,
+        &5u32,
+        &0u32,
+    )
+}
+
+fn contribute_all_and_pay(env: &Env, client: &AjoContractClient, group_id: u64) -> Address {
+    for member in client.list_members(&group_id).iter() {
+        client.contribute(&member, &group_id);
+    }
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE_DURATION + GRACE_PERIOD;
+    });
+    let cycle = client.get_group(&group_id).current_cycle;
+    client.execute_payout(&group_id);
+    client.get_payout_order(&group_id, &cycle).recipient
+}
+
+#[test]
+fn test_double_share_pays_twice_and_is_paid_twice() {
+    let (env, client, members, token) = setup_test_env();
+    let creator = members.get(0).unwrap();
+    let double = members.get(1).unwrap();
+    let single = members.get(2).unwrap();
+    let token_client = token::Client::new(&env, &token);
+
+    let group_id = create_group(&client, &creator, &token, 4);
+    client.join_group(&double, &group_id);
+    client.join_group(&single, &group_id);
+    client.set_member_shares(&double, &group_id, &2);
+    assert_eq!(client.get_member_shares(&group_id, &double), 2);
+    assert_eq!(client.get_member_shares(&group_id, &single), 1);
+
+    // The double share contributes twice per cycle
+    client.contribute(&double, &group_id);
+    assert_eq!(
+        token_client.balance(&double),
+        STARTING_BALANCE - 2 * CONTRIBUTION
+    );
+    client.contribute(&creator, &group_id);
+    client.contribute(&single, &group_id);
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE_DURATION + GRACE_PERIOD;
+    });
+    client.execute_payout(&group_id);
+    assert_eq!(client.get_payout_order(&group_id, &1).recipient, creator);
+
+    // Join order with two consecutive slots for the double share
+    assert_eq!(contribute_all_and_pay(&env, &client, group_id), double);
+    assert_eq!(contribute_all_and_pay(&env, &client, group_id), double);
+    assert_eq!(contribute_all_and_pay(&env, &client, group_id), single);
+    assert!(client.get_group(&group_id).is_complete);
+
+    // Four payouts of four shares' worth: everyone breaks even
+    for member in [creator, double, single] {
+        assert_eq!(token_client.balance(&member), STARTING_BALANCE);
+    }
+}
+
+#[test]
+fn test_shares_count_towards_capacity() {
+    let (_env, client, members, token) = setup_test_env();
+    let creator = members.get(0).unwrap();
+
+    let group_id = create_group(&client, &creator, &token, 4);
+    client.set_member_shares(&creator, &group_id, &3);
+    client.join_group(&members.get(1).unwrap(), &group_id);
+
+    let result = client.try_join_group(&members.get(2).unwrap(), &group_id);
+    assert_eq!(result, Err(Ok(AjoError::MaxMembersExceeded)));
+
+    let result = client.try_set_member_shares(&creator, &group_id, &4);
+    assert_eq!(result, Err(Ok(AjoError::MaxMembersExceeded)));
+}
+
+#[test]
+fn test_all_shares_must_be_paid_before_payout() {
+    let (env, client, members, token) = setup_test_env();
+    let creator = members.get(0).unwrap();
+    let double = members.get(1).unwrap();
+
+    let group_id = create_group(&client, &creator, &token, 3);
+    client.join_group(&double, &group_id);
+    client.set_member_shares(&double, &group_id, &2);
+
+    client.contribute(&creator, &group_id);
+    client.contribute_partial(&double, &group_id, &CONTRIBUTION);
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE_DURATION + GRACE_PERIOD;
+    });
+
+    let result = client.try_execute_payout(&group_id);
+    assert_eq!(result, Err(Ok(AjoError::IncompleteContributions)));
+
+    client.contribute_partial(&double, &group_id, &CONTRIBUTION);
+    client.execute_payout(&group_id);
+    assert_eq!(client.get_group(&group_id).current_cycle, 2);
+}
+
+#[test]
+fn test_shares_locked_once_paid() {
+    let (env, client, members, token) = setup_test_env();
+    let creator = members.get(0).unwrap();
+    let member = members.get(1).unwrap();
+
+    let group_id = create_group(&client, &creator, &token, 4);
+    client.join_group(&member, &group_id);
+
+    let result = client.try_set_member_shares(&member, &group_id, &0);
+    assert_eq!(result, Err(Ok(AjoError::InvalidInput)));
+
+    client.contribute_partial(&member, &group_id, &(CONTRIBUTION / 2));
+    let result = client.try_set_member_shares(&member, &group_id, &2);
+    assert_eq!(result, Err(Ok(AjoError::InvalidState)));
+
+    client.contribute_partial(&member, &group_id, &(CONTRIBUTION / 2));
+    client.contribute(&creator, &group_id);
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE_DURATION + GRACE_PERIOD;
+    });
+    client.execute_payout(&group_id);
+
+    let result = client.try_set_member_shares(&creator, &group_id, &2);
+    assert_eq!(result, Err(Ok(AjoError::InvalidState)));
+}
+
+#[test]
+fn test_votes_weighted_by_shares() {
+    let (_env, client, members, token) = setup_test_env();
+    let creator = members.get(0).unwrap();
+    let heavy = members.get(1).unwrap();
+    let voter = members.get(2).unwrap();
+
+    let group_id = client.create_group_with_ordering(
+        &creator,
+        &token,
+        &CONTRIBUTION,
+        &CYCLE_DURATION,
+        &5u32,
+        &GRACE_PERIOD,
+        &5u32,
+        &0u32,
+        &PayoutOrderingStrategy::VotingBased,
+    );
+    client.join_group(&heavy, &group_id);
+    client.join_group(&voter, &group_id);
+    client.set_member_shares(&heavy, &group_id, &3);
+
+    // Two single-share votes lose to one triple-share vote
+    client.vote_for_next_recipient(&creator, &group_id, &voter);
+    client.vote_for_next_recipient(&voter, &group_id, &voter);
+    client.vote_for_next_recipient(&heavy, &group_id, &heavy);
+
+    let status = client.get_group_status(&group_id);
+    assert_eq!(status.next_recipient, heavy);
+}
@@ -0,0 +1,190 @@
+#![cfg(test)]
+
+use soroban_ajo::{AjoContract, AjoContractClient, AjoError};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env,
+};
+
+const CONTRIBUTION: i128 = 100_000_000;
+const CYCLE_DURATION: u64 = 604_800;
+const GRACE_PERIOD: u64 = 86_400;
+const INITIAL_BALANCE: i128 = 1_000_000_000;
+
+/// Helper function to create a test environment and contract
+fn setup_test_env() -> (
+    Env,
+    AjoContractClient<'static>,
+    Address,
+    Address,
+    Address,
+    Address,
+    Address,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AjoContract);
+    let client = AjoContractClient::new(&env, &contract_id);
+
+    let creator = Address::generate(&env);
+    let member2 = Address::generate(&env);
+    let member3 = Address::generate(&env);
+    let replacement = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = env.register_stellar_asset_contract(token_admin);
+
+    let token_client = token::StellarAssetClient::new(&env, &token);
+    for account in [&creator, &member2, &member3, &replacement] {
+        token_client.mint(account, &INITIAL_BALANCE);
+    }
+
+    (env, client, creator, member2, member3, replacement, token)
+}
+
+fn create_group(
+    client: &AjoContractClient,
+    creator: &Address,
+    member2: &Address,
+    member3: &Address,
+    token: &Address,
+) -> u64 {
+    let group_id = client.create_group(
+        creator,
+        token,
+        &CONTRIBUTION,
+        &CYCLE_DURATION,
+        &3u32,
+        &GRACE_PERIOD,
+        &5u32,
+        &0u32,
+    );
+    client.join_group(member2, &group_id);
+    client.join_group(member3, &group_id);
+    group_id
+}
+
+fn advance_past_grace_period(env: &Env) {
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE_DURATION + GRACE_PERIOD + 1;
+    });
+}
+
+#[test]
+fn test_leave_refunds_current_contribution() {
+    let (env, client, creator, member2, member3, _replacement, token) = setup_test_env();
+    let group_id = create_group(&client, &creator, &member2, &member3, &token);
+    let token_client = token::Client::new(&env, &token);
+
+    client.contribute(&member2, &group_id);
+    assert_eq!(client.get_member_stats(&member2).total_contributions, 1);
+
+    let refunded = client.leave_group(&member2, &group_id);
+    assert_eq!(refunded, CONTRIBUTION);
+    assert_eq!(token_client.balance(&member2), INITIAL_BALANCE);
+    assert!(!client.is_member(&group_id, &member2));
+    assert_eq!(client.list_members(&group_id).len(), 2);
+
+    let stats = client.get_member_stats(&member2);
+    assert_eq!(stats.total_contributions, 0);
+    assert_eq!(stats.on_time_contributions, 0);
+    assert_eq!(stats.total_groups_joined, 0);
+    assert_eq!(client.get_token_liabilities(&token).pots, 0);
+}
+
+#[test]
+fn test_remaining_members_complete_cycle_after_leave() {
+    let (env, client, creator, member2, member3, _replacement, token) = setup_test_env();
+    let group_id = create_group(&client, &creator, &member2, &member3, &token);
+    let token_client = token::Client::new(&env, &token);
+
+    client.contribute(&member2, &group_id);
+    client.leave_group(&member2, &group_id);
+
+    client.contribute(&creator, &group_id);
+    client.contribute(&member3, &group_id);
+    advance_past_grace_period(&env);
+    let before = token_client.balance(&creator);
+    client.execute_payout(&group_id);
+    assert_eq!(token_client.balance(&creator) - before, CONTRIBUTION * 2);
+    assert!(client.is_solvent(&token));
+}
+
+#[test]
+fn test_cannot_leave_after_payout_or_as_creator() {
+    let (env, client, creator, member2, member3, _replacement, token) = setup_test_env();
+    let group_id = create_group(&client, &creator, &member2, &member3, &token);
+
+    let result = client.try_leave_group(&creator, &group_id);
+    assert_eq!(result, Err(Ok(AjoError::Unauthorized)));
+
+    client.contribute(&creator, &group_id);
+    client.contribute(&member2, &group_id);
+    client.contribute(&member3, &group_id);
+    advance_past_grace_period(&env);
+    client.execute_payout(&group_id);
+
+    let result = client.try_leave_group(&member3, &group_id);
+    assert_eq!(result, Err(Ok(AjoError::CannotCancelAfterPayout)));
+}
+
+#[test]
+fn test_transfer_keeps_slot_and_contribution() {
+    let (_env, client, creator, member2, member3, replacement, token) = setup_test_env();
+    let group_id = create_group(&client, &creator, &member2, &member3, &token);
+
+    client.contribute(&member2, &group_id);
+    client.transfer_membership(&creator, &group_id, &member2, &replacement);
+
+    let members = client.list_members(&group_id);
+    assert_eq!(members.get(1).unwrap(), replacement);
+    assert!(!client.is_member(&group_id, &member2));
+
+    // The replacement inherits member2's paid contribution
+    let status = client.get_contribution_status(&group_id, &1u32);
+    assert_eq!(status.get(1).unwrap(), (replacement.clone(), true));
+    assert_eq!(client.get_member_stats(&replacement).total_groups_joined, 1);
+    assert_eq!(client.get_member_stats(&member2).total_groups_joined, 0);
+
+    let result = client.try_contribute(&replacement, &group_id);
+    assert_eq!(result, Err(Ok(AjoError::AlreadyContributed)));
+}
+
+#[test]
+fn test_transfer_moves_payout_entitlement() {
+    let (env, client, creator, member2, member3, replacement, token) = setup_test_env();
+    let group_id = create_group(&client, &creator, &member2, &member3, &token);
+    let token_client = token::Client::new(&env, &token);
+
+    client.contribute(&creator, &group_id);
+    client.contribute(&member2, &group_id);
+    client.contribute(&member3, &group_id);
+    advance_past_grace_period(&env);
+    client.execute_payout(&group_id);
+
+    // member2 is next in line; their replacement receives the next payout
+    client.transfer_membership(&creator, &group_id, &member2, &replacement);
+    client.contribute(&creator, &group_id);
+    client.contribute(&replacement, &group_id);
+    client.contribute(&member3, &group_id);
+
+    advance_past_grace_period(&env);
+    let before = token_client.balance(&replacement);
+    client.execute_payout(&group_id);
+    assert_eq!(
+        token_client.balance(&replacement) - before,
+        CONTRIBUTION * 3
+    );
+}
+
+#[test]
+fn test_transfer_rejects_existing_member_and_non_creator() {
+    let (_env, client, creator, member2, member3, replacement, token) = setup_test_env();
+    let group_id = create_group(&client, &creator, &member2, &member3, &token);
+
+    let result = client.try_transfer_membership(&creator, &group_id, &member2, &member3);
+    assert_eq!(result, Err(Ok(AjoError::AlreadyMember)));
+
+    let result = client.try_transfer_membership(&member3, &group_id, &member2, &replacement);
+    assert_eq!(result, Err(Ok(AjoError::Unauthorized)));
+}
@@ -1,15 +1,40 @@
+mod access_control_tests;
 mod ajo_flow;
+mod auction_tests;
+mod auto_debit_tests;
 mod cancellation_tests;
+mod collateral_tests;
+mod contribution_tier_tests;
+mod default_tests;
+mod discovery_tests;
 mod dispute_tests;
+mod formation_tests;
 mod group_status_tests;
+mod insurance_coverage_tests;
+mod insurance_pool_tests;
 mod integration_tests;
+mod keeper_crank_tests;
+mod late_penalty_tests;
+mod member_shares_tests;
+mod membership_change_tests;
 mod metadata_tests;
 mod multi_token_tests;
+mod need_based_tests;
+mod partial_contribution_tests;
 mod payout_ordering_tests;
+mod payout_schedule_tests;
+mod portfolio_tests;
+mod position_swap_tests;
+mod prepayment_tests;
+mod random_commit_reveal_tests;
 mod reminder_tests;
+mod risk_pricing_tests;
+mod risk_score_tests;
+mod rollover_tests;
 mod security_tests;
 mod template_tests;
 mod validation_tests;
+mod yield_adapter_tests;
 
 // additional edge-case tests for duplicate/zero/negative contributions
 // are implemented in `ajo_flow.rs` as part of the contribution flow suite
@@ -432,6 +432,62 @@ fn test_multi_token_payout_mixed_tokens() {
     assert_eq!(group.current_cycle, 2);
 }
 
+#[test]
+fn test_multi_token_payout_covers_default_without_primary_contributions() {
+    let (env, client, creator) = setup_test_env();
+    let token_a = register_token(&env);
+    let token_b = register_token(&env);
+    let member2 = Address::generate(&env);
+    let member3 = Address::generate(&env);
+    let members = [creator.clone(), member2.clone(), member3.clone()];
+
+    let configs = build_token_configs(&env, &[(token_a.clone(), 100), (token_b.clone(), 100)]);
+
+    // 50% premiums so the first cycle funds a full missing contribution
+    let group_id = client.create_multi_token_group(
+        &creator,
+        &configs,
+        &100_000_000i128,
+        &604_800u64,
+        &3u32,
+        &86400u64,
+        &5u32,
+        &5_000u32,
+    );
+    client.join_group(&member2, &group_id);
+    client.join_group(&member3, &group_id);
+    mint_tokens(&env, &token_a, &members, 1_000_000_000i128);
+    mint_tokens(&env, &token_b, &members, 1_000_000_000i128);
+
+    // Cycle 1: everyone pays in the primary token
+    for member in members.iter() {
+        client.contribute_with_token(member, &group_id, &token_a);
+    }
+    env.ledger().with_mut(|li| {
+        li.timestamp = li.timestamp + 604_800 + 86400 + 1;
+    });
+    client.execute_multi_token_payout(&group_id);
+
+    // Cycle 2: nobody pays in the primary token and member3 defaults
+    client.contribute_with_token(&creator, &group_id, &token_b);
+    client.contribute_with_token(&member2, &group_id, &token_b);
+    env.ledger().with_mut(|li| {
+        li.timestamp = li.timestamp + 604_800 + 86400 + 1;
+    });
+    client.mark_defaults(&group_id);
+
+    let token_a_client = token::Client::new(&env, &token_a);
+    let token_b_client = token::Client::new(&env, &token_b);
+    let before_a = token_a_client.balance(&member2);
+    let before_b = token_b_client.balance(&member2);
+    client.execute_multi_token_payout(&group_id);
+
+    // The insured amount is paid in the primary token
+    assert_eq!(token_a_client.balance(&member2) - before_a, 100_000_000i128);
+    assert_eq!(token_b_client.balance(&member2) - before_b, 200_000_000i128);
+    assert!(client.is_solvent(&token_a));
+}
+
 #[test]
 fn test_multi_token_full_lifecycle() {
     let (env, client, creator) = setup_test_env();
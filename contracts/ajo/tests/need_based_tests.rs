@@ -0,0 +1,192 @@
+#![cfg(test)]
+
+use soroban_ajo::{AjoContract, AjoContractClient, AjoError, NeedCategory, PayoutOrderingStrategy};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, BytesN, Env,
+};
+
+const CONTRIBUTION: i128 = 100_000_000;
+const CYCLE_DURATION: u64 = 604_800;
+const GRACE_PERIOD: u64 = 86_400;
+const FAR_FUTURE: u64 = 100 * CYCLE_DURATION;
+
+/// Helper function to create a test environment and contract
+fn setup_test_env() -> (
+    Env,
+    AjoContractClient<'static>,
+    Address,
+    Address,
+    Address,
+    u64,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AjoContract);
+    let client = AjoContractClient::new(&env, &contract_id);
+
+    let creator = Address::generate(&env);
+    let member2 = Address::generate(&env);
+    let member3 = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = env.register_stellar_asset_contract(token_admin);
+
+    let token_client = token::StellarAssetClient::new(&env, &token);
+    for account in [&creator, &member2, &member3] {
+        token_client.mint(account, &1_000_000_000);
+    }
+
+    let group_id = client.create_group_with_ordering(
+        &creator,
+        &token,
+        &CONTRIBUTION,
+        &CYCLE_DURATION,
+        &3u32,
+        &GRACE_PERIOD,
+        &5u32,
+        &0u32,
+        &PayoutOrderingStrategy::NeedBased,
+    );
+    client.join_group(&member2, &group_id);
+    client.join_group(&member3, &group_id);
+
+    (env, client, creator, member2, member3, group_id)
+}
+
+fn evidence(env: &Env) -> BytesN<32> {
+    BytesN::from_array(env, &[7u8; 32])
+}
+
+fn contribute_all_and_pay(env: &Env, client: &AjoContractClient, group_id: u64) {
+    for member in client.list_members(&group_id).iter() {
+        client.contribute(&member, &group_id);
+    }
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE_DURATION + GRACE_PERIOD + 1;
+    });
+    client.execute_payout(&group_id);
+}
+
+#[test]
+fn test_most_urgent_need_is_paid_first() {
+    let (env, client, _creator, member2, member3, group_id) = setup_test_env();
+
+    client.declare_need(
+        &member2,
+        &group_id,
+        &NeedCategory::Education,
+        &2u32,
+        &FAR_FUTURE,
+        &evidence(&env),
+    );
+    client.declare_need(
+        &member3,
+        &group_id,
+        &NeedCategory::Medical,
+        &4u32,
+        &FAR_FUTURE,
+        &evidence(&env),
+    );
+    assert_eq!(client.get_need_score(&group_id, &member3), 80);
+    assert_eq!(client.get_group_status(&group_id).next_recipient, member3);
+
+    contribute_all_and_pay(&env, &client, group_id);
+    assert_eq!(client.get_payout_order(&group_id, &1u32).recipient, member3);
+}
+
+#[test]
+fn test_endorsements_and_deadline_outweigh_urgency() {
+    let (env, client, creator, member2, member3, group_id) = setup_test_env();
+
+    // Urgency 3 needed this cycle with both endorsements: 60 + 60 + 20
+    let soon = env.ledger().timestamp() + CYCLE_DURATION / 2;
+    client.declare_need(
+        &member2,
+        &group_id,
+        &NeedCategory::Housing,
+        &3u32,
+        &soon,
+        &evidence(&env),
+    );
+    client.endorse_need(&creator, &group_id, &member2);
+    assert_eq!(client.endorse_need(&member3, &group_id, &member2), 2);
+    assert_eq!(client.get_need_score(&group_id, &member2), 140);
+
+    client.declare_need(
+        &member3,
+        &group_id,
+        &NeedCategory::Emergency,
+        &5u32,
+        &FAR_FUTURE,
+        &evidence(&env),
+    );
+    assert_eq!(client.get_group_status(&group_id).next_recipient, member2);
+}
+
+#[test]
+fn test_waiting_raises_score() {
+    let (env, client, creator, member2, _member3, group_id) = setup_test_env();
+
+    client.declare_need(
+        &member2,
+        &group_id,
+        &NeedCategory::Business,
+        &1u32,
+        &FAR_FUTURE,
+        &evidence(&env),
+    );
+    client.declare_need(
+        &creator,
+        &group_id,
+        &NeedCategory::Medical,
+        &2u32,
+        &FAR_FUTURE,
+        &evidence(&env),
+    );
+    contribute_all_and_pay(&env, &client, group_id);
+    assert_eq!(client.get_payout_order(&group_id, &1u32).recipient, creator);
+
+    assert_eq!(client.get_need_score(&group_id, &member2), 30);
+}
+
+#[test]
+fn test_endorsement_rules() {
+    let (env, client, creator, member2, _member3, group_id) = setup_test_env();
+
+    let result = client.try_endorse_need(&creator, &group_id, &member2);
+    assert_eq!(result, Err(Ok(AjoError::InvalidInput)));
+
+    client.declare_need(
+        &member2,
+        &group_id,
+        &NeedCategory::Other,
+        &3u32,
+        &FAR_FUTURE,
+        &evidence(&env),
+    );
+    let result = client.try_endorse_need(&member2, &group_id, &member2);
+    assert_eq!(result, Err(Ok(AjoError::Unauthorized)));
+
+    client.endorse_need(&creator, &group_id, &member2);
+    let result = client.try_endorse_need(&creator, &group_id, &member2);
+    assert_eq!(result, Err(Ok(AjoError::AlreadyVoted)));
+
+    let result = client.try_declare_need(
+        &member2,
+        &group_id,
+        &NeedCategory::Other,
+        &6u32,
+        &FAR_FUTURE,
+        &evidence(&env),
+    );
+    assert_eq!(result, Err(Ok(AjoError::InvalidInput)));
+}
+
+#[test]
+fn test_need_based_groups_do_not_take_votes() {
+    let (_env, client, creator, member2, _member3, group_id) = setup_test_env();
+
+    let result = client.try_vote_for_next_recipient(&creator, &group_id, &member2);
+    assert_eq!(result, Err(Ok(AjoError::InvalidStrategy)));
+}
@@ -0,0 +1,152 @@
+#![cfg(test)]
+
+use soroban_ajo::{AjoContract, AjoContractClient, AjoError};
+use soroban_sdk::{testutils::{Address as _, Ledger}, token, Address, Env};
+
+/// Helper function to create a test environment and contract
+fn setup_test_env() -> (Env, AjoContractClient<'static>, Address, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AjoContract);
+    let client = AjoContractClient::new(&env, &contract_id);
+
+    let creator = Address::generate(&env);
+    let member2 = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = env.register_stellar_asset_contract(token_admin);
+
+    (env, client, creator, member2, token)
+}
+
+fn mint_tokens(env: &Env, token_id: &Address, members: &[Address], amount: i128) {
+    let token_client = token::StellarAssetClient::new(env, token_id);
+    for member in members {
+        token_client.mint(member, &amount);
+    }
+}
+
+fn create_two_member_group(
+    env: &Env,
+    client: &AjoContractClient,
+    creator: &Address,
+    member2: &Address,
+    token: &Address,
+) -> u64 {
+    let group_id = client.create_group(creator, token, &100_000_000i128, &604_800u64, &2u32, &86400u64, &5u32, &0u32);
+    client.join_group(member2, &group_id);
+    mint_tokens(env, token, &[creator.clone(), member2.clone()], 1_000_000_000);
+    group_id
+}
+
+#[test]
+fn test_partial_contribution_tracks_running_total() {
+    let (env, client, creator, member2, token) = setup_test_env();
+    let group_id = create_two_member_group(&env, &client, &creator, &member2, &token);
+
+    let total = client.contribute_partial(&member2, &group_id, &25_000_000i128);
+    assert_eq!(total, 25_000_000);
+    let total = client.contribute_partial(&member2, &group_id, &25_000_000i128);
+    assert_eq!(total, 50_000_000);
+
+    let progress = client.get_contribution_progress(&group_id, &1u32, &member2);
+    assert_eq!(progress.amount_paid, 50_000_000);
+    assert_eq!(progress.installments, 2);
+
+    // Not yet counted as contributed
+    let statuses = client.get_contribution_status(&group_id, &1u32);
+    assert_eq!(statuses.get(1).unwrap(), (member2.clone(), false));
+
+    let status = client.get_group_status(&group_id);
+    assert_eq!(status.contributions_received, 0);
+    assert_eq!(status.pending_contributors.len(), 2);
+    assert_eq!(status.total_collected, 50_000_000);
+}
+
+#[test]
+fn test_progress_is_empty_before_any_payment() {
+    let (env, client, creator, member2, token) = setup_test_env();
+    let group_id = create_two_member_group(&env, &client, &creator, &member2, &token);
+
+    let progress = client.get_contribution_progress(&group_id, &1u32, &member2);
+    assert_eq!(progress.member, member2);
+    assert_eq!(progress.amount_paid, 0);
+    assert_eq!(progress.installments, 0);
+
+    let result = client.try_get_contribution_progress(&(group_id + 1), &1u32, &member2);
+    assert_eq!(result, Err(Ok(AjoError::GroupNotFound)));
+}
+
+#[test]
+fn test_partial_contribution_completes_cycle() {
+    let (env, client, creator, member2, token) = setup_test_env();
+    let group_id = create_two_member_group(&env, &client, &creator, &member2, &token);
+
+    client.contribute(&creator, &group_id);
+    for _ in 0..4 {
+        client.contribute_partial(&member2, &group_id, &25_000_000i128);
+    }
+
+    let statuses = client.get_contribution_status(&group_id, &1u32);
+    assert_eq!(statuses.get(1).unwrap(), (member2.clone(), true));
+    assert_eq!(client.get_member_stats(&member2).total_contributions, 1);
+
+    let status = client.get_group_status(&group_id);
+    assert_eq!(status.contributions_received, 2);
+    assert_eq!(status.total_collected, 200_000_000);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = li.timestamp + 604_800 + 86400 + 1;
+    });
+    client.execute_payout(&group_id);
+
+    let token_client = token::Client::new(&env, &token);
+    assert_eq!(token_client.balance(&creator), 1_100_000_000);
+}
+
+#[test]
+fn test_contribute_after_partial_pays_remainder() {
+    let (env, client, creator, member2, token) = setup_test_env();
+    let group_id = create_two_member_group(&env, &client, &creator, &member2, &token);
+
+    client.contribute_partial(&member2, &group_id, &30_000_000i128);
+    client.contribute(&member2, &group_id);
+
+    let token_client = token::Client::new(&env, &token);
+    assert_eq!(token_client.balance(&member2), 900_000_000);
+    assert_eq!(client.get_contribution_progress(&group_id, &1u32, &member2).amount_paid, 100_000_000);
+}
+
+#[test]
+fn test_partial_contribution_rejects_overpayment() {
+    let (env, client, creator, member2, token) = setup_test_env();
+    let group_id = create_two_member_group(&env, &client, &creator, &member2, &token);
+
+    client.contribute_partial(&member2, &group_id, &60_000_000i128);
+    let result = client.try_contribute_partial(&member2, &group_id, &60_000_000i128);
+    assert_eq!(result, Err(Ok(AjoError::InvalidInput)));
+
+    let result = client.try_contribute_partial(&member2, &group_id, &0i128);
+    assert_eq!(result, Err(Ok(AjoError::ContributionAmountZero)));
+
+    client.contribute_partial(&member2, &group_id, &40_000_000i128);
+    let result = client.try_contribute_partial(&member2, &group_id, &1i128);
+    assert_eq!(result, Err(Ok(AjoError::AlreadyContributed)));
+}
+
+#[test]
+fn test_cancel_refunds_partial_installments() {
+    let (env, client, creator, member2, token) = setup_test_env();
+    let group_id = create_two_member_group(&env, &client, &creator, &member2, &token);
+
+    client.contribute(&creator, &group_id);
+    client.contribute_partial(&member2, &group_id, &40_000_000i128);
+
+    client.cancel_group(&creator, &group_id);
+
+    assert_eq!(client.get_refund_record(&group_id, &creator).amount, 100_000_000);
+    assert_eq!(client.get_refund_record(&group_id, &member2).amount, 40_000_000);
+
+    let token_client = token::Client::new(&env, &token);
+    assert_eq!(token_client.balance(&member2), 1_000_000_000);
+}
@@ -0,0 +1,203 @@
+#![cfg(test)]
+
+use soroban_ajo::{AjoContract, AjoContractClient, AjoError, ScheduleRule};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Bytes, Env, Vec,
+};
+
+const CONTRIBUTION: i128 = 100_000_000;
+const CYCLE_DURATION: u64 = 604_800;
+const GRACE_PERIOD: u64 = 86_400;
+
+/// Helper function to create a test environment and contract
+fn setup_test_env() -> (Env, AjoContractClient<'static>, Vec<Address>, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AjoContract);
+    let client = AjoContractClient::new(&env, &contract_id);
+
+    let token_admin = Address::generate(&env);
+    let token = env.register_stellar_asset_contract(token_admin);
+    let token_client = token::StellarAssetClient::new(&env, &token);
+
+    let mut members = Vec::new(&env);
+    for _ in 0..4 {
+        let member = Address::generate(&env);
+        token_client.mint(&member, &1_000_000_000);
+        members.push_back(member);
+    }
+
+    (env, client, members, token)
+}
+
+fn create_group(
+    client: &AjoContractClient,
+    creator: &Address,
+    token: &Address,
+    max_members: u32,
+) -> u64 {
+    client.create_group(
+        creator,
+        token,
+        &CONTRIBUTION,
+        &CYCLE_DURATION,
+        &max_members,
+        &GRACE_PERIOD,
+        &5u32,
+        &0u32,
+    )
+}
+
+fn contribute_all_and_pay(env: &Env, client: &AjoContractClient, group_id: u64) {
+    for member in client.list_members(&group_id).iter() {
+        client.contribute(&member, &group_id);
+    }
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE_DURATION + GRACE_PERIOD;
+    });
+    client.execute_payout(&group_id);
+}
+
+/// Repeats the documented Fisher–Yates shuffle from the recorded seed
+fn reshuffle(env: &Env, seed: &[u8; 32], members: &Vec<Address>) -> Vec<Address> {
+    let mut order = members.clone();
+    let mut i = order.len();
+    while i > 1 {
+        i -= 1;
+        let mut data = Bytes::from_array(env, seed);
+        data.extend_from_array(&i.to_be_bytes());
+        let hash = env.crypto().sha256(&data).to_array();
+        let mut prefix = [0u8; 8];
+        prefix.copy_from_slice(&hash[..8]);
+        let j = (u64::from_be_bytes(prefix) % (i as u64 + 1)) as u32;
+        let a = order.get(i).unwrap();
+        let b = order.get(j).unwrap();
+        order.set(i, b);
+        order.set(j, a);
+    }
+    order
+}
+
+#[test]
+fn test_schedule_locked_when_group_fills() {
+    let (env, client, members, token) = setup_test_env();
+    let creator = members.get(0).unwrap();
+    let group_id = create_group(&client, &creator, &token, 3);
+    client.set_payout_schedule_rule(&creator, &group_id, &ScheduleRule::JoinOrder);
+
+    client.join_group(&members.get(1).unwrap(), &group_id);
+    let result = client.try_get_payout_schedule(&group_id);
+    assert_eq!(result, Err(Ok(AjoError::InvalidState)));
+
+    client.join_group(&members.get(2).unwrap(), &group_id);
+    let schedule = client.get_payout_schedule(&group_id);
+    assert_eq!(schedule.len(), 3);
+
+    let start = client.get_group(&group_id).cycle_start_time;
+    for (i, entry) in schedule.iter().enumerate() {
+        assert_eq!(entry.cycle, i as u32 + 1);
+        assert_eq!(entry.recipient, members.get(i as u32).unwrap());
+        assert_eq!(
+            entry.projected_date,
+            start + (i as u64 + 1) * (CYCLE_DURATION + GRACE_PERIOD)
+        );
+        assert!(!entry.paid);
+    }
+
+    let result = client.try_set_payout_schedule_rule(&creator, &group_id, &ScheduleRule::Shuffled);
+    assert_eq!(result, Err(Ok(AjoError::InvalidState)));
+}
+
+#[test]
+fn test_shuffled_schedule_is_verifiable_and_followed() {
+    let (env, client, members, token) = setup_test_env();
+    let creator = members.get(0).unwrap();
+    let group_id = create_group(&client, &creator, &token, 4);
+    client.set_payout_schedule_rule(&creator, &group_id, &ScheduleRule::Shuffled);
+    for i in 1..4 {
+        client.join_group(&members.get(i).unwrap(), &group_id);
+    }
+
+    let draw = client.get_payout_schedule_draw(&group_id);
+    assert_eq!(draw.rule, ScheduleRule::Shuffled);
+    assert_eq!(
+        draw.recipients,
+        reshuffle(&env, &draw.seed.to_array(), &members)
+    );
+
+    for cycle in 1..=4u32 {
+        contribute_all_and_pay(&env, &client, group_id);
+        assert_eq!(
+            client.get_payout_order(&group_id, &cycle).recipient,
+            draw.recipients.get(cycle - 1).unwrap()
+        );
+    }
+
+    let schedule = client.get_payout_schedule(&group_id);
+    assert!(schedule.iter().all(|entry| entry.paid));
+    assert!(client.get_group(&group_id).is_complete);
+}
+
+#[test]
+fn test_most_reliable_members_paid_first() {
+    let (env, client, members, token) = setup_test_env();
+    let creator = members.get(0).unwrap();
+    let reliable = members.get(2).unwrap();
+
+    // Give one member an on-time payment in another group
+    let warmup = create_group(&client, &reliable, &token, 2);
+    client.contribute(&reliable, &warmup);
+
+    let group_id = create_group(&client, &creator, &token, 3);
+    client.join_group(&members.get(1).unwrap(), &group_id);
+    client.join_group(&reliable, &group_id);
+    client.set_payout_schedule_rule(&creator, &group_id, &ScheduleRule::MostReliableFirst);
+
+    let schedule = client.get_payout_schedule(&group_id);
+    assert_eq!(schedule.get(0).unwrap().recipient, reliable);
+    assert_eq!(schedule.get(1).unwrap().recipient, creator);
+    assert_eq!(schedule.get(2).unwrap().recipient, members.get(1).unwrap());
+
+    assert_eq!(client.get_group_status(&group_id).next_recipient, reliable);
+    contribute_all_and_pay(&env, &client, group_id);
+    assert_eq!(
+        client.get_payout_order(&group_id, &1u32).recipient,
+        reliable
+    );
+}
+
+#[test]
+fn test_schedule_locked_when_forming_group_starts_early() {
+    let (env, client, members, token) = setup_test_env();
+    let creator = members.get(0).unwrap();
+    let group_id = create_group(&client, &creator, &token, 4);
+    client.set_formation(&creator, &group_id, &2u32, &CYCLE_DURATION, &true);
+    client.set_payout_schedule_rule(&creator, &group_id, &ScheduleRule::JoinOrder);
+    client.join_group(&members.get(1).unwrap(), &group_id);
+
+    client.start_group(&creator, &group_id);
+    let draw = client.get_payout_schedule_draw(&group_id);
+    assert_eq!(draw.recipients.len(), 2);
+
+    // Joining before the first payout redraws the rotation
+    client.join_group(&members.get(2).unwrap(), &group_id);
+    assert_eq!(client.get_payout_schedule(&group_id).len(), 3);
+
+    // Joining afterwards queues the member at the end
+    contribute_all_and_pay(&env, &client, group_id);
+    client.join_group(&members.get(3).unwrap(), &group_id);
+    let draw = client.get_payout_schedule_draw(&group_id);
+    assert_eq!(draw.recipients.len(), 4);
+    assert_eq!(draw.recipients.get(3).unwrap(), members.get(3).unwrap());
+
+    for cycle in 2..=4u32 {
+        contribute_all_and_pay(&env, &client, group_id);
+        assert_eq!(
+            client.get_payout_order(&group_id, &cycle).recipient,
+            members.get(cycle - 1).unwrap()
+        );
+    }
+    assert!(client.get_group(&group_id).is_complete);
+}
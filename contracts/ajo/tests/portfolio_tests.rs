@@ -0,0 +1,284 @@
+#![cfg(test)]
+
+use soroban_ajo::{AjoContract, AjoContractClient, GroupState};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    token, Address, Env, Vec,
+};
+
+const CONTRIBUTION: i128 = 100_000_000;
+const CYCLE_DURATION: u64 = 604_800;
+const GRACE_PERIOD: u64 = 86_400;
+const STARTING_BALANCE: i128 = 1_000_000_000;
+const FORMATION_PERIOD: u64 = 86_400;
+
+/// Helper function to create a test environment and contract
+fn setup_test_env() -> (Env, AjoContractClient<'static>, Vec<Address>, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AjoContract);
+    let client = AjoContractClient::new(&env, &contract_id);
+
+    let token_admin = Address::generate(&env);
+    let token = env.register_stellar_asset_contract(token_admin);
+    let token_client = token::StellarAssetClient::new(&env, &token);
+
+    let mut members = Vec::new(&env);
+    for _ in 0..4 {
+        let member = Address::generate(&env);
+        token_client.mint(&member, &STARTING_BALANCE);
+        members.push_back(member);
+    }
+
+    (env, client, members, token)
+}
+
+/// Creates a full three-member group from the first three members
+fn create_full_group(client: &AjoContractClient, members: &Vec<Address>, token: &Address) -> u64 {
+    let group_id = client.create_group(
+        &members.get(0).unwrap(),
+        token,
+        &CONTRIBUTION,
+        &CYCLE_DURATION,
+        &3u32,
+        &GRACE_PERIOD,
+        &5u32,
+        &0u32,
+    );
+    client.join_group(&members.get(1).unwrap(), &group_id);
+    client.join_group(&members.get(2).unwrap(), &group_id);
+    group_id
+}
+
+fn group_ids(env: &Env, client: &AjoContractClient, member: &Address) -> Vec<u64> {
+    let mut ids = Vec::new(env);
+    for entry in client.get_member_portfolio(member, &0u64, &10u32).iter() {
+        ids.push_back(entry.group_id);
+    }
+    ids
+}
+
+fn run_cycle(env: &Env, client: &AjoContractClient, group_id: u64) {
+    for member in client.list_members(&group_id).iter() {
+        client.contribute(&member, &group_id);
+    }
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE_DURATION + GRACE_PERIOD;
+    });
+    client.execute_payout(&group_id);
+}
+
+#[test]
+fn test_portfolio_reports_standing_in_each_group() {
+    let (env, client, members, token) = setup_test_env();
+    let member = members.get(1).unwrap();
+
+    let first = create_full_group(&client, &members, &token);
+    let second = client.create_group(
+        &members.get(3).unwrap(),
+        &token,
+        &(2 * CONTRIBUTION),
+        &CYCLE_DURATION,
+        &3u32,
+        &GRACE_PERIOD,
+        &5u32,
+        &0u32,
+    );
+    client.join_group(&member, &second);
+
+    let portfolio = client.get_member_portfolio(&member, &0u64, &10u32);
+    assert_eq!(portfolio.len(), 2);
+
+    let entry = portfolio.get(0).unwrap();
+    assert_eq!(entry.group_id, first);
+    assert_eq!(entry.state, GroupState::Active);
+    assert_eq!(entry.current_cycle, 1);
+    assert_eq!(entry.amount_due, CONTRIBUTION);
+    assert_eq!(
+        entry.due_at,
+        client.get_group(&first).cycle_start_time + CYCLE_DURATION
+    );
+    assert!(!entry.paid_this_cycle);
+    assert_eq!(entry.payout_position, 2);
+    assert!(!entry.received_payout);
+
+    let entry = portfolio.get(1).unwrap();
+    assert_eq!(entry.group_id, second);
+    assert_eq!(entry.amount_due, 2 * CONTRIBUTION);
+
+    client.contribute(&member, &first);
+    let entry = client.get_member_portfolio(&member, &0u64, &10u32).get(0).unwrap();
+    assert!(entry.paid_this_cycle);
+    assert_eq!(entry.amount_due, 0);
+    assert_eq!(entry.due_at, 0);
+
+    assert_eq!(
+        client
+            .get_member_portfolio(&Address::generate(&env), &0u64, &10u32)
+            .len(),
+        0
+    );
+}
+
+#[test]
+fn test_leaving_and_transfers_update_portfolio() {
+    let (env, client, members, token) = setup_test_env();
+    let creator = members.get(0).unwrap();
+    let leaving = members.get(1).unwrap();
+    let handing_over = members.get(2).unwrap();
+    let replacement = members.get(3).unwrap();
+
+    let group_id = create_full_group(&client, &members, &token);
+    assert_eq!(
+        group_ids(&env, &client, &leaving),
+        Vec::from_array(&env, [group_id])
+    );
+
+    client.leave_group(&leaving, &group_id);
+    assert_eq!(group_ids(&env, &client, &leaving).len(), 0);
+
+    client.transfer_membership(&creator, &group_id, &handing_over, &replacement);
+    assert_eq!(group_ids(&env, &client, &handing_over).len(), 0);
+    assert_eq!(
+        group_ids(&env, &client, &replacement),
+        Vec::from_array(&env, [group_id])
+    );
+    assert_eq!(
+        client
+            .get_member_portfolio(&replacement, &0u64, &10u32)
+            .get(0)
+            .unwrap()
+            .payout_position,
+        2
+    );
+}
+
+#[test]
+fn test_completed_groups_stay_in_portfolio() {
+    let (env, client, members, token) = setup_test_env();
+    let creator = members.get(0).unwrap();
+    let member = members.get(2).unwrap();
+
+    let group_id = create_full_group(&client, &members, &token);
+    run_cycle(&env, &client, group_id);
+
+    let entry = client.get_member_portfolio(&creator, &0u64, &10u32).get(0).unwrap();
+    assert!(entry.received_payout);
+    assert_eq!(entry.current_cycle, 2);
+    let entry = client.get_member_portfolio(&member, &0u64, &10u32).get(0).unwrap();
+    assert!(!entry.received_payout);
+    assert_eq!(entry.payout_position, 3);
+
+    run_cycle(&env, &client, group_id);
+    run_cycle(&env, &client, group_id);
+
+    let entry = client.get_member_portfolio(&member, &0u64, &10u32).get(0).unwrap();
+    assert_eq!(entry.group_id, group_id);
+    assert_eq!(entry.state, GroupState::Complete);
+    assert!(entry.received_payout);
+    assert_eq!(entry.amount_due, 0);
+}
+
+#[test]
+fn test_installments_reduce_amount_due() {
+    let (_env, client, members, token) = setup_test_env();
+    let member = members.get(1).unwrap();
+
+    let group_id = create_full_group(&client, &members, &token);
+    client.contribute_partial(&member, &group_id, &(CONTRIBUTION / 4));
+
+    let entry = client.get_member_portfolio(&member, &0u64, &10u32).get(0).unwrap();
+    assert!(!entry.paid_this_cycle);
+    assert_eq!(entry.amount_due, CONTRIBUTION - CONTRIBUTION / 4);
+
+    client.contribute_partial(&member, &group_id, &(CONTRIBUTION - CONTRIBUTION / 4));
+    assert!(
+        client
+            .get_member_portfolio(&member, &0u64, &10u32)
+            .get(0)
+            .unwrap()
+            .paid_this_cycle
+    );
+}
+
+#[test]
+fn test_forming_group_has_nothing_due() {
+    let (env, client, members, token) = setup_test_env();
+    let creator = members.get(0).unwrap();
+
+    let group_id = client.create_group(
+        &creator,
+        &token,
+        &CONTRIBUTION,
+        &CYCLE_DURATION,
+        &3u32,
+        &GRACE_PERIOD,
+        &5u32,
+        &0u32,
+    );
+    client.set_formation(&creator, &group_id, &2, &FORMATION_PERIOD, &false);
+
+    let entry = client.get_member_portfolio(&creator, &0u64, &10u32).get(0).unwrap();
+    assert_eq!(entry.state, GroupState::Forming);
+    assert_eq!(entry.amount_due, 0);
+    assert_eq!(entry.due_at, 0);
+    assert_eq!(entry.payout_position, 1);
+
+    // Starting the group makes the first contribution due
+    client.join_group(&members.get(1).unwrap(), &group_id);
+    client.join_group(&members.get(2).unwrap(), &group_id);
+    let entry = client.get_member_portfolio(&creator, &0u64, &10u32).get(0).unwrap();
+    assert_eq!(entry.state, GroupState::Active);
+    assert_eq!(entry.amount_due, CONTRIBUTION);
+    assert_eq!(entry.due_at, env.ledger().timestamp() + CYCLE_DURATION);
+}
+
+#[test]
+fn test_portfolio_pages_follow_cursor() {
+    let (env, client, members, token) = setup_test_env();
+    let member = members.get(1).unwrap();
+
+    let mut ids = Vec::new(&env);
+    for _ in 0..3 {
+        ids.push_back(create_full_group(&client, &members, &token));
+    }
+
+    let page = client.get_member_portfolio(&member, &0u64, &2u32);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(1).unwrap().group_id, ids.get(1).unwrap());
+
+    let page = client.get_member_portfolio(&member, &ids.get(1).unwrap(), &2u32);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().group_id, ids.get(2).unwrap());
+    assert_eq!(
+        client
+            .get_member_portfolio(&member, &ids.get(2).unwrap(), &2u32)
+            .len(),
+        0
+    );
+}
+
+#[test]
+fn test_backfill_adds_groups_to_portfolios() {
+    let (env, client, members, token) = setup_test_env();
+    let member = members.get(1).unwrap();
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let group_id = create_full_group(&client, &members, &token);
+
+    // Drop the member's group list, as if the group predated it
+    env.as_contract(&client.address, || {
+        let key = (symbol_short!("MGROUPS"), member.clone());
+        env.storage().persistent().remove(&key);
+    });
+    assert_eq!(group_ids(&env, &client, &member).len(), 0);
+
+    client.backfill_group_indexes(&admin, &0u64, &10u32);
+    assert_eq!(
+        group_ids(&env, &client, &member),
+        Vec::from_array(&env, [group_id])
+    );
+}
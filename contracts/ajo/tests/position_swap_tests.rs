@@ -0,0 +1,174 @@
+#![cfg(test)]
+
+use soroban_ajo::{AjoContract, AjoContractClient, AjoError, PayoutOrderingStrategy, SwapStatus};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env,
+};
+
+const CONTRIBUTION: i128 = 100_000_000;
+const CYCLE_DURATION: u64 = 604_800;
+const GRACE_PERIOD: u64 = 86_400;
+
+/// Helper function to create a test environment and contract
+fn setup_test_env() -> (
+    Env,
+    AjoContractClient<'static>,
+    Address,
+    Address,
+    Address,
+    Address,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AjoContract);
+    let client = AjoContractClient::new(&env, &contract_id);
+
+    let creator = Address::generate(&env);
+    let member2 = Address::generate(&env);
+    let member3 = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = env.register_stellar_asset_contract(token_admin);
+
+    let token_client = token::StellarAssetClient::new(&env, &token);
+    for account in [&creator, &member2, &member3] {
+        token_client.mint(account, &1_000_000_000);
+    }
+
+    (env, client, creator, member2, member3, token)
+}
+
+fn create_group(
+    client: &AjoContractClient,
+    creator: &Address,
+    member2: &Address,
+    member3: &Address,
+    token: &Address,
+) -> u64 {
+    let group_id = client.create_group(
+        creator,
+        token,
+        &CONTRIBUTION,
+        &CYCLE_DURATION,
+        &3u32,
+        &GRACE_PERIOD,
+        &5u32,
+        &0u32,
+    );
+    client.join_group(member2, &group_id);
+    client.join_group(member3, &group_id);
+    group_id
+}
+
+fn run_cycle(env: &Env, client: &AjoContractClient, group_id: u64) {
+    for member in client.list_members(&group_id).iter() {
+        client.contribute(&member, &group_id);
+    }
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE_DURATION + GRACE_PERIOD + 1;
+    });
+    client.execute_payout(&group_id);
+}
+
+#[test]
+fn test_accepted_swap_reorders_rotation() {
+    let (env, client, creator, member2, member3, token) = setup_test_env();
+    let group_id = create_group(&client, &creator, &member2, &member3, &token);
+
+    assert_eq!(client.get_payout_order(&group_id, &2u32).recipient, member2);
+
+    let swap_id = client.propose_position_swap(&member3, &group_id, &member2);
+    client.accept_position_swap(&member2, &group_id, &swap_id);
+
+    let swap = client.get_position_swap(&swap_id);
+    assert_eq!(swap.status, SwapStatus::Completed);
+    assert_eq!(client.get_payout_order(&group_id, &1u32).recipient, creator);
+    assert_eq!(client.get_payout_order(&group_id, &2u32).recipient, member3);
+    assert_eq!(client.get_payout_order(&group_id, &3u32).recipient, member2);
+
+    run_cycle(&env, &client, group_id);
+    assert_eq!(client.get_payout_order(&group_id, &1u32).recipient, creator);
+    assert_eq!(client.get_group_status(&group_id).next_recipient, member3);
+
+    run_cycle(&env, &client, group_id);
+    assert_eq!(client.get_payout_order(&group_id, &2u32).recipient, member3);
+    assert!(client.get_payout_order(&group_id, &2u32).determined_at > 0);
+}
+
+#[test]
+fn test_cannot_swap_with_paid_member() {
+    let (env, client, creator, member2, member3, token) = setup_test_env();
+    let group_id = create_group(&client, &creator, &member2, &member3, &token);
+
+    let swap_id = client.propose_position_swap(&member3, &group_id, &creator);
+    run_cycle(&env, &client, group_id);
+
+    let result = client.try_accept_position_swap(&creator, &group_id, &swap_id);
+    assert_eq!(result, Err(Ok(AjoError::AlreadyReceivedPayout)));
+
+    let result = client.try_propose_position_swap(&member2, &group_id, &creator);
+    assert_eq!(result, Err(Ok(AjoError::AlreadyReceivedPayout)));
+}
+
+#[test]
+fn test_creator_approval_gates_swap() {
+    let (_env, client, creator, member2, member3, token) = setup_test_env();
+    let group_id = create_group(&client, &creator, &member2, &member3, &token);
+    client.set_swap_approval(&creator, &group_id, &true);
+
+    let swap_id = client.propose_position_swap(&member3, &group_id, &member2);
+    client.accept_position_swap(&member2, &group_id, &swap_id);
+    assert_eq!(
+        client.get_position_swap(&swap_id).status,
+        SwapStatus::AwaitingApproval
+    );
+    assert_eq!(client.get_payout_order(&group_id, &2u32).recipient, member2);
+
+    let result = client.try_approve_position_swap(&member2, &group_id, &swap_id);
+    assert_eq!(result, Err(Ok(AjoError::Unauthorized)));
+
+    client.approve_position_swap(&creator, &group_id, &swap_id);
+    assert_eq!(client.get_payout_order(&group_id, &2u32).recipient, member3);
+}
+
+#[test]
+fn test_cancelled_swap_cannot_be_accepted() {
+    let (_env, client, creator, member2, member3, token) = setup_test_env();
+    let group_id = create_group(&client, &creator, &member2, &member3, &token);
+
+    let swap_id = client.propose_position_swap(&member3, &group_id, &member2);
+
+    let result = client.try_accept_position_swap(&member3, &group_id, &swap_id);
+    assert_eq!(result, Err(Ok(AjoError::Unauthorized)));
+
+    client.cancel_position_swap(&member3, &group_id, &swap_id);
+    let result = client.try_accept_position_swap(&member2, &group_id, &swap_id);
+    assert_eq!(result, Err(Ok(AjoError::InvalidState)));
+
+    let swaps = client.get_group_position_swaps(&group_id);
+    assert_eq!(swaps.len(), 1);
+    assert_eq!(swaps.get(0).unwrap().status, SwapStatus::Cancelled);
+    assert_eq!(client.list_members(&group_id).get(1).unwrap(), member2);
+}
+
+#[test]
+fn test_swap_requires_sequential_ordering() {
+    let (_env, client, creator, member2, member3, token) = setup_test_env();
+    let group_id = client.create_group_with_ordering(
+        &creator,
+        &token,
+        &CONTRIBUTION,
+        &CYCLE_DURATION,
+        &3u32,
+        &GRACE_PERIOD,
+        &5u32,
+        &0u32,
+        &PayoutOrderingStrategy::VotingBased,
+    );
+    client.join_group(&member2, &group_id);
+    client.join_group(&member3, &group_id);
+
+    let result = client.try_propose_position_swap(&member3, &group_id, &member2);
+    assert_eq!(result, Err(Ok(AjoError::InvalidStrategy)));
+}
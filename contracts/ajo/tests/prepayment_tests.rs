@@ -0,0 +1,214 @@
+#![cfg(test)]
+
+use soroban_ajo::{AjoContract, AjoContractClient, AjoError, Prepayment};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, Vec,
+};
+
+const CONTRIBUTION: i128 = 100_000_000;
+const CYCLE_DURATION: u64 = 604_800;
+const GRACE_PERIOD: u64 = 86_400;
+const STARTING_BALANCE: i128 = 1_000_000_000;
+
+/// Helper function to create a test environment and contract
+fn setup_test_env() -> (Env, AjoContractClient<'static>, Vec<Address>, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AjoContract);
+    let client = AjoContractClient::new(&env, &contract_id);
+
+    let token_admin = Address::generate(&env);
+    let token = env.register_stellar_asset_contract(token_admin);
+    let token_client = token::StellarAssetClient::new(&env, &token);
+
+    let mut members = Vec::new(&env);
+    for _ in 0..3 {
+        let member = Address::generate(&env);
+        token_client.mint(&member, &STARTING_BALANCE);
+        members.push_back(member);
+    }
+
+    (env, client, members, token)
+}
+
+/// Creates a full three-member group
+fn create_full_group(client: &AjoContractClient, members: &Vec<Address>, token: &Address) -> u64 {
+    let group_id = client.create_group(
+        &members.get(0).unwrap(),
+        token,
+        &CONTRIBUTION,
+        &CYCLE_DURATION,
+        &3u32,
+        &GRACE_PERIOD,
+        &5u32,
+        &0u32,
+    );
+    client.join_group(&members.get(1).unwrap(), &group_id);
+    client.join_group(&members.get(2).unwrap(), &group_id);
+    group_id
+}
+
+/// Contributes for everyone who has not yet paid and runs the payout
+fn finish_cycle(env: &Env, client: &AjoContractClient, group_id: u64) {
+    let cycle = client.get_group(&group_id).current_cycle;
+    for (member, paid) in client.get_contribution_status(&group_id, &cycle).iter() {
+        if !paid {
+            client.contribute(&member, &group_id);
+        }
+    }
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE_DURATION + GRACE_PERIOD;
+    });
+    client.execute_payout(&group_id);
+}
+
+fn has_paid(client: &AjoContractClient, group_id: u64, member: &Address) -> bool {
+    let cycle = client.get_group(&group_id).current_cycle;
+    client
+        .get_contribution_status(&group_id, &cycle)
+        .iter()
+        .any(|(address, paid)| address == *member && paid)
+}
+
+#[test]
+fn test_prepaid_cycles_applied_as_cycles_advance() {
+    let (env, client, members, token) = setup_test_env();
+    let member = members.get(1).unwrap();
+    let token_client = token::Client::new(&env, &token);
+
+    let group_id = create_full_group(&client, &members, &token);
+    let prepayment = client.prepay_contributions(&member, &group_id, &2u32);
+    assert_eq!(
+        prepayment,
+        Prepayment {
+            cycles: 2,
+            amount: 2 * CONTRIBUTION
+        }
+    );
+    assert_eq!(
+        token_client.balance(&member),
+        STARTING_BALANCE - 2 * CONTRIBUTION
+    );
+
+    // The current cycle is still paid as usual
+    assert!(!has_paid(&client, group_id, &member));
+    finish_cycle(&env, &client, group_id);
+
+    assert!(has_paid(&client, group_id, &member));
+    assert_eq!(client.get_prepayment(&group_id, &member).unwrap().cycles, 1);
+    let result = client.try_contribute(&member, &group_id);
+    assert_eq!(result, Err(Ok(AjoError::AlreadyContributed)));
+
+    finish_cycle(&env, &client, group_id);
+    assert!(has_paid(&client, group_id, &member));
+    assert_eq!(client.get_prepayment(&group_id, &member), None);
+
+    finish_cycle(&env, &client, group_id);
+    assert!(client.get_group(&group_id).is_complete);
+    assert_eq!(token_client.balance(&member), STARTING_BALANCE);
+    assert_eq!(token_client.balance(&client.address), 0);
+}
+
+#[test]
+fn test_prepayment_limited_to_remaining_cycles() {
+    let (env, client, members, token) = setup_test_env();
+    let member = members.get(1).unwrap();
+
+    let group_id = create_full_group(&client, &members, &token);
+    for cycles in [0u32, 3u32] {
+        let result = client.try_prepay_contributions(&member, &group_id, &cycles);
+        assert_eq!(result, Err(Ok(AjoError::InvalidInput)));
+    }
+
+    client.prepay_contributions(&member, &group_id, &1u32);
+    let result = client.try_prepay_contributions(&member, &group_id, &2u32);
+    assert_eq!(result, Err(Ok(AjoError::InvalidInput)));
+    assert_eq!(
+        client
+            .prepay_contributions(&member, &group_id, &1u32)
+            .cycles,
+        2
+    );
+
+    let result = client.try_prepay_contributions(&Address::generate(&env), &group_id, &1u32);
+    assert_eq!(result, Err(Ok(AjoError::NotMember)));
+
+    // One cycle fewer remains once the group moves on
+    let other = members.get(2).unwrap();
+    finish_cycle(&env, &client, group_id);
+    let result = client.try_prepay_contributions(&other, &group_id, &2u32);
+    assert_eq!(result, Err(Ok(AjoError::InvalidInput)));
+    client.prepay_contributions(&other, &group_id, &1u32);
+}
+
+#[test]
+fn test_unused_prepayment_refunded_on_cancellation() {
+    let (env, client, members, token) = setup_test_env();
+    let creator = members.get(0).unwrap();
+    let member = members.get(1).unwrap();
+    let token_client = token::Client::new(&env, &token);
+
+    let group_id = create_full_group(&client, &members, &token);
+    client.prepay_contributions(&member, &group_id, &2u32);
+    assert_eq!(
+        client.get_token_liabilities(&token).prepaid,
+        2 * CONTRIBUTION
+    );
+    assert!(client.is_solvent(&token));
+
+    client.cancel_group(&creator, &group_id);
+    assert_eq!(token_client.balance(&member), STARTING_BALANCE);
+    assert_eq!(client.get_prepayment(&group_id, &member), None);
+    assert_eq!(client.get_token_liabilities(&token).prepaid, 0);
+}
+
+#[test]
+fn test_prepayment_refunded_when_member_leaves() {
+    let (env, client, members, token) = setup_test_env();
+    let member = members.get(2).unwrap();
+    let token_client = token::Client::new(&env, &token);
+
+    let group_id = create_full_group(&client, &members, &token);
+    client.contribute(&member, &group_id);
+    client.prepay_contributions(&member, &group_id, &2u32);
+
+    assert_eq!(client.leave_group(&member, &group_id), CONTRIBUTION);
+    assert_eq!(token_client.balance(&member), STARTING_BALANCE);
+    assert_eq!(client.get_prepayment(&group_id, &member), None);
+}
+
+#[test]
+fn test_prepayment_refunded_on_membership_transfer() {
+    let (env, client, members, token) = setup_test_env();
+    let member = members.get(2).unwrap();
+    let replacement = Address::generate(&env);
+    let token_client = token::Client::new(&env, &token);
+
+    let group_id = create_full_group(&client, &members, &token);
+    client.contribute(&member, &group_id);
+    client.prepay_contributions(&member, &group_id, &2u32);
+
+    client.transfer_membership(&members.get(0).unwrap(), &group_id, &member, &replacement);
+    assert_eq!(token_client.balance(&member), STARTING_BALANCE - CONTRIBUTION);
+    assert_eq!(client.get_prepayment(&group_id, &member), None);
+    assert_eq!(client.get_prepayment(&group_id, &replacement), None);
+    assert_eq!(client.get_token_liabilities(&token).prepaid, 0);
+}
+
+#[test]
+fn test_prepayment_rejected_after_group_ends() {
+    let (env, client, members, token) = setup_test_env();
+    let member = members.get(1).unwrap();
+
+    let group_id = create_full_group(&client, &members, &token);
+    for _ in 0..3 {
+        finish_cycle(&env, &client, group_id);
+    }
+
+    let result = client.try_prepay_contributions(&member, &group_id, &1u32);
+    assert_eq!(result, Err(Ok(AjoError::GroupComplete)));
+    let result = client.try_prepay_contributions(&member, &999u64, &1u32);
+    assert_eq!(result, Err(Ok(AjoError::GroupNotFound)));
+}
@@ -0,0 +1,242 @@
+#![cfg(test)]
+
+use soroban_ajo::{AjoContract, AjoContractClient, AjoError, PayoutOrderingStrategy};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Bytes, BytesN, Env, Vec,
+};
+
+const CONTRIBUTION: i128 = 100_000_000;
+const CYCLE_DURATION: u64 = 604_800;
+const GRACE_PERIOD: u64 = 86_400;
+
+/// Helper function to create a test environment and contract
+fn setup_test_env() -> (Env, AjoContractClient<'static>, Vec<Address>, u64) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AjoContract);
+    let client = AjoContractClient::new(&env, &contract_id);
+
+    let token_admin = Address::generate(&env);
+    let token = env.register_stellar_asset_contract(token_admin);
+    let token_client = token::StellarAssetClient::new(&env, &token);
+
+    let mut members = Vec::new(&env);
+    for _ in 0..3 {
+        let member = Address::generate(&env);
+        token_client.mint(&member, &1_000_000_000);
+        members.push_back(member);
+    }
+
+    let group_id = client.create_group_with_ordering(
+        &members.get(0).unwrap(),
+        &token,
+        &CONTRIBUTION,
+        &CYCLE_DURATION,
+        &3u32,
+        &GRACE_PERIOD,
+        &5u32,
+        &0u32,
+        &PayoutOrderingStrategy::Random,
+    );
+    client.join_group(&members.get(1).unwrap(), &group_id);
+    client.join_group(&members.get(2).unwrap(), &group_id);
+
+    (env, client, members, group_id)
+}
+
+fn secret(env: &Env, byte: u8) -> BytesN<32> {
+    BytesN::from_array(env, &[byte; 32])
+}
+
+fn commitment(env: &Env, secret: &BytesN<32>) -> BytesN<32> {
+    env.crypto().sha256(&secret.clone().into()).to_bytes()
+}
+
+/// Recomputes the seed from the group, cycle and revealed secrets
+fn expected_seed(env: &Env, group_id: u64, cycle: u32, secrets: &[BytesN<32>]) -> BytesN<32> {
+    let mut data = Bytes::from_array(env, &group_id.to_be_bytes());
+    data.extend_from_array(&cycle.to_be_bytes());
+    for secret in secrets {
+        data.append(&secret.clone().into());
+    }
+    env.crypto().sha256(&data).to_bytes()
+}
+
+fn draw(seed: &BytesN<32>, candidates: u64) -> u32 {
+    let mut prefix = [0u8; 8];
+    prefix.copy_from_slice(&seed.to_array()[..8]);
+    (u64::from_be_bytes(prefix) % candidates) as u32
+}
+
+fn contribute_all(client: &AjoContractClient, members: &Vec<Address>, group_id: u64) {
+    for member in members.iter() {
+        client.contribute(&member, &group_id);
+    }
+}
+
+fn advance(env: &Env, seconds: u64) {
+    env.ledger().with_mut(|li| {
+        li.timestamp += seconds;
+        li.sequence_number += 17;
+    });
+}
+
+#[test]
+fn test_draw_uses_revealed_secrets_and_is_verifiable() {
+    let (env, client, members, group_id) = setup_test_env();
+    let secrets = [secret(&env, 1), secret(&env, 2), secret(&env, 3)];
+
+    for (i, member) in members.iter().enumerate() {
+        client.commit_random_seed(&member, &group_id, &commitment(&env, &secrets[i]));
+    }
+    contribute_all(&client, &members, group_id);
+
+    advance(&env, CYCLE_DURATION + 1);
+    for (i, member) in members.iter().enumerate() {
+        client.reveal_random_seed(&member, &group_id, &secrets[i]);
+    }
+    advance(&env, GRACE_PERIOD);
+    client.execute_payout(&group_id);
+
+    let seed = expected_seed(&env, group_id, 1, &secrets);
+    let order = client.get_payout_order(&group_id, &1u32);
+    assert_eq!(order.seed, seed);
+    assert_eq!(order.recipient, members.get(draw(&seed, 3)).unwrap());
+    assert_eq!(client.get_random_commitments(&group_id, &1u32).len(), 3);
+}
+
+#[test]
+fn test_non_revealer_excluded_from_draw() {
+    let (env, client, members, group_id) = setup_test_env();
+    let first = secret(&env, 9);
+    let withheld = secret(&env, 4);
+
+    client.commit_random_seed(
+        &members.get(0).unwrap(),
+        &group_id,
+        &commitment(&env, &first),
+    );
+    client.commit_random_seed(
+        &members.get(1).unwrap(),
+        &group_id,
+        &commitment(&env, &withheld),
+    );
+    contribute_all(&client, &members, group_id);
+
+    advance(&env, CYCLE_DURATION + 1);
+    client.reveal_random_seed(&members.get(0).unwrap(), &group_id, &first);
+    advance(&env, GRACE_PERIOD);
+    client.execute_payout(&group_id);
+
+    // Candidates are the revealer and the member who never committed
+    let seed = expected_seed(&env, group_id, 1, &[first]);
+    let candidates = [members.get(0).unwrap(), members.get(2).unwrap()];
+    let order = client.get_payout_order(&group_id, &1u32);
+    assert_eq!(order.seed, seed);
+    assert_eq!(order.recipient, candidates[draw(&seed, 2) as usize]);
+}
+
+#[test]
+fn test_draw_does_not_depend_on_when_payout_is_called() {
+    let (env, client, members, group_id) = setup_test_env();
+    let only = secret(&env, 5);
+
+    client.commit_random_seed(
+        &members.get(2).unwrap(),
+        &group_id,
+        &commitment(&env, &only),
+    );
+    contribute_all(&client, &members, group_id);
+    advance(&env, CYCLE_DURATION + 1);
+    client.reveal_random_seed(&members.get(2).unwrap(), &group_id, &only);
+    advance(&env, GRACE_PERIOD);
+
+    let previewed = client.get_group_status(&group_id).next_recipient;
+    advance(&env, 12_345);
+    assert_eq!(client.get_group_status(&group_id).next_recipient, previewed);
+
+    client.execute_payout(&group_id);
+    assert_eq!(
+        client.get_payout_order(&group_id, &1u32).recipient,
+        previewed
+    );
+}
+
+#[test]
+fn test_commit_reveal_phases_enforced() {
+    let (env, client, members, group_id) = setup_test_env();
+    let member = members.get(1).unwrap();
+    let value = secret(&env, 6);
+
+    client.commit_random_seed(&member, &group_id, &commitment(&env, &value));
+    let result = client.try_commit_random_seed(&member, &group_id, &commitment(&env, &value));
+    assert_eq!(result, Err(Ok(AjoError::InvalidState)));
+
+    let result = client.try_reveal_random_seed(&member, &group_id, &value);
+    assert_eq!(result, Err(Ok(AjoError::OutsideCycleWindow)));
+
+    advance(&env, CYCLE_DURATION + 1);
+    let late = members.get(2).unwrap();
+    let result = client.try_commit_random_seed(&late, &group_id, &commitment(&env, &value));
+    assert_eq!(result, Err(Ok(AjoError::OutsideCycleWindow)));
+
+    let result = client.try_reveal_random_seed(&member, &group_id, &secret(&env, 7));
+    assert_eq!(result, Err(Ok(AjoError::InvalidInput)));
+
+    client.reveal_random_seed(&member, &group_id, &value);
+    let result = client.try_reveal_random_seed(&member, &group_id, &value);
+    assert_eq!(result, Err(Ok(AjoError::InvalidState)));
+}
+
+#[test]
+fn test_reveals_close_when_grace_period_ends() {
+    let (env, client, members, group_id) = setup_test_env();
+    let member = members.get(1).unwrap();
+    let value = secret(&env, 8);
+
+    client.commit_random_seed(&member, &group_id, &commitment(&env, &value));
+    contribute_all(&client, &members, group_id);
+    advance(&env, CYCLE_DURATION + GRACE_PERIOD);
+
+    let result = client.try_reveal_random_seed(&member, &group_id, &value);
+    assert_eq!(result, Err(Ok(AjoError::OutsideCycleWindow)));
+
+    // The late secret takes no part in the draw
+    client.execute_payout(&group_id);
+    let order = client.get_payout_order(&group_id, &1u32);
+    assert_ne!(order.recipient, member);
+    assert_ne!(order.seed, expected_seed(&env, group_id, 1, &[value]));
+}
+
+#[test]
+fn test_draw_without_reveals_is_not_precomputable() {
+    let (env, client, members, group_id) = setup_test_env();
+    contribute_all(&client, &members, group_id);
+    advance(&env, CYCLE_DURATION + GRACE_PERIOD);
+    client.execute_payout(&group_id);
+
+    let order = client.get_payout_order(&group_id, &1u32);
+    assert_ne!(order.seed, expected_seed(&env, group_id, 1, &[]));
+}
+
+#[test]
+fn test_random_group_requires_grace_period() {
+    let (env, client, members, _group_id) = setup_test_env();
+    let token_admin = Address::generate(&env);
+    let token = env.register_stellar_asset_contract(token_admin);
+
+    let result = client.try_create_group_with_ordering(
+        &members.get(0).unwrap(),
+        &token,
+        &CONTRIBUTION,
+        &CYCLE_DURATION,
+        &3u32,
+        &0u64,
+        &5u32,
+        &0u32,
+        &PayoutOrderingStrategy::Random,
+    );
+    assert_eq!(result, Err(Ok(AjoError::InvalidGracePeriod)));
+}
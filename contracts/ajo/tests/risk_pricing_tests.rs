@@ -0,0 +1,193 @@
+#![cfg(test)]
+
+use soroban_ajo::{AjoContract, AjoContractClient, AjoError, PremiumPricing};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env,
+};
+
+const CONTRIBUTION: i128 = 100_000_000;
+const CYCLE_DURATION: u64 = 604_800;
+const GRACE_PERIOD: u64 = 86_400;
+const BASE_RATE_BPS: u32 = 200;
+
+/// Helper function to create a test environment and contract
+fn setup_test_env() -> (Env, AjoContractClient<'static>, Address, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AjoContract);
+    let client = AjoContractClient::new(&env, &contract_id);
+
+    let creator = Address::generate(&env);
+    let member2 = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = env.register_stellar_asset_contract(token_admin);
+
+    let token_client = token::StellarAssetClient::new(&env, &token);
+    token_client.mint(&creator, &1_000_000_000);
+    token_client.mint(&member2, &1_000_000_000);
+
+    (env, client, creator, member2, token)
+}
+
+fn create_insured_group(
+    client: &AjoContractClient,
+    creator: &Address,
+    member2: &Address,
+    token: &Address,
+) -> u64 {
+    let group_id = client.create_group(
+        creator,
+        token,
+        &CONTRIBUTION,
+        &CYCLE_DURATION,
+        &2u32,
+        &GRACE_PERIOD,
+        &5u32,
+        &BASE_RATE_BPS,
+    );
+    client.join_group(member2, &group_id);
+    group_id
+}
+
+/// Creator pays on time, member2 pays during the grace period, then payout
+fn run_first_cycle(
+    env: &Env,
+    client: &AjoContractClient,
+    group_id: u64,
+    creator: &Address,
+    member2: &Address,
+) {
+    client.contribute(creator, &group_id);
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE_DURATION + 3600;
+    });
+    client.contribute(member2, &group_id);
+    env.ledger().with_mut(|li| {
+        li.timestamp += GRACE_PERIOD;
+    });
+    client.execute_payout(&group_id);
+}
+
+#[test]
+fn test_flat_pricing_charges_base_rate() {
+    let (env, client, creator, member2, token) = setup_test_env();
+    let group_id = create_insured_group(&client, &creator, &member2, &token);
+
+    run_first_cycle(&env, &client, group_id, &creator, &member2);
+
+    assert_eq!(
+        client.get_member_premium_rate(&group_id, &creator),
+        BASE_RATE_BPS
+    );
+    assert_eq!(
+        client.get_member_premium_rate(&group_id, &member2),
+        BASE_RATE_BPS
+    );
+}
+
+#[test]
+fn test_risk_pricing_scales_by_history() {
+    let (env, client, creator, member2, token) = setup_test_env();
+    let group_id = create_insured_group(&client, &creator, &member2, &token);
+    let token_client = token::Client::new(&env, &token);
+
+    client.set_premium_pricing(
+        &creator,
+        &group_id,
+        &PremiumPricing::RiskBased,
+        &100u32,
+        &400u32,
+    );
+
+    // No history yet: both pay the base rate
+    assert_eq!(
+        client.get_member_premium_rate(&group_id, &member2),
+        BASE_RATE_BPS
+    );
+    run_first_cycle(&env, &client, group_id, &creator, &member2);
+
+    // Perfect record halves the rate; one late payment adds 1.5x + 0.1x
+    assert_eq!(client.get_member_premium_rate(&group_id, &creator), 100);
+    assert_eq!(client.get_member_premium_rate(&group_id, &member2), 320);
+
+    let before = token_client.balance(&member2);
+    client.contribute(&member2, &group_id);
+    assert_eq!(
+        before - token_client.balance(&member2),
+        CONTRIBUTION + 3_200_000
+    );
+}
+
+#[test]
+fn test_rate_capped() {
+    let (env, client, creator, member2, token) = setup_test_env();
+    let group_id = create_insured_group(&client, &creator, &member2, &token);
+
+    client.set_premium_pricing(
+        &creator,
+        &group_id,
+        &PremiumPricing::RiskBased,
+        &100u32,
+        &250u32,
+    );
+    run_first_cycle(&env, &client, group_id, &creator, &member2);
+
+    assert_eq!(client.get_member_premium_rate(&group_id, &member2), 250);
+}
+
+#[test]
+fn test_cycle_member_premiums_query() {
+    let (env, client, creator, member2, token) = setup_test_env();
+    let group_id = create_insured_group(&client, &creator, &member2, &token);
+
+    client.set_premium_pricing(
+        &creator,
+        &group_id,
+        &PremiumPricing::RiskBased,
+        &100u32,
+        &400u32,
+    );
+    run_first_cycle(&env, &client, group_id, &creator, &member2);
+    client.contribute(&creator, &group_id);
+    client.contribute(&member2, &group_id);
+
+    let first = client.get_cycle_member_premiums(&group_id, &1u32);
+    assert_eq!(first.len(), 2);
+    assert_eq!(first.get(0).unwrap().rate_bps, BASE_RATE_BPS);
+    assert_eq!(first.get(1).unwrap().rate_bps, BASE_RATE_BPS);
+
+    let second = client.get_cycle_member_premiums(&group_id, &2u32);
+    assert_eq!(second.len(), 2);
+    let creator_premium = second.get(0).unwrap();
+    assert_eq!(creator_premium.member, creator);
+    assert_eq!(creator_premium.amount, 1_000_000);
+    let member2_premium = second.get(1).unwrap();
+    assert_eq!(member2_premium.member, member2);
+    assert_eq!(member2_premium.amount, 3_200_000);
+}
+
+#[test]
+fn test_invalid_bounds_rejected() {
+    let (_env, client, creator, member2, token) = setup_test_env();
+    let group_id = create_insured_group(&client, &creator, &member2, &token);
+
+    let result = client.try_set_premium_pricing(
+        &creator,
+        &group_id,
+        &PremiumPricing::RiskBased,
+        &300u32,
+        &400u32,
+    );
+    assert_eq!(result, Err(Ok(AjoError::InvalidInput)));
+
+    let result = client.try_set_premium_pricing(
+        &member2,
+        &group_id,
+        &PremiumPricing::RiskBased,
+        &100u32,
+        &400u32,
+    );
+    assert_eq!(result, Err(Ok(AjoError::Unauthorized)));
+}
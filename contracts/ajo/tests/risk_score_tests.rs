@@ -0,0 +1,187 @@
+#![cfg(test)]
+
+use soroban_ajo::{AjoContract, AjoContractClient};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env,
+};
+
+const CONTRIBUTION: i128 = 100_000_000;
+const CYCLE_DURATION: u64 = 604_800;
+const GRACE_PERIOD: u64 = 86_400;
+const DAY: u64 = 86_400;
+
+/// Helper function to create a test environment and contract
+fn setup_test_env() -> (Env, AjoContractClient<'static>, Address, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AjoContract);
+    let client = AjoContractClient::new(&env, &contract_id);
+
+    let creator = Address::generate(&env);
+    let member2 = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = env.register_stellar_asset_contract(token_admin);
+
+    let token_client = token::StellarAssetClient::new(&env, &token);
+    token_client.mint(&creator, &1_000_000_000);
+    token_client.mint(&member2, &1_000_000_000);
+
+    (env, client, creator, member2, token)
+}
+
+fn create_group(
+    client: &AjoContractClient,
+    creator: &Address,
+    member2: &Address,
+    token: &Address,
+    penalty_rate: u32,
+) -> u64 {
+    let group_id = client.create_group(
+        creator,
+        token,
+        &CONTRIBUTION,
+        &CYCLE_DURATION,
+        &2u32,
+        &GRACE_PERIOD,
+        &penalty_rate,
+        &0u32,
+    );
+    client.join_group(member2, &group_id);
+    group_id
+}
+
+fn advance(env: &Env, seconds: u64) {
+    env.ledger().with_mut(|li| {
+        li.timestamp += seconds;
+    });
+}
+
+#[test]
+fn test_new_member_scores_neutral() {
+    let (env, client, _creator, _member2, _token) = setup_test_env();
+    let stranger = Address::generate(&env);
+
+    let breakdown = client.get_member_risk_breakdown(&stranger);
+    assert_eq!(breakdown.on_time_ratio_bps, 0);
+    assert_eq!(breakdown.base, 20);
+    assert_eq!(breakdown.on_time_points, 30);
+    assert_eq!(breakdown.score, 50);
+    assert_eq!(client.get_member_risk_score(&stranger), 50);
+}
+
+#[test]
+fn test_on_time_history_raises_score() {
+    let (_env, client, creator, member2, token) = setup_test_env();
+    let group_id = create_group(&client, &creator, &member2, &token, 5);
+
+    client.contribute(&creator, &group_id);
+
+    let breakdown = client.get_member_risk_breakdown(&creator);
+    assert_eq!(breakdown.on_time_ratio_bps, 10_000);
+    assert_eq!(breakdown.on_time_points, 60);
+    assert_eq!(breakdown.score, 80);
+}
+
+#[test]
+fn test_late_payment_penalised_with_recency_decay() {
+    let (env, client, creator, member2, token) = setup_test_env();
+    let group_id = create_group(&client, &creator, &member2, &token, 5);
+
+    client.contribute(&creator, &group_id);
+    advance(&env, CYCLE_DURATION + 3600);
+    client.contribute(&member2, &group_id);
+
+    let breakdown = client.get_member_risk_breakdown(&member2);
+    assert_eq!(breakdown.on_time_points, 0);
+    assert_eq!(breakdown.late_deduction, 3);
+    assert_eq!(breakdown.penalty_deduction, 10);
+    assert_eq!(breakdown.recency_deduction, 15);
+    assert_eq!(breakdown.score, 0);
+
+    advance(&env, 31 * DAY);
+    let breakdown = client.get_member_risk_breakdown(&member2);
+    assert_eq!(breakdown.recency_deduction, 5);
+    assert_eq!(breakdown.score, 2);
+
+    advance(&env, 60 * DAY);
+    let breakdown = client.get_member_risk_breakdown(&member2);
+    assert_eq!(breakdown.recency_deduction, 0);
+    assert_eq!(breakdown.score, 7);
+}
+
+#[test]
+fn test_default_deducted_from_score() {
+    let (env, client, creator, member2, token) = setup_test_env();
+    let group_id = create_group(&client, &creator, &member2, &token, 5);
+
+    client.contribute(&creator, &group_id);
+    advance(&env, CYCLE_DURATION + GRACE_PERIOD + 1);
+    client.mark_defaults(&group_id);
+
+    let breakdown = client.get_member_risk_breakdown(&member2);
+    assert_eq!(breakdown.default_deduction, 10);
+    assert_eq!(breakdown.penalty_deduction, 10);
+    assert_eq!(breakdown.recency_deduction, 15);
+    assert_eq!(breakdown.score, 0);
+}
+
+#[test]
+fn test_worst_group_penalty_record_deducted() {
+    let (env, client, creator, member2, token) = setup_test_env();
+    let first = create_group(&client, &creator, &member2, &token, 5);
+    let second = create_group(&client, &creator, &member2, &token, 5);
+
+    // On time in one group, late in the other
+    client.contribute(&member2, &first);
+    client.contribute(&creator, &second);
+    advance(&env, CYCLE_DURATION + 3600);
+    client.contribute(&member2, &second);
+    advance(&env, 91 * DAY);
+
+    let breakdown = client.get_member_risk_breakdown(&member2);
+    assert_eq!(breakdown.on_time_points, 30);
+    assert_eq!(breakdown.late_deduction, 3);
+    assert_eq!(breakdown.penalty_deduction, 10);
+    assert_eq!(breakdown.score, 37);
+
+    // The creator paid on time everywhere
+    assert_eq!(client.get_member_risk_breakdown(&creator).penalty_deduction, 0);
+}
+
+#[test]
+fn test_emergency_refund_deducted() {
+    let (env, client, creator, member2, token) = setup_test_env();
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    let group_id = create_group(&client, &creator, &member2, &token, 5);
+
+    client.contribute(&creator, &group_id);
+    client.contribute(&member2, &group_id);
+    assert_eq!(client.get_member_risk_breakdown(&member2).refund_deduction, 0);
+
+    client.emergency_refund(&admin, &group_id);
+
+    let breakdown = client.get_member_risk_breakdown(&member2);
+    assert_eq!(breakdown.refund_deduction, 5);
+    assert_eq!(breakdown.score, 75);
+}
+
+#[test]
+fn test_group_rating_uses_members_and_parameters() {
+    let (_env, client, creator, member2, token) = setup_test_env();
+    let group_id = create_group(&client, &creator, &member2, &token, 5);
+    assert_eq!(client.get_group_risk_rating(&group_id), 50);
+
+    // Creator's clean payment lifts the average, weighted against the lowest score
+    client.contribute(&creator, &group_id);
+    assert_eq!(client.get_group_risk_rating(&group_id), (65 * 3 + 50) / 4);
+
+    // No late penalty costs the group 5 points
+    let no_penalty_group = create_group(&client, &creator, &member2, &token, 0);
+    assert_eq!(
+        client.get_group_risk_rating(&no_penalty_group),
+        (65 * 3 + 50) / 4 - 5
+    );
+}
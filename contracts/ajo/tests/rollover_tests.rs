@@ -0,0 +1,251 @@
+#![cfg(test)]
+
+use soroban_ajo::{AjoContract, AjoContractClient, GroupState};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String, Vec,
+};
+
+const CONTRIBUTION: i128 = 100_000_000;
+const CYCLE_DURATION: u64 = 604_800;
+const GRACE_PERIOD: u64 = 86_400;
+const STARTING_BALANCE: i128 = 1_000_000_000;
+
+/// Helper function to create a test environment and contract
+fn setup_test_env() -> (Env, AjoContractClient<'static>, Vec<Address>, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AjoContract);
+    let client = AjoContractClient::new(&env, &contract_id);
+
+    let token_admin = Address::generate(&env);
+    let token = env.register_stellar_asset_contract(token_admin);
+    let token_client = token::StellarAssetClient::new(&env, &token);
+
+    let mut members = Vec::new(&env);
+    for _ in 0..3 {
+        let member = Address::generate(&env);
+        token_client.mint(&member, &STARTING_BALANCE);
+        members.push_back(member);
+    }
+
+    (env, client, members, token)
+}
+
+/// Creates a full three-member group
+fn create_full_group(client: &AjoContractClient, members: &Vec<Address>, token: &Address) -> u64 {
+    let creator = members.get(0).unwrap();
+    let group_id = client.create_group(
+        &creator,
+        token,
+        &CONTRIBUTION,
+        &CYCLE_DURATION,
+        &3u32,
+        &GRACE_PERIOD,
+        &5u32,
+        &0u32,
+    );
+    client.join_group(&members.get(1).unwrap(), &group_id);
+    client.join_group(&members.get(2).unwrap(), &group_id);
+    group_id
+}
+
+fn run_to_completion(env: &Env, client: &AjoContractClient, group_id: u64) {
+    while !client.get_group(&group_id).is_complete {
+        for member in client.list_members(&group_id).iter() {
+            client.contribute(&member, &group_id);
+        }
+        env.ledger().with_mut(|li| {
+            li.timestamp += CYCLE_DURATION + GRACE_PERIOD;
+        });
+        client.execute_payout(&group_id);
+    }
+}
+
+#[test]
+fn test_completion_opens_next_round_with_opted_in_members() {
+    let (env, client, members, token) = setup_test_env();
+    let creator = members.get(0).unwrap();
+    let staying = members.get(1).unwrap();
+
+    let group_id = create_full_group(&client, &members, &token);
+    client.set_group_metadata(
+        &group_id,
+        &String::from_str(&env, "Market traders"),
+        &String::from_str(&env, "Weekly savings"),
+        &String::from_str(&env, "Pay on Fridays"),
+    );
+    client.set_rollover(&creator, &group_id, &true);
+    client.set_rollover_opt_in(&creator, &group_id, &true);
+    client.set_rollover_opt_in(&staying, &group_id, &true);
+    run_to_completion(&env, &client, group_id);
+
+    let group = client.get_group(&group_id);
+    assert_ne!(group.successor_id, 0);
+
+    let successor = client.get_group(&group.successor_id);
+    assert_eq!(successor.round, 2);
+    assert_eq!(successor.predecessor_id, group_id);
+    assert_eq!(successor.creator, creator);
+    assert_eq!(successor.members.len(), 2);
+    assert_eq!(successor.members.get(0).unwrap(), creator);
+    assert_eq!(successor.members.get(1).unwrap(), staying);
+    assert_eq!(successor.contribution_amount, CONTRIBUTION);
+    assert_eq!(successor.state, GroupState::Active);
+    assert!(successor.rollover_enabled);
+    assert_eq!(
+        client.get_group_metadata(&successor.id).name,
+        String::from_str(&env, "Market traders")
+    );
+
+    let rounds = client.get_group_rounds(&successor.id);
+    assert_eq!(rounds.len(), 2);
+    assert_eq!(rounds.get(0).unwrap(), group_id);
+    assert_eq!(rounds.get(1).unwrap(), successor.id);
+
+    // The next round runs like any other group
+    client.contribute(&creator, &successor.id);
+    client.contribute(&staying, &successor.id);
+}
+
+#[test]
+fn test_next_round_with_open_seats_forms_for_newcomers() {
+    let (env, client, members, token) = setup_test_env();
+    let creator = members.get(0).unwrap();
+    let staying = members.get(1).unwrap();
+
+    let group_id = client.create_group(
+        &creator,
+        &token,
+        &CONTRIBUTION,
+        &CYCLE_DURATION,
+        &3u32,
+        &GRACE_PERIOD,
+        &5u32,
+        &0u32,
+    );
+    client.set_formation(&creator, &group_id, &3u32, &CYCLE_DURATION, &false);
+    client.join_group(&staying, &group_id);
+    client.join_group(&members.get(2).unwrap(), &group_id);
+    client.set_rollover(&creator, &group_id, &true);
+    client.set_rollover_opt_in(&creator, &group_id, &true);
+    client.set_rollover_opt_in(&staying, &group_id, &true);
+    run_to_completion(&env, &client, group_id);
+
+    let successor = client.get_group(&client.get_group(&group_id).successor_id);
+    assert_eq!(successor.state, GroupState::Forming);
+    assert_eq!(
+        successor.formation_deadline,
+        env.ledger().timestamp() + CYCLE_DURATION
+    );
+
+    // A newcomer fills the last seat and the round starts
+    let newcomer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&newcomer, &STARTING_BALANCE);
+    client.join_group(&newcomer, &successor.id);
+    let successor = client.get_group(&successor.id);
+    assert_eq!(successor.state, GroupState::Active);
+    assert_eq!(successor.members.len(), 3);
+}
+
+#[test]
+fn test_no_rollover_unless_enabled() {
+    let (env, client, members, token) = setup_test_env();
+
+    let group_id = create_full_group(&client, &members, &token);
+    client.set_rollover_opt_in(&members.get(1).unwrap(), &group_id, &true);
+    run_to_completion(&env, &client, group_id);
+
+    let group = client.get_group(&group_id);
+    assert_eq!(group.successor_id, 0);
+    assert_eq!(group.round, 1);
+    assert_eq!(client.get_group_rounds(&group_id).len(), 1);
+}
+
+#[test]
+fn test_no_next_round_without_other_members() {
+    let (env, client, members, token) = setup_test_env();
+    let creator = members.get(0).unwrap();
+    let member = members.get(1).unwrap();
+
+    let group_id = create_full_group(&client, &members, &token);
+    client.set_rollover(&creator, &group_id, &true);
+    client.set_rollover_opt_in(&creator, &group_id, &true);
+    client.set_rollover_opt_in(&member, &group_id, &true);
+    client.set_rollover_opt_in(&member, &group_id, &false);
+    assert_eq!(client.get_rollover_opt_ins(&group_id).len(), 1);
+    run_to_completion(&env, &client, group_id);
+
+    assert_eq!(client.get_group(&group_id).successor_id, 0);
+}
+
+#[test]
+fn test_collateral_carried_into_next_round() {
+    let (env, client, members, token) = setup_test_env();
+    let creator = members.get(0).unwrap();
+    let staying = members.get(1).unwrap();
+    let leaving = members.get(2).unwrap();
+    let token_client = token::Client::new(&env, &token);
+
+    let group_id = client.create_group(
+        &creator,
+        &token,
+        &CONTRIBUTION,
+        &CYCLE_DURATION,
+        &3u32,
+        &GRACE_PERIOD,
+        &5u32,
+        &0u32,
+    );
+    client.set_collateral_amount(&creator, &group_id, &CONTRIBUTION);
+    client.join_group(&staying, &group_id);
+    client.join_group(&leaving, &group_id);
+    client.set_rollover(&creator, &group_id, &true);
+    client.set_rollover_opt_in(&creator, &group_id, &true);
+    client.set_rollover_opt_in(&staying, &group_id, &true);
+    run_to_completion(&env, &client, group_id);
+
+    let successor_id = client.get_group(&group_id).successor_id;
+    assert_eq!(
+        client.get_member_collateral(&successor_id, &staying),
+        CONTRIBUTION
+    );
+    assert_eq!(client.get_member_collateral(&group_id, &staying), 0);
+    assert_eq!(
+        token_client.balance(&staying),
+        STARTING_BALANCE - CONTRIBUTION
+    );
+    assert_eq!(token_client.balance(&leaving), STARTING_BALANCE);
+}
+
+#[test]
+fn test_no_next_round_unless_creator_opts_in() {
+    let (env, client, members, token) = setup_test_env();
+    let creator = members.get(0).unwrap();
+    let staying = members.get(1).unwrap();
+    let token_client = token::Client::new(&env, &token);
+
+    let group_id = client.create_group(
+        &creator,
+        &token,
+        &CONTRIBUTION,
+        &CYCLE_DURATION,
+        &3u32,
+        &GRACE_PERIOD,
+        &5u32,
+        &0u32,
+    );
+    client.set_collateral_amount(&creator, &group_id, &CONTRIBUTION);
+    client.join_group(&staying, &group_id);
+    client.join_group(&members.get(2).unwrap(), &group_id);
+    client.set_rollover(&creator, &group_id, &true);
+    client.set_rollover_opt_in(&staying, &group_id, &true);
+    run_to_completion(&env, &client, group_id);
+
+    // Without the creator nobody carries on, and all collateral comes back
+    assert_eq!(client.get_group(&group_id).successor_id, 0);
+    for member in members.iter() {
+        assert_eq!(token_client.balance(&member), STARTING_BALANCE);
+    }
+}
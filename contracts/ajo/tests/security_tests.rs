@@ -41,7 +41,7 @@ fn test_security_unauthorized_pause() {
     
     // Attacker tries to pause without being admin
     let result = client.try_pause();
-    assert_eq!(result, Err(Ok(AjoError::UnauthorizedPause)));
+    assert_eq!(result, Err(Ok(AjoError::Unauthorized)));
 }
 
 #[test]
@@ -54,7 +54,7 @@ fn test_security_unauthorized_unpause() {
     // Attacker tries to unpause
     let attacker = Address::generate(&env);
     let result = client.try_unpause();
-    assert_eq!(result, Err(Ok(AjoError::UnauthorizedUnpause)));
+    assert_eq!(result, Err(Ok(AjoError::Unauthorized)));
 }
 
 #[test]
@@ -330,15 +330,18 @@ fn test_security_large_group_operations() {
         client.join_group(member, &group_id);
     }
 
-    // Mint tokens and contribute for all members
+    // Mint tokens and contribute for all members. Each contribution is its own
+    // transaction on-chain, so each gets a fresh default budget.
     let tc = token::StellarAssetClient::new(&env, &token);
     for member in &members {
         tc.mint(member, &100_000_000i128);
+        env.budget().reset_default();
         client.contribute(member, &group_id);
     }
 
     // Advance time past grace period and execute payout
     env.ledger().with_mut(|li| { li.timestamp += 604_800 + 86400 + 1; });
+    env.budget().reset_default();
     client.execute_payout(&group_id);
 
     let group = client.get_group(&group_id);
@@ -0,0 +1,377 @@
+#![cfg(test)]
+
+use soroban_ajo::{AjoContract, AjoContractClient, AjoError, YieldDistribution};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, Vec,
+};
+
+const CONTRIBUTION: i128 = 100_000_000;
+const CYCLE_DURATION: u64 = 604_800;
+const GRACE_PERIOD: u64 = 86_400;
+const STARTING_BALANCE: i128 = 1_000_000_000;
+
+/// A lending pool that tracks deposits per owner and pays interest on demand
+mod mock_lender {
+    use soroban_ajo::YieldAdapter;
+    use soroban_sdk::{contract, contractimpl, symbol_short, token, Address, Env};
+
+    #[contract]
+    pub struct MockLender;
+
+    fn token(env: &Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("token"))
+            .unwrap()
+    }
+
+    fn set_balance(env: &Env, owner: &Address, amount: i128) {
+        env.storage().persistent().set(owner, &amount);
+    }
+
+    #[contractimpl]
+    impl MockLender {
+        pub fn init(env: Env, token: Address) {
+            env.storage()
+                .instance()
+                .set(&symbol_short!("token"), &token);
+        }
+
+        /// Credits interest to `owner`, or writes off a loss when `amount` is
+        /// negative; the test mints the tokens backing any interest
+        pub fn accrue(env: Env, owner: Address, amount: i128) {
+            let balance = Self::balance(env.clone(), owner.clone());
+            set_balance(&env, &owner, balance + amount);
+        }
+    }
+
+    #[contractimpl]
+    impl YieldAdapter for MockLender {
+        fn deposit(env: Env, from: Address, amount: i128) {
+            from.require_auth();
+            token::Client::new(&env, &token(&env)).transfer(
+                &from,
+                &env.current_contract_address(),
+                &amount,
+            );
+            let balance = Self::balance(env.clone(), from.clone());
+            set_balance(&env, &from, balance + amount);
+        }
+
+        fn withdraw(env: Env, to: Address, amount: i128) {
+            to.require_auth();
+            let balance = Self::balance(env.clone(), to.clone());
+            set_balance(&env, &to, balance - amount);
+            token::Client::new(&env, &token(&env)).transfer(
+                &env.current_contract_address(),
+                &to,
+                &amount,
+            );
+        }
+
+        fn balance(env: Env, owner: Address) -> i128 {
+            env.storage().persistent().get(&owner).unwrap_or(0)
+        }
+    }
+}
+
+use mock_lender::{MockLender, MockLenderClient};
+
+/// Helper function to create a test environment, contract and allowlisted lender
+fn setup_test_env() -> (
+    Env,
+    AjoContractClient<'static>,
+    MockLenderClient<'static>,
+    Vec<Address>,
+    Address,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AjoContract);
+    let client = AjoContractClient::new(&env, &contract_id);
+    client.initialize(&Address::generate(&env));
+
+    let token_admin = Address::generate(&env);
+    let token = env.register_stellar_asset_contract(token_admin);
+    let token_client = token::StellarAssetClient::new(&env, &token);
+
+    let lender_id = env.register_contract(None, MockLender);
+    let lender = MockLenderClient::new(&env, &lender_id);
+    lender.init(&token);
+    client.allow_yield_adapter(&lender_id, &token);
+
+    let mut members = Vec::new(&env);
+    for _ in 0..3 {
+        let member = Address::generate(&env);
+        token_client.mint(&member, &STARTING_BALANCE);
+        members.push_back(member);
+    }
+
+    (env, client, lender, members, token)
+}
+
+/// Creates a full three-member group
+fn create_full_group(client: &AjoContractClient, members: &Vec<Address>, token: &Address) -> u64 {
+    let group_id = client.create_group(
+        &members.get(0).unwrap(),
+        token,
+        &CONTRIBUTION,
+        &CYCLE_DURATION,
+        &3u32,
+        &GRACE_PERIOD,
+        &5u32,
+        &0u32,
+    );
+    client.join_group(&members.get(1).unwrap(), &group_id);
+    client.join_group(&members.get(2).unwrap(), &group_id);
+    group_id
+}
+
+/// Pays `amount` of interest on the contract's position with the lender
+fn accrue(
+    env: &Env,
+    client: &AjoContractClient,
+    lender: &MockLenderClient,
+    token: &Address,
+    amount: i128,
+) {
+    token::StellarAssetClient::new(env, token).mint(&lender.address, &amount);
+    lender.accrue(&client.address, &amount);
+}
+
+/// Writes `amount` off the contract's position with the lender
+fn lose(client: &AjoContractClient, lender: &MockLenderClient, amount: i128) {
+    lender.accrue(&client.address, &-amount);
+}
+
+fn contribute_all(env: &Env, client: &AjoContractClient, group_id: u64) {
+    for member in client.list_members(&group_id).iter() {
+        client.contribute(&member, &group_id);
+    }
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE_DURATION + GRACE_PERIOD;
+    });
+}
+
+#[test]
+fn test_pot_parked_and_yield_paid_to_recipient() {
+    let (env, client, lender, members, token) = setup_test_env();
+    let creator = members.get(0).unwrap();
+    let token_client = token::Client::new(&env, &token);
+
+    let group_id = create_full_group(&client, &members, &token);
+    client.enable_yield(
+        &creator,
+        &group_id,
+        &lender.address,
+        &YieldDistribution::Recipient,
+    );
+
+    contribute_all(&env, &client, group_id);
+    let position = client.get_yield_position(&group_id).unwrap();
+    assert_eq!(position.principal, 3 * CONTRIBUTION);
+    assert_eq!(token_client.balance(&client.address), 0);
+    assert_eq!(lender.balance(&client.address), 3 * CONTRIBUTION);
+    assert_eq!(
+        client.get_token_liabilities(&token).parked,
+        3 * CONTRIBUTION
+    );
+
+    accrue(&env, &client, &lender, &token, 30);
+    client.execute_payout(&group_id);
+
+    assert_eq!(
+        token_client.balance(&creator),
+        STARTING_BALANCE + 2 * CONTRIBUTION + 30
+    );
+    assert_eq!(client.get_yield_position(&group_id), None);
+    assert_eq!(lender.balance(&client.address), 0);
+    assert_eq!(token_client.balance(&client.address), 0);
+    assert_eq!(client.get_token_liabilities(&token).parked, 0);
+}
+
+#[test]
+fn test_yield_shared_among_members() {
+    let (env, client, lender, members, token) = setup_test_env();
+    let creator = members.get(0).unwrap();
+    let token_client = token::Client::new(&env, &token);
+
+    let group_id = create_full_group(&client, &members, &token);
+    client.enable_yield(
+        &creator,
+        &group_id,
+        &lender.address,
+        &YieldDistribution::Members,
+    );
+
+    contribute_all(&env, &client, group_id);
+    accrue(&env, &client, &lender, &token, 300);
+    client.execute_payout(&group_id);
+
+    assert_eq!(
+        token_client.balance(&creator),
+        STARTING_BALANCE + 2 * CONTRIBUTION + 100
+    );
+    for member in [members.get(1).unwrap(), members.get(2).unwrap()] {
+        assert_eq!(
+            token_client.balance(&member),
+            STARTING_BALANCE - CONTRIBUTION + 100
+        );
+    }
+}
+
+#[test]
+fn test_yield_paid_into_insurance_pool() {
+    let (env, client, lender, members, token) = setup_test_env();
+    let creator = members.get(0).unwrap();
+
+    let group_id = create_full_group(&client, &members, &token);
+    client.enable_yield(
+        &creator,
+        &group_id,
+        &lender.address,
+        &YieldDistribution::InsurancePool,
+    );
+
+    contribute_all(&env, &client, group_id);
+    accrue(&env, &client, &lender, &token, 50);
+    client.execute_payout(&group_id);
+
+    assert_eq!(client.get_insurance_pool(&group_id, &token).balance, 50);
+    assert!(client.is_solvent(&token));
+}
+
+#[test]
+fn test_adapter_loss_reduces_payout() {
+    let (env, client, lender, members, token) = setup_test_env();
+    let creator = members.get(0).unwrap();
+    let token_client = token::Client::new(&env, &token);
+
+    let group_id = create_full_group(&client, &members, &token);
+    client.enable_yield(
+        &creator,
+        &group_id,
+        &lender.address,
+        &YieldDistribution::Recipient,
+    );
+
+    contribute_all(&env, &client, group_id);
+    lose(&client, &lender, 30);
+    client.execute_payout(&group_id);
+
+    // Only what came back from the lender is paid out
+    assert_eq!(
+        token_client.balance(&creator),
+        STARTING_BALANCE + 2 * CONTRIBUTION - 30
+    );
+    assert_eq!(token_client.balance(&client.address), 0);
+    assert_eq!(client.get_token_liabilities(&token).pots, 0);
+    assert!(client.is_solvent(&token));
+}
+
+#[test]
+fn test_adapter_loss_covered_from_insurance_pool() {
+    let (env, client, lender, members, token) = setup_test_env();
+    let creator = members.get(0).unwrap();
+    let recipient = members.get(1).unwrap();
+    let token_client = token::Client::new(&env, &token);
+
+    let group_id = create_full_group(&client, &members, &token);
+    client.enable_yield(
+        &creator,
+        &group_id,
+        &lender.address,
+        &YieldDistribution::InsurancePool,
+    );
+
+    contribute_all(&env, &client, group_id);
+    accrue(&env, &client, &lender, &token, 50);
+    client.execute_payout(&group_id);
+    assert_eq!(client.get_insurance_pool(&group_id, &token).balance, 50);
+
+    // The reserve covers what it can and the payout bears the rest
+    contribute_all(&env, &client, group_id);
+    lose(&client, &lender, 80);
+    client.execute_payout(&group_id);
+
+    assert_eq!(client.get_insurance_pool(&group_id, &token).balance, 0);
+    assert_eq!(
+        token_client.balance(&recipient),
+        STARTING_BALANCE + CONTRIBUTION - 30
+    );
+    assert_eq!(token_client.balance(&client.address), 0);
+    assert!(client.is_solvent(&token));
+}
+
+#[test]
+fn test_departing_member_refunded_from_parked_funds() {
+    let (env, client, lender, members, token) = setup_test_env();
+    let creator = members.get(0).unwrap();
+    let leaving = members.get(2).unwrap();
+    let token_client = token::Client::new(&env, &token);
+
+    let group_id = create_full_group(&client, &members, &token);
+    client.enable_yield(
+        &creator,
+        &group_id,
+        &lender.address,
+        &YieldDistribution::Recipient,
+    );
+    client.contribute(&creator, &group_id);
+    client.contribute(&leaving, &group_id);
+    accrue(&env, &client, &lender, &token, 20);
+
+    assert_eq!(client.leave_group(&leaving, &group_id), CONTRIBUTION);
+    assert_eq!(token_client.balance(&leaving), STARTING_BALANCE);
+
+    // The rest stays parked and keeps its yield
+    let position = client.get_yield_position(&group_id).unwrap();
+    assert_eq!(position.principal, CONTRIBUTION);
+    assert!(client.is_solvent(&token));
+
+    client.contribute(&members.get(1).unwrap(), &group_id);
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE_DURATION + GRACE_PERIOD;
+    });
+    client.execute_payout(&group_id);
+    assert!(token_client.balance(&creator) >= STARTING_BALANCE + CONTRIBUTION + 19);
+}
+
+#[test]
+fn test_adapter_must_be_allowlisted_for_group_token() {
+    let (env, client, lender, members, token) = setup_test_env();
+    let creator = members.get(0).unwrap();
+
+    let group_id = create_full_group(&client, &members, &token);
+    let stranger = Address::generate(&env);
+    let result =
+        client.try_enable_yield(&creator, &group_id, &stranger, &YieldDistribution::Members);
+    assert_eq!(result, Err(Ok(AjoError::InvalidInput)));
+
+    let other_token = env.register_stellar_asset_contract(Address::generate(&env));
+    client.allow_yield_adapter(&stranger, &other_token);
+    let result =
+        client.try_enable_yield(&creator, &group_id, &stranger, &YieldDistribution::Members);
+    assert_eq!(result, Err(Ok(AjoError::InvalidInput)));
+
+    // Settings are fixed while funds are parked
+    client.enable_yield(
+        &creator,
+        &group_id,
+        &lender.address,
+        &YieldDistribution::Members,
+    );
+    client.contribute(&creator, &group_id);
+    let result = client.try_disable_yield(&creator, &group_id);
+    assert_eq!(result, Err(Ok(AjoError::InvalidState)));
+
+    // Once removed from the allowlist, new contributions stay with the contract
+    client.remove_yield_adapter(&lender.address);
+    client.contribute(&members.get(1).unwrap(), &group_id);
+    assert_eq!(
+        client.get_yield_position(&group_id).unwrap().principal,
+        CONTRIBUTION
+    );
+}